@@ -12,20 +12,21 @@ import sys
 import tempfile
 
 
-def build_python(cpython_path, version):
+def build_python(cpython_path, version, free_threaded=False):
     # TODO: probably easier to use pyenv for this?
     print("Compiling python %s from repo at %s" % (version, cpython_path))
-    install_path = os.path.abspath(os.path.join(cpython_path, version))
+    suffix = "t" if free_threaded else ""
+    install_path = os.path.abspath(os.path.join(cpython_path, version + suffix))
 
     ret = os.system(
         f"""
         cd {cpython_path}
         git checkout {version}
 
-        # build in a subdirectory
-        mkdir -p build_{version}
-        cd build_{version}
-        ../configure prefix={install_path}
+        # build in a subdirectory, keeping the free-threaded build separate from the default one
+        mkdir -p build_{version}{suffix}
+        cd build_{version}{suffix}
+        ../configure prefix={install_path} {"--disable-gil" if free_threaded else ""}
         make
         make install
     """
@@ -100,16 +101,26 @@ def calculate_pyruntime_offsets(cpython_path, version, configure=False):
             return ret
 
 
-def extract_bindings(cpython_path, version, configure=False):
+def extract_bindings(cpython_path, version, configure=False, free_threaded=False):
     print("Generating bindings for python %s from repo at %s" % (version, cpython_path))
 
+    # the free-threaded build changes the layout of most structs, and is only selected by
+    # pyconfig.h - so always needs configuring
+    suffix = "t" if free_threaded else ""
+    configure_command = ""
+    if configure or free_threaded:
+        install_path = os.path.abspath(os.path.join(cpython_path, version + suffix))
+        configure_command = f"./configure prefix={install_path}"
+        if free_threaded:
+            configure_command += " --disable-gil"
+
     ret = os.system(
         f"""
         cd {cpython_path}
         git checkout {version}
 
         # need to run configure on the current branch to generate pyconfig.h sometimes
-        {("./configure prefix=" + os.path.abspath(os.path.join(cpython_path, version))) if configure else ""}
+        {configure_command}
 
 
         echo "// autogenerated by generate_bindings.py " > bindgen_input.h
@@ -151,8 +162,9 @@ def extract_bindings(cpython_path, version, configure=False):
         return ret
 
     # write the file out to the appropriate place, disabling some warnings
-    with open(os.path.join("src", "python_bindings", version.replace(".", "_") + ".rs"), "w") as o:
-        o.write(f"// Generated bindings for python {version}\n")
+    filename = version.replace(".", "_") + suffix + ".rs"
+    with open(os.path.join("src", "python_bindings", filename), "w") as o:
+        o.write(f"// Generated bindings for python {version}{suffix}\n")
         o.write("#![allow(dead_code)]\n")
         o.write("#![allow(non_upper_case_globals)]\n")
         o.write("#![allow(non_camel_case_types)]\n")
@@ -189,6 +201,12 @@ if __name__ == "__main__":
         help="Run configure script prior to generating bindings",
         action="store_true",
     )
+    parser.add_argument(
+        "--free-threaded",
+        help="Use the free-threaded build of python (configured with --disable-gil)",
+        action="store_true",
+        dest="free_threaded",
+    )
     parser.add_argument("--pyruntime", help="generate offsets for pyruntime", action="store_true")
     parser.add_argument("--build", help="Build python for this version", action="store_true")
     parser.add_argument("--all", help="Build all versions", action="store_true")
@@ -227,11 +245,13 @@ if __name__ == "__main__":
     for version in versions:
         if args.build:
             # todo: this probably should be a separate script
-            if build_python(args.cpython, version):
+            if build_python(args.cpython, version, free_threaded=args.free_threaded):
                 print("Failed to build python")
         elif args.pyruntime:
             calculate_pyruntime_offsets(args.cpython, version, configure=args.configure)
 
         else:
-            if extract_bindings(args.cpython, version, configure=args.configure):
+            if extract_bindings(
+                args.cpython, version, configure=args.configure, free_threaded=args.free_threaded
+            ):
                 print("Failed to generate bindings")
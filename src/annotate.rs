@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+
+use anyhow::Error;
+use remoteprocess::Pid;
+
+use crate::stack_trace::StackTrace;
+
+/*
+ * This file contains code to write out the source of each sampled python file, with the
+ * number of samples that each line was running in (own) or was on the stack for (total)
+ * written next to it - similar to what 'perf annotate' shows for native code.
+ *
+ * Rather than writing out every line of each file, only the lines that were sampled are
+ * shown along with a few lines of surrounding context.
+ */
+
+// The number of lines of context to show before and after each sampled line
+const CONTEXT_LINES: usize = 3;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct LineStatistics {
+    own: u64,
+    total: u64,
+}
+
+struct FileStatistics {
+    pid: Pid,
+    total: u64,
+    lines: BTreeMap<usize, LineStatistics>,
+}
+
+pub struct Annotate {
+    files: HashMap<String, FileStatistics>,
+    samples: u64,
+}
+
+impl Annotate {
+    pub fn new() -> Annotate {
+        Annotate {
+            files: HashMap::new(),
+            samples: 0,
+        }
+    }
+
+    pub fn increment(&mut self, trace: &StackTrace) -> std::io::Result<()> {
+        self.samples += 1;
+
+        // deduplicate so that recursive calls don't count the same line multiple times
+        let mut seen_files = HashSet::new();
+        let mut seen_lines = HashSet::new();
+        for (i, frame) in trace.frames.iter().enumerate() {
+            // native frames and the frames added for threads/processes have no source
+            if frame.line <= 0 || frame.filename.is_empty() {
+                continue;
+            }
+            let line = frame.line as usize;
+
+            let file = self
+                .files
+                .entry(frame.filename.clone())
+                .or_insert_with(|| FileStatistics {
+                    pid: trace.pid,
+                    total: 0,
+                    lines: BTreeMap::new(),
+                });
+            if seen_files.insert(&frame.filename) {
+                file.total += 1;
+            }
+
+            let stats = file.lines.entry(line).or_default();
+            if i == 0 {
+                stats.own += 1;
+            }
+            if seen_lines.insert((&frame.filename, line)) {
+                stats.total += 1;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        writeln!(w, "Annotated source from {} samples", self.samples)?;
+
+        // show the files that were sampled the most first
+        let mut files: Vec<_> = self.files.iter().collect();
+        files.sort_by(|(a_name, a), (b_name, b)| b.total.cmp(&a.total).then(a_name.cmp(b_name)));
+
+        for (filename, file) in files {
+            writeln!(w)?;
+            writeln!(
+                w,
+                "{} ({:.2}% of samples)",
+                filename,
+                self.percent(file.total)
+            )?;
+            writeln!(w, "{:>8} {:>8} {:>6}  Source", "Own", "Total", "Line")?;
+
+            let source = read_source(filename, file.pid);
+            let mut previous = None;
+            for line in visible_lines(&file.lines, source.as_ref().map(|s| s.len())) {
+                if previous.is_some_and(|previous| line > previous + 1) {
+                    writeln!(w, "{:>8} {:>8} {:>6}", "", "", "...")?;
+                }
+                previous = Some(line);
+
+                let text = source
+                    .as_ref()
+                    .and_then(|source| source.get(line - 1))
+                    .map_or("", |text| text.trim_end());
+                match file.lines.get(&line) {
+                    Some(stats) => writeln!(
+                        w,
+                        "{:>7.2}% {:>7.2}% {:>6}  {}",
+                        self.percent(stats.own),
+                        self.percent(stats.total),
+                        line,
+                        text
+                    )?,
+                    None => writeln!(w, "{:>8} {:>8} {:>6}  {}", "", "", line, text)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn percent(&self, count: u64) -> f64 {
+        100.0 * count as f64 / self.samples.max(1) as f64
+    }
+}
+
+/// Returns the line numbers to show for a file: each sampled line along with the lines of
+/// context around it. Without the source we only know about the sampled lines themselves
+fn visible_lines(
+    lines: &BTreeMap<usize, LineStatistics>,
+    source_lines: Option<usize>,
+) -> Vec<usize> {
+    let source_lines = match source_lines {
+        Some(source_lines) => source_lines,
+        None => return lines.keys().copied().collect(),
+    };
+
+    let mut visible = Vec::new();
+    for &line in lines.keys() {
+        let start = line.saturating_sub(CONTEXT_LINES).max(1);
+        let end = (line + CONTEXT_LINES).min(source_lines).max(line);
+        let start = match visible.last() {
+            Some(&last) if last >= start => last + 1,
+            _ => start,
+        };
+        visible.extend(start..=end);
+    }
+    visible
+}
+
+/// Reads the lines of a source file, which might only be visible inside the profiled
+/// process's root directory if it's running in a container
+fn read_source(filename: &str, _pid: Pid) -> Option<Vec<String>> {
+    let contents = std::fs::read(filename).ok();
+
+    #[cfg(target_os = "linux")]
+    let contents =
+        contents.or_else(|| std::fs::read(format!("/proc/{}/root{}", _pid, filename)).ok());
+
+    contents.map(|contents| {
+        String::from_utf8_lossy(&contents)
+            .lines()
+            .map(|line| line.to_owned())
+            .collect()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack_trace::tests::{frame, trace};
+
+    #[test]
+    fn test_annotate() {
+        let mut annotate = Annotate::new();
+        let recursive = trace(vec![
+            frame("fib", "/missing/app.py", 4),
+            frame("fib", "/missing/app.py", 5),
+            frame("fib", "/missing/app.py", 5),
+            frame("<module>", "/missing/app.py", 10),
+        ]);
+        let other = trace(vec![
+            frame("sleep", "/missing/util.py", 20),
+            frame("<module>", "/missing/app.py", 10),
+            frame("thread (0x1)", "", 0),
+        ]);
+        annotate.increment(&recursive).unwrap();
+        annotate.increment(&recursive).unwrap();
+        annotate.increment(&other).unwrap();
+
+        let app = &annotate.files["/missing/app.py"];
+        assert_eq!(app.total, 3);
+        assert_eq!(app.lines[&4], LineStatistics { own: 2, total: 2 });
+        assert_eq!(app.lines[&5], LineStatistics { own: 0, total: 2 });
+        assert_eq!(app.lines[&10], LineStatistics { own: 0, total: 3 });
+        assert_eq!(annotate.files["/missing/util.py"].total, 1);
+
+        let mut output = Vec::new();
+        annotate.write(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "Annotated source from 3 samples");
+        assert_eq!(lines[2], "/missing/app.py (100.00% of samples)");
+        assert_eq!(lines[4].trim_end(), "  66.67%   66.67%      4");
+        assert_eq!(lines[5].trim_end(), "   0.00%   66.67%      5");
+        assert_eq!(lines[6].trim_end(), "                     ...");
+        assert_eq!(lines[7].trim_end(), "   0.00%  100.00%     10");
+        assert_eq!(lines[9], "/missing/util.py (33.33% of samples)");
+    }
+
+    #[test]
+    fn test_visible_lines() {
+        let mut lines = BTreeMap::new();
+        lines.insert(2, LineStatistics::default());
+        lines.insert(6, LineStatistics::default());
+        lines.insert(20, LineStatistics::default());
+
+        assert_eq!(visible_lines(&lines, None), vec![2, 6, 20]);
+        assert_eq!(
+            visible_lines(&lines, Some(21)),
+            vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 17, 18, 19, 20, 21]
+        );
+    }
+}
@@ -8,6 +8,7 @@ use memmap2::Mmap;
 
 use crate::utils::is_subrange;
 
+#[derive(Clone)]
 pub struct BinaryInfo {
     pub symbols: HashMap<String, u64>,
     pub bss_addr: u64,
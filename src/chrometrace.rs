@@ -4,18 +4,22 @@ use std::io::Write;
 use std::time::Instant;
 
 use anyhow::Error;
-use serde_derive::Serialize;
+use remoteprocess::Pid;
+use serde_derive::{Deserialize, Serialize};
 
-use crate::stack_trace::Frame;
+use crate::config::Config;
 use crate::stack_trace::StackTrace;
+use crate::stack_trace::{ColumnRange, Frame};
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct Args {
     pub filename: String,
     pub line: Option<u32>,
+    pub column: Option<u32>,
+    pub end_column: Option<u32>,
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 struct Event {
     pub args: Args,
     pub cat: String,
@@ -29,31 +33,38 @@ struct Event {
 pub struct Chrometrace {
     events: Vec<Event>,
     start_ts: Instant,
+    end_ts: u64,
     prev_traces: HashMap<u64, StackTrace>,
     show_linenumbers: bool,
+    sampling_interval: u64,
 }
 
 impl Chrometrace {
-    pub fn new(show_linenumbers: bool) -> Chrometrace {
+    pub fn new(config: &Config) -> Chrometrace {
         Chrometrace {
             events: Vec::new(),
             start_ts: Instant::now(),
+            end_ts: 0,
             prev_traces: HashMap::new(),
-            show_linenumbers,
+            show_linenumbers: config.show_line_numbers,
+            sampling_interval: 1_000_000 / config.sampling_rate.max(1),
         }
     }
 
     // Return whether these frames are similar enough such that we should merge
     // them, instead of creating separate events for them.
     fn should_merge_frames(&self, a: &Frame, b: &Frame) -> bool {
-        a.name == b.name && a.filename == b.filename && (!self.show_linenumbers || a.line == b.line)
+        a.name == b.name
+            && a.qualname == b.qualname
+            && a.filename == b.filename
+            && (!self.show_linenumbers || (a.line == b.line && a.columns == b.columns))
     }
 
     fn event(&self, trace: &StackTrace, frame: &Frame, phase: &str, ts: u64) -> Event {
         Event {
             tid: trace.thread_id,
             pid: trace.pid as u64,
-            name: frame.name.to_string(),
+            name: frame.display_name().to_string(),
             cat: "py-spy".to_owned(),
             ph: phase.to_owned(),
             ts,
@@ -64,12 +75,27 @@ impl Chrometrace {
                 } else {
                     None
                 },
+                column: frame
+                    .columns
+                    .filter(|_| self.show_linenumbers)
+                    .map(|c| c.start),
+                end_column: frame
+                    .columns
+                    .filter(|_| self.show_linenumbers)
+                    .map(|c| c.end),
             },
         }
     }
 
     pub fn increment(&mut self, trace: &StackTrace) -> std::io::Result<()> {
         let now = self.start_ts.elapsed().as_micros() as u64;
+        self.increment_at(trace, now)
+    }
+
+    /// Records a stack trace that was sampled `now` microseconds after the start of the profile
+    pub fn increment_at(&mut self, trace: &StackTrace, now: u64) -> std::io::Result<()> {
+        // each sample covers the time until the next one is taken
+        self.end_ts = self.end_ts.max(now + self.sampling_interval);
 
         // Load the previous frames for this thread.
         let prev_frames = self
@@ -109,7 +135,7 @@ impl Chrometrace {
         events.extend(self.events.to_vec());
 
         // Add end events for any unfinished slices.
-        let now = self.start_ts.elapsed().as_micros() as u64;
+        let now = self.end_ts.max(self.start_ts.elapsed().as_micros() as u64);
         for trace in self.prev_traces.values() {
             for frame in &trace.frames {
                 events.push(self.event(trace, frame, "E", now));
@@ -120,3 +146,79 @@ impl Chrometrace {
         Ok(())
     }
 }
+
+/// Reads back the stack traces from a chrometrace file, along with the time in microseconds that
+/// each was sampled at. Consecutive samples with the same stack are merged into a single slice in
+/// this format, so the sampling rate is used to work out how many samples each slice covered
+pub fn read_traces(contents: &str, sampling_rate: u64) -> Result<Vec<(u64, StackTrace)>, Error> {
+    let events: Vec<Event> = serde_json::from_str(contents)?;
+    let interval = 1_000_000 / sampling_rate.max(1);
+
+    // the stack at each point in time for each thread, with frames ordered from the root
+    type Timeline = Vec<(u64, Vec<Frame>)>;
+    let mut timelines: HashMap<(u64, u64), Timeline> = HashMap::new();
+    for event in events {
+        let timeline = timelines.entry((event.pid, event.tid)).or_default();
+        let mut stack = match timeline.last() {
+            Some((ts, _)) if *ts == event.ts => {
+                timeline.pop().map(|(_, stack)| stack).unwrap_or_default()
+            }
+            Some((_, stack)) => stack.clone(),
+            None => Vec::new(),
+        };
+        match event.ph.as_str() {
+            "B" => stack.push(Frame {
+                name: event.name,
+                qualname: None,
+                filename: event.args.filename,
+                module: None,
+                short_filename: None,
+                line: event.args.line.unwrap_or(0) as i32,
+                columns: match (event.args.column, event.args.end_column) {
+                    (Some(start), Some(end)) => Some(ColumnRange { start, end }),
+                    _ => None,
+                },
+                instruction: None,
+                locals: None,
+                is_entry: true,
+                is_shim_entry: false,
+            }),
+            "E" => {
+                stack.pop();
+            }
+            _ => {}
+        }
+        timeline.push((event.ts, stack));
+    }
+
+    let mut traces = Vec::new();
+    for ((pid, tid), timeline) in timelines {
+        for (i, (ts, stack)) in timeline.iter().enumerate() {
+            if stack.is_empty() {
+                continue;
+            }
+            let samples = match timeline.get(i + 1) {
+                Some((next_ts, _)) => ((next_ts - ts + interval / 2) / interval).max(1),
+                None => 1,
+            };
+            let trace = StackTrace {
+                pid: pid as Pid,
+                thread_id: tid,
+                thread_name: None,
+                os_thread_id: None,
+                interpreter_id: None,
+                task_name: None,
+                active: true,
+                owns_gil: false,
+                cpu_time: None,
+                labels: Vec::new(),
+                frames: stack.iter().rev().cloned().collect(),
+                process_info: None,
+            };
+            for sample in 0..samples {
+                traces.push((ts + sample * interval, trace.clone()));
+            }
+        }
+    }
+    Ok(traces)
+}
@@ -32,18 +32,42 @@ pub struct Config {
     #[doc(hidden)]
     pub format: Option<FileFormat>,
     #[doc(hidden)]
+    pub filenames: Vec<String>,
+    #[doc(hidden)]
+    pub formats: Vec<FileFormat>,
+    #[doc(hidden)]
     pub show_line_numbers: bool,
     #[doc(hidden)]
     pub duration: RecordDuration,
     #[doc(hidden)]
+    pub rotate_seconds: Option<u64>,
+    #[doc(hidden)]
     pub include_idle: bool,
     #[doc(hidden)]
     pub include_thread_ids: bool,
     #[doc(hidden)]
+    pub include_interpreter_ids: bool,
+    #[doc(hidden)]
+    pub async_tasks: bool,
+    #[doc(hidden)]
     pub subprocesses: bool,
     #[doc(hidden)]
     pub gil_only: bool,
     #[doc(hidden)]
+    pub cpu_time: bool,
+    #[doc(hidden)]
+    pub include: Option<String>,
+    #[doc(hidden)]
+    pub exclude: Option<String>,
+    #[doc(hidden)]
+    pub focus: Option<String>,
+    #[doc(hidden)]
+    pub aggregate: Aggregate,
+    #[doc(hidden)]
+    pub instructions: bool,
+    #[doc(hidden)]
+    pub labels: Vec<(String, String)>,
+    #[doc(hidden)]
     pub hide_progress: bool,
     #[doc(hidden)]
     pub capture_output: bool,
@@ -54,11 +78,25 @@ pub struct Config {
     #[doc(hidden)]
     pub full_filenames: bool,
     #[doc(hidden)]
+    pub qualified_names: bool,
+    #[doc(hidden)]
     pub lineno: LineNo,
     #[doc(hidden)]
     pub refresh_seconds: f64,
     #[doc(hidden)]
+    pub batch: bool,
+    #[doc(hidden)]
+    pub batch_json: bool,
+    #[doc(hidden)]
+    pub iterations: Option<u64>,
+    #[doc(hidden)]
     pub core_filename: Option<String>,
+    #[doc(hidden)]
+    pub listen: Option<String>,
+    #[doc(hidden)]
+    pub input_filenames: Vec<String>,
+    #[doc(hidden)]
+    pub input_format: Option<FileFormat>,
 }
 
 #[allow(non_camel_case_types)]
@@ -68,6 +106,9 @@ pub enum FileFormat {
     raw,
     speedscope,
     chrometrace,
+    pprof,
+    pyspy,
+    annotate,
 }
 
 impl FileFormat {
@@ -91,6 +132,23 @@ impl std::str::FromStr for FileFormat {
     }
 }
 
+/// How frames are rolled up before being recorded
+#[allow(non_camel_case_types)]
+#[derive(ArgEnum, Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Aggregate {
+    function,
+    module,
+    package,
+}
+
+impl std::str::FromStr for Aggregate {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        <Self as ArgEnum>::from_str(s, true)
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum LockingStrategy {
     NonBlocking,
@@ -121,24 +179,43 @@ impl Default for Config {
             python_program: None,
             filename: None,
             format: None,
+            filenames: Vec::new(),
+            formats: Vec::new(),
             command: String::from("top"),
             blocking: LockingStrategy::Lock,
             show_line_numbers: false,
             sampling_rate: 100,
             duration: RecordDuration::Unlimited,
+            rotate_seconds: None,
             native: false,
             gil_only: false,
+            cpu_time: false,
+            include: None,
+            exclude: None,
+            focus: None,
+            aggregate: Aggregate::function,
+            instructions: false,
+            labels: Vec::new(),
             include_idle: false,
             include_thread_ids: false,
+            include_interpreter_ids: false,
+            async_tasks: false,
             hide_progress: false,
             capture_output: true,
             dump_json: false,
             dump_locals: 0,
             subprocesses: false,
             full_filenames: false,
+            qualified_names: true,
             lineno: LineNo::LastInstruction,
             refresh_seconds: 1.0,
+            batch: false,
+            batch_json: false,
+            iterations: None,
             core_filename: None,
+            listen: None,
+            input_filenames: Vec::new(),
+            input_format: None,
         }
     }
 }
@@ -192,6 +269,9 @@ impl Config {
         let full_filenames = Arg::new("full_filenames").long("full-filenames").help(
             "Show full Python filenames, instead of shortening to show only the package part",
         );
+        let no_qualnames = Arg::new("no_qualnames").long("no-qualnames").help(
+            "Show only the function name for each frame, instead of qualifying methods with their class name",
+        );
         let program = Arg::new("python_program")
             .help("commandline of a python program to run")
             .multiple_values(true);
@@ -206,6 +286,30 @@ impl Config {
             .long("gil")
             .help("Only include traces that are holding on to the GIL");
 
+        let interpreters = Arg::new("interpreters")
+            .long("interpreters")
+            .help("Show which subinterpreter each thread is running in");
+
+        let async_tasks = Arg::new("async").long("async").help(
+            "Show stack traces for asyncio tasks, following the chain of awaiting coroutines",
+        );
+
+        let aggregate = Arg::new("aggregate")
+            .long("aggregate")
+            .value_name("level")
+            .help("Collapse consecutive frames from the same module or package into a single frame")
+            .takes_value(true)
+            .possible_values(
+                Aggregate::value_variants()
+                    .iter()
+                    .filter_map(ArgEnum::to_possible_value),
+            )
+            .ignore_case(true)
+            .default_value("function")
+            // modules and packages come from the shortened filenames, which aren't available
+            // when showing full filenames
+            .conflicts_with("full_filenames");
+
         let top_delay = Arg::new("delay")
             .long("delay")
             .value_name("seconds")
@@ -214,18 +318,22 @@ impl Config {
             .value_parser(clap::value_parser!(f64))
             .takes_value(true);
 
+        // 'r' is ambiguous between record and report, keep it as a shortcut for record
         let record = Command::new("record")
-            .about("Records stack trace information to a flamegraph, speedscope or raw file")
+            .about("Records stack trace information to a flamegraph, speedscope, pprof or raw file")
+            .alias("r")
             .arg(program.clone())
             .arg(pid.clone().required_unless_present("python_program"))
             .arg(full_filenames.clone())
+            .arg(no_qualnames.clone())
             .arg(
                 Arg::new("output")
                     .short('o')
                     .long("output")
                     .value_name("filename")
-                    .help("Output filename")
+                    .help("Output filename, or '-' to write to stdout. When recording multiple formats, pass an --output for each format or a directory to write them to")
                     .takes_value(true)
+                    .multiple_occurrences(true)
                     .required(false),
             )
             .arg(
@@ -233,10 +341,12 @@ impl Config {
                     .short('f')
                     .long("format")
                     .value_name("format")
-                    .help("Output file format")
+                    .help("Output file format. Pass multiple formats separated by commas to write each of them from the same samples")
                     .takes_value(true)
                     .possible_values(FileFormat::possible_values())
                     .ignore_case(true)
+                    .use_value_delimiter(true)
+                    .multiple_occurrences(true)
                     .default_value("flamegraph"),
             )
             .arg(
@@ -248,6 +358,14 @@ impl Config {
                     .default_value("unlimited")
                     .takes_value(true),
             )
+            .arg(
+                Arg::new("rotate")
+                    .long("rotate")
+                    .value_name("seconds")
+                    .help("Write out a new output file every N seconds, instead of a single file when sampling stops")
+                    .value_parser(clap::value_parser!(u64).range(1..))
+                    .takes_value(true),
+            )
             .arg(rate.clone())
             .arg(subprocesses.clone())
             .arg(Arg::new("function").short('F').long("function").help(
@@ -264,8 +382,50 @@ impl Config {
                     .long("threads")
                     .help("Show thread ids in the output"),
             )
+            .arg(interpreters.clone())
+            .arg(async_tasks.clone())
             .arg(gil.clone())
             .arg(idle.clone())
+            .arg(aggregate.clone())
+            .arg(
+                Arg::new("include")
+                    .long("include")
+                    .value_name("regex")
+                    .help("Only record samples that have a function or filename matching this regex on the stack")
+                    .value_parser(parse_regex)
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::new("exclude")
+                    .long("exclude")
+                    .value_name("regex")
+                    .help("Remove frames whose function or filename match this regex from recorded samples")
+                    .value_parser(parse_regex)
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::new("focus")
+                    .long("focus")
+                    .value_name("function")
+                    .help("Only record samples that pass through a function matching this regex, with the stack re-rooted at that function")
+                    .value_parser(parse_regex)
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::new("label")
+                    .long("label")
+                    .value_name("name=expr")
+                    .help("Label each sample with a value read from the process, where expr is a module global like 'app.config.region', a contextvars.ContextVar like 'app.context.tenant' or a threading.local attribute like 'app.state.local.endpoint'")
+                    .value_parser(parse_label)
+                    .takes_value(true)
+                    .multiple_occurrences(true),
+            )
+            .arg(
+                Arg::new("instructions")
+                    .long("instructions")
+                    .help("Record the bytecode instruction running in each sample, and write out an annotated disassembly instead of a profile")
+                    .conflicts_with("format"),
+            )
             .arg(
                 Arg::new("capture")
                     .long("capture")
@@ -279,6 +439,13 @@ impl Config {
                     .help("Hides progress bar (useful for showing error output on record)"),
             );
 
+        #[cfg(target_os = "linux")]
+        let record = record.arg(
+            Arg::new("cpu_time")
+                .long("cpu-time")
+                .help("Weight samples by the CPU time each thread used, instead of counting whether the thread was sampled"),
+        );
+
         let top = Command::new("top")
             .about("Displays a top like view of functions consuming CPU")
             .arg(program.clone())
@@ -286,9 +453,33 @@ impl Config {
             .arg(rate.clone())
             .arg(subprocesses.clone())
             .arg(full_filenames.clone())
+            .arg(no_qualnames.clone())
             .arg(gil.clone())
             .arg(idle.clone())
-            .arg(top_delay.clone());
+            .arg(interpreters.clone())
+            .arg(aggregate.clone())
+            .arg(top_delay.clone())
+            .arg(
+                Arg::new("batch")
+                    .short('b')
+                    .long("batch")
+                    .help("Print the function table to stdout on each refresh, instead of running interactively"),
+            )
+            .arg(
+                Arg::new("iterations")
+                    .long("iterations")
+                    .value_name("count")
+                    .help("Exit after refreshing this many times in batch mode")
+                    .value_parser(clap::value_parser!(u64).range(1..))
+                    .takes_value(true)
+                    .requires("batch"),
+            )
+            .arg(
+                Arg::new("json")
+                    .long("json")
+                    .help("Print each refresh as a line of JSON in batch mode")
+                    .requires("batch"),
+            );
 
         #[cfg(target_os = "linux")]
         let dump_pid = pid.clone().required_unless_present("core");
@@ -296,8 +487,10 @@ impl Config {
         #[cfg(not(target_os = "linux"))]
         let dump_pid = pid.clone().required(true);
 
+        // 'd' is ambiguous between dump and diff, keep it as a shortcut for dump
         let dump = Command::new("dump")
             .about("Dumps stack traces for a target program to stdout")
+            .alias("d")
             .arg(dump_pid);
 
         #[cfg(target_os = "linux")]
@@ -307,10 +500,12 @@ impl Config {
                 .long("core")
                 .help("Filename of coredump to display python stack traces from")
                 .value_name("core")
-                .takes_value(true),
+                .takes_value(true)
+                .conflicts_with("subprocesses"),
         );
 
         let dump = dump.arg(full_filenames.clone())
+            .arg(no_qualnames.clone())
             .arg(Arg::new("locals")
                 .short('l')
                 .long("locals")
@@ -320,8 +515,132 @@ impl Config {
                 .short('j')
                 .long("json")
                 .help("Format output as JSON"))
+            .arg(interpreters.clone())
+            .arg(async_tasks.clone())
             .arg(subprocesses.clone());
 
+        let serve = Command::new("serve")
+            .about("Runs a HTTP server that returns stack traces and profiles from a target program on demand")
+            .arg(pid.clone().required(true))
+            .arg(
+                Arg::new("listen")
+                    .long("listen")
+                    .value_name("address")
+                    .help("Address to listen for HTTP requests on")
+                    .default_value("127.0.0.1:8080")
+                    .takes_value(true),
+            )
+            .arg(rate.clone())
+            .arg(subprocesses.clone())
+            .arg(full_filenames.clone())
+            .arg(no_qualnames.clone())
+            .arg(gil.clone())
+            .arg(idle.clone())
+            .arg(interpreters.clone())
+            .arg(async_tasks.clone());
+
+        let diff = Command::new("diff")
+            .about("Compares two profiles recorded in the raw or speedscope formats, writing a differential flamegraph")
+            .arg(
+                Arg::new("before")
+                    .help("Profile to compare against")
+                    .value_name("before")
+                    .required(true),
+            )
+            .arg(
+                Arg::new("after")
+                    .help("Profile to compare")
+                    .value_name("after")
+                    .required(true),
+            )
+            .arg(
+                Arg::new("output")
+                    .short('o')
+                    .long("output")
+                    .value_name("filename")
+                    .help("Output filename for the differential flamegraph")
+                    .default_value("diff.svg")
+                    .takes_value(true),
+            );
+
+        let convert = Command::new("convert")
+            .about("Converts a profile saved in the raw, speedscope or chrometrace formats to another format")
+            .arg(
+                Arg::new("from")
+                    .long("from")
+                    .value_name("format")
+                    .help("Format of the input file")
+                    .takes_value(true)
+                    .possible_values(["raw", "speedscope", "chrometrace"])
+                    .ignore_case(true)
+                    .required(true),
+            )
+            .arg(
+                Arg::new("to")
+                    .long("to")
+                    .value_name("format")
+                    .help("Format to convert to")
+                    .takes_value(true)
+                    .possible_values(FileFormat::possible_values())
+                    .ignore_case(true)
+                    .required(true),
+            )
+            .arg(
+                Arg::new("input")
+                    .help("Profile to convert")
+                    .value_name("input")
+                    .required(true),
+            )
+            .arg(
+                Arg::new("output")
+                    .help("Filename to write the converted profile to")
+                    .value_name("output")
+                    .required(true),
+            )
+            .arg(rate.clone().help("The number of samples per second the profile was recorded with"));
+
+        let report = Command::new("report")
+            .about("Renders a sample log recorded with '--format pyspy' to a flamegraph, speedscope, pprof or raw file")
+            .arg(
+                Arg::new("input")
+                    .help("Sample log to render")
+                    .value_name("file")
+                    .required(true),
+            )
+            .arg(
+                Arg::new("format")
+                    .short('f')
+                    .long("format")
+                    .value_name("format")
+                    .help("Output file format")
+                    .takes_value(true)
+                    .possible_values(FileFormat::possible_values().filter(|v| v.get_name() != "pyspy"))
+                    .ignore_case(true)
+                    .default_value("flamegraph"),
+            )
+            .arg(
+                Arg::new("output")
+                    .short('o')
+                    .long("output")
+                    .value_name("filename")
+                    .help("Output filename")
+                    .takes_value(true),
+            )
+            .arg(
+                Arg::new("nolineno")
+                    .long("nolineno")
+                    .help("Do not show line numbers"),
+            )
+            .arg(
+                Arg::new("threads")
+                    .short('t')
+                    .long("threads")
+                    .help("Show thread ids in the output"),
+            )
+            .arg(interpreters.clone())
+            .arg(gil.clone())
+            .arg(idle.clone());
+
         let completions = Command::new("completions")
             .about("Generate shell completions")
             .hide(true)
@@ -334,6 +653,7 @@ impl Config {
         let record = record.arg(native.clone());
         let top = top.arg(native.clone());
         let dump = dump.arg(native.clone());
+        let serve = serve.arg(native.clone());
 
         // Nonblocking isn't an option for freebsd, remove
         #[cfg(not(target_os = "freebsd"))]
@@ -342,6 +662,8 @@ impl Config {
         let top = top.arg(nonblocking.clone());
         #[cfg(not(target_os = "freebsd"))]
         let dump = dump.arg(nonblocking.clone());
+        #[cfg(not(target_os = "freebsd"))]
+        let serve = serve.arg(nonblocking.clone());
 
         let mut app = Command::new(crate_name!())
             .version(crate_version!())
@@ -353,6 +675,10 @@ impl Config {
             .subcommand(record)
             .subcommand(top)
             .subcommand(dump)
+            .subcommand(serve)
+            .subcommand(diff)
+            .subcommand(convert)
+            .subcommand(report)
             .subcommand(completions);
         let matches = app.clone().try_get_matches_from(args)?;
         info!("Command line args: {:?}", matches);
@@ -361,8 +687,9 @@ impl Config {
 
         let (subcommand, matches) = matches.subcommand().unwrap();
 
-        // Check if `--native` was used on an unsupported platform
-        if !cfg!(feature = "unwind") && matches.contains_id("native") {
+        // Check if `--native` was used on an unsupported platform. Subcommands that only read
+        // profiles from disk (diff, convert, report) don't have this argument
+        if !cfg!(feature = "unwind") && matches.try_contains_id("native").unwrap_or(false) {
             eprintln!(
                 "Collecting stack traces from native extensions (`--native`) is not supported on your platform."
             );
@@ -378,8 +705,16 @@ impl Config {
                         RecordDuration::Seconds(seconds.parse().expect("invalid duration"))
                     }
                 };
-                config.format = Some(matches.value_of_t("format")?);
-                config.filename = matches.value_of("output").map(|f| f.to_owned());
+                config.rotate_seconds = matches.get_one::<u64>("rotate").copied();
+                config.formats = matches.values_of_t("format")?;
+                config.filenames = matches
+                    .values_of("output")
+                    .map(|filenames| filenames.map(|f| f.to_owned()).collect())
+                    .unwrap_or_default();
+                config.instructions = matches.occurrences_of("instructions") > 0;
+                validate_outputs(&mut app, &config)?;
+                config.format = config.formats.first().copied();
+                config.filename = config.filenames.first().cloned();
                 config.show_line_numbers = matches.occurrences_of("nolineno") == 0;
                 config.lineno = if matches.occurrences_of("nolineno") > 0 {
                     LineNo::NoLine
@@ -389,26 +724,89 @@ impl Config {
                     LineNo::LastInstruction
                 };
                 config.include_thread_ids = matches.occurrences_of("threads") > 0;
+                config.async_tasks = matches.occurrences_of("async") > 0;
                 if matches.occurrences_of("nolineno") > 0 && matches.occurrences_of("function") > 0
                 {
                     eprintln!("--function & --nolinenos can't be used together");
                     std::process::exit(1);
                 }
                 config.hide_progress = matches.occurrences_of("hideprogress") > 0;
+                config.include = matches.get_one::<String>("include").cloned();
+                config.exclude = matches.get_one::<String>("exclude").cloned();
+                config.focus = matches.get_one::<String>("focus").cloned();
+                config.aggregate = matches.value_of_t("aggregate")?;
+                config.labels = matches
+                    .get_many::<(String, String)>("label")
+                    .map(|labels| labels.cloned().collect())
+                    .unwrap_or_default();
+                #[cfg(target_os = "linux")]
+                {
+                    config.cpu_time = matches.occurrences_of("cpu_time") > 0;
+                }
             }
             "top" => {
                 config.sampling_rate = matches.value_of_t("rate")?;
                 config.refresh_seconds = *matches.get_one::<f64>("delay").unwrap();
+                config.batch = matches.occurrences_of("batch") > 0;
+                config.batch_json = matches.occurrences_of("json") > 0;
+                config.iterations = matches.get_one::<u64>("iterations").copied();
+                config.aggregate = matches.value_of_t("aggregate")?;
             }
             "dump" => {
                 config.dump_json = matches.occurrences_of("json") > 0;
                 config.dump_locals = matches.occurrences_of("locals");
+                config.async_tasks = matches.occurrences_of("async") > 0;
 
                 #[cfg(target_os = "linux")]
                 {
                     config.core_filename = matches.value_of("core").map(|f| f.to_owned());
                 }
             }
+            "serve" => {
+                config.sampling_rate = matches.value_of_t("rate")?;
+                config.listen = matches.value_of("listen").map(|f| f.to_owned());
+                config.show_line_numbers = true;
+                config.async_tasks = matches.occurrences_of("async") > 0;
+            }
+            "diff" => {
+                // comparing profiles doesn't need any of the options for connecting to a process
+                config.command = subcommand.to_owned();
+                config.input_filenames = ["before", "after"]
+                    .iter()
+                    .filter_map(|arg| matches.value_of(arg).map(|f| f.to_owned()))
+                    .collect();
+                config.filename = matches.value_of("output").map(|f| f.to_owned());
+                return Ok(config);
+            }
+            "convert" => {
+                config.command = subcommand.to_owned();
+                config.input_format = Some(matches.value_of_t("from")?);
+                config.format = Some(matches.value_of_t("to")?);
+                config.sampling_rate = matches.value_of_t("rate")?;
+                config.input_filenames = matches
+                    .value_of("input")
+                    .map(|f| vec![f.to_owned()])
+                    .unwrap_or_default();
+                config.filename = matches.value_of("output").map(|f| f.to_owned());
+                config.show_line_numbers = true;
+                return Ok(config);
+            }
+            "report" => {
+                // samples are filtered and decorated when the log is rendered, not when recorded
+                config.command = subcommand.to_owned();
+                config.input_filenames = matches
+                    .value_of("input")
+                    .map(|f| vec![f.to_owned()])
+                    .unwrap_or_default();
+                config.format = Some(matches.value_of_t("format")?);
+                config.filename = matches.value_of("output").map(|f| f.to_owned());
+                config.show_line_numbers = matches.occurrences_of("nolineno") == 0;
+                config.include_thread_ids = matches.occurrences_of("threads") > 0;
+                config.include_interpreter_ids = matches.occurrences_of("interpreters") > 0;
+                config.gil_only = matches.occurrences_of("gil") > 0;
+                config.include_idle = matches.occurrences_of("idle") > 0;
+                return Ok(config);
+            }
             "completions" => {
                 let shell = matches.get_one::<clap_complete::Shell>("shell").unwrap();
                 let app_name = app.get_name().to_string();
@@ -426,6 +824,10 @@ impl Config {
                 config.gil_only = matches.occurrences_of("gil") > 0;
                 config.include_idle = matches.occurrences_of("idle") > 0;
             }
+            "serve" => {
+                config.gil_only = matches.occurrences_of("gil") > 0;
+                config.include_idle = matches.occurrences_of("idle") > 0;
+            }
             _ => {}
         }
 
@@ -442,6 +844,8 @@ impl Config {
         });
 
         config.full_filenames = matches.occurrences_of("full_filenames") > 0;
+        config.qualified_names = matches.occurrences_of("no_qualnames") == 0;
+        config.include_interpreter_ids = matches.occurrences_of("interpreters") > 0;
         if cfg!(feature = "unwind") {
             config.native = matches.occurrences_of("native") > 0;
         }
@@ -491,6 +895,94 @@ impl Config {
     }
 }
 
+/// Checks that the --output filenames can hold every --format being recorded
+fn validate_outputs(app: &mut Command, config: &Config) -> clap::Result<()> {
+    for (i, format) in config.formats.iter().enumerate() {
+        if config.formats[..i].contains(format) {
+            return Err(app.error(
+                clap::ErrorKind::ArgumentConflict,
+                format!("The '{format:?}' format was passed more than once"),
+            ));
+        }
+    }
+
+    // every format would be written out as the same disassembly
+    if config.instructions && config.formats.len() > 1 {
+        return Err(app.error(
+            clap::ErrorKind::ArgumentConflict,
+            "--instructions writes a single annotated disassembly, and can't be used with multiple formats",
+        ));
+    }
+
+    let stdout_outputs = config.filenames.iter().filter(|f| *f == "-").count();
+    if stdout_outputs > 1 {
+        return Err(app.error(
+            clap::ErrorKind::ArgumentConflict,
+            "Only one format can be written to stdout",
+        ));
+    }
+    if stdout_outputs > 0 && config.rotate_seconds.is_some() {
+        return Err(app.error(
+            clap::ErrorKind::ArgumentConflict,
+            "Can't use --rotate when writing to stdout",
+        ));
+    }
+
+    match config.filenames.as_slice() {
+        [] => Ok(()),
+        [filename] if config.formats.len() > 1 && !is_output_directory(filename) => {
+            Err(app.error(
+                clap::ErrorKind::WrongNumberOfValues,
+                format!("'{filename}' isn't a directory. When recording multiple formats, --output needs to be a directory (ending in '/' if it doesn't exist yet) or have a filename for each format"),
+            ))
+        }
+        [_] => Ok(()),
+        filenames if filenames.len() != config.formats.len() => Err(app.error(
+            clap::ErrorKind::WrongNumberOfValues,
+            format!(
+                "Got {} output filenames for {} formats, --output needs to have a filename for each format",
+                filenames.len(),
+                config.formats.len()
+            ),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Whether an --output filename is a directory to write files into, rather than a file
+pub fn is_output_directory(filename: &str) -> bool {
+    filename.ends_with(std::path::is_separator) || std::path::Path::new(filename).is_dir()
+}
+
+/// Checks that a regex passed on the command line compiles, so that mistakes are reported
+/// before we start sampling
+fn parse_regex(value: &str) -> Result<String, regex::Error> {
+    regex::Regex::new(value).map(|_| value.to_owned())
+}
+
+/// Splits a --label option into the label name and the expression to read its value from,
+/// which has to be a dotted path starting with a module name
+fn parse_label(value: &str) -> Result<(String, String), String> {
+    let (name, expression) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected name=expr, got '{value}'"))?;
+    if name.is_empty() {
+        return Err(format!("missing label name in '{value}'"));
+    }
+    let valid = expression.contains('.')
+        && expression.split('.').all(|part| {
+            !part.is_empty()
+                && !part.starts_with(|c: char| c.is_ascii_digit())
+                && part.chars().all(|c| c.is_alphanumeric() || c == '_')
+        });
+    if !valid {
+        return Err(format!(
+            "'{expression}' should be a dotted path starting with a module, like 'app.context.tenant'"
+        ));
+    }
+    Ok((name.to_owned(), expression.to_owned()))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -545,6 +1037,183 @@ mod tests {
         assert_eq!(config_flags.include_idle, true);
         assert_eq!(config_flags.gil_only, true);
         assert_eq!(config_flags.include_thread_ids, true);
+        assert!(!config.include_interpreter_ids);
+
+        let config_interpreters = get_config("py-spy r -p 1234 --interpreters").unwrap();
+        assert!(config_interpreters.include_interpreter_ids);
+
+        assert_eq!(config.rotate_seconds, None);
+        assert!(!config.cpu_time);
+        #[cfg(target_os = "linux")]
+        assert!(get_config("py-spy r -p 1234 --cpu-time").unwrap().cpu_time);
+        let config_rotate = get_config("py-spy r -p 1234 --rotate 60").unwrap();
+        assert_eq!(config_rotate.rotate_seconds, Some(60));
+        assert_eq!(
+            get_config("py-spy r -p 1234 --rotate 0").unwrap_err().kind,
+            clap::ErrorKind::ValueValidation
+        );
+
+        assert_eq!(config.include, None);
+        let config_filters =
+            get_config("py-spy r -p 1234 --include app/ --exclude ^_ --focus handle_request")
+                .unwrap();
+        assert_eq!(config_filters.include, Some(String::from("app/")));
+        assert_eq!(config_filters.exclude, Some(String::from("^_")));
+        assert_eq!(config_filters.focus, Some(String::from("handle_request")));
+        assert_eq!(
+            get_config("py-spy r -p 1234 --include (").unwrap_err().kind,
+            clap::ErrorKind::ValueValidation
+        );
+
+        assert!(config.qualified_names);
+        assert!(
+            !get_config("py-spy r -p 1234 --no-qualnames")
+                .unwrap()
+                .qualified_names
+        );
+
+        assert_eq!(config.aggregate, Aggregate::function);
+        let config_aggregate = get_config("py-spy r -p 1234 --aggregate package").unwrap();
+        assert_eq!(config_aggregate.aggregate, Aggregate::package);
+        assert_eq!(
+            get_config("py-spy r -p 1234 --aggregate class")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::InvalidValue
+        );
+
+        // packages are found from the short filenames, so can't be used with full filenames
+        assert_eq!(
+            get_config("py-spy r -p 1234 --aggregate package --full-filenames")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            get_config("py-spy top -p 1234 --aggregate module --full-filenames")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+        assert!(
+            get_config("py-spy r -p 1234 --full-filenames")
+                .unwrap()
+                .full_filenames
+        );
+
+        assert!(!config.instructions);
+        assert!(
+            get_config("py-spy r -p 1234 --instructions")
+                .unwrap()
+                .instructions
+        );
+        assert_eq!(
+            get_config("py-spy r -p 1234 --instructions -f speedscope")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            get_config("py-spy r -p 1234 --instructions -f pyspy,flamegraph")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+
+        assert!(config.labels.is_empty());
+        let config_labels =
+            get_config("py-spy r -p 1234 --label tenant=app.ctx.tenant --label region=app.REGION")
+                .unwrap();
+        assert_eq!(
+            config_labels.labels,
+            vec![
+                (String::from("tenant"), String::from("app.ctx.tenant")),
+                (String::from("region"), String::from("app.REGION"))
+            ]
+        );
+        for invalid in [
+            "tenant",
+            "=app.tenant",
+            "tenant=app",
+            "tenant=app..x",
+            "t=app.1x",
+        ] {
+            assert_eq!(
+                get_config(&format!("py-spy r -p 1234 --label {invalid}"))
+                    .unwrap_err()
+                    .kind,
+                clap::ErrorKind::ValueValidation
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_record_outputs() {
+        let config = get_config("py-spy r -p 1234 -o foo").unwrap();
+        assert_eq!(config.formats, vec![FileFormat::flamegraph]);
+        assert_eq!(config.filenames, vec!["foo"]);
+
+        // filenames can have commas in them
+        let config = get_config("py-spy r -p 1234 -o run,v2.svg").unwrap();
+        assert_eq!(config.filenames, vec!["run,v2.svg"]);
+        assert_eq!(config.filename, Some(String::from("run,v2.svg")));
+
+        // multiple formats with a filename for each, with the formats comma separated or repeated
+        let config =
+            get_config("py-spy r -p 1234 -f flamegraph,speedscope -o out.svg -o out.json").unwrap();
+        assert_eq!(
+            config.formats,
+            vec![FileFormat::flamegraph, FileFormat::speedscope]
+        );
+        assert_eq!(config.filenames, vec!["out.svg", "out.json"]);
+        assert_eq!(config.format, Some(FileFormat::flamegraph));
+        assert_eq!(config.filename, Some(String::from("out.svg")));
+        let repeated =
+            get_config("py-spy r -p 1234 -f flamegraph -f speedscope -o out.svg -o out.json")
+                .unwrap();
+        assert_eq!(config, repeated);
+
+        // or a directory to write them all to, or the default filenames
+        let config = get_config("py-spy r -p 1234 -f flamegraph,raw,pprof -o profiles/").unwrap();
+        assert_eq!(config.filenames, vec!["profiles/"]);
+        let config = get_config("py-spy r -p 1234 -f flamegraph,raw").unwrap();
+        assert!(config.filenames.is_empty());
+
+        // a single file can't hold multiple formats
+        assert_eq!(
+            get_config("py-spy r -p 1234 -f flamegraph,raw -o out.svg")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::WrongNumberOfValues
+        );
+        assert_eq!(
+            get_config("py-spy r -p 1234 -f flamegraph,raw,pprof -o out.svg -o out.txt")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::WrongNumberOfValues
+        );
+        assert_eq!(
+            get_config("py-spy r -p 1234 -f raw,raw -o profiles/")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+
+        // writing to stdout
+        let config = get_config("py-spy r -p 1234 -f raw,flamegraph -o - -o out.svg").unwrap();
+        assert_eq!(config.filenames, vec!["-", "out.svg"]);
+        assert_eq!(
+            get_config("py-spy r -p 1234 -f raw,pprof -o - -o -")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+        assert_eq!(
+            get_config("py-spy r -p 1234 -o - --rotate 60")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
     }
 
     #[test]
@@ -558,6 +1227,21 @@ mod tests {
         let short_config = get_config("py-spy d -p 1234").unwrap();
         assert_eq!(config, short_config);
 
+        let config = get_config("py-spy dump --pid 1234 --interpreters").unwrap();
+        assert!(config.include_interpreter_ids);
+
+        let config = get_config("py-spy dump --pid 1234 --async").unwrap();
+        assert!(config.async_tasks);
+
+        // a core dump only holds the memory of a single process
+        #[cfg(target_os = "linux")]
+        assert_eq!(
+            get_config("py-spy dump --core core --subprocesses")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::ArgumentConflict
+        );
+
         // missing the --pid argument should fail
         assert_eq!(
             get_config("py-spy dump").unwrap_err().kind,
@@ -575,6 +1259,101 @@ mod tests {
         // short version
         let short_config = get_config("py-spy t -p 1234").unwrap();
         assert_eq!(config, short_config);
+        assert!(!config.batch);
+
+        let batch_config = get_config("py-spy top -p 1234 --batch --iterations 5 --json").unwrap();
+        assert!(batch_config.batch);
+        assert!(batch_config.batch_json);
+        assert_eq!(batch_config.iterations, Some(5));
+
+        let aggregate_config = get_config("py-spy top -p 1234 --aggregate module").unwrap();
+        assert_eq!(aggregate_config.aggregate, Aggregate::module);
+
+        // the batch options only make sense in batch mode
+        assert_eq!(
+            get_config("py-spy top -p 1234 --iterations 5")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::MissingRequiredArgument
+        );
+    }
+
+    #[test]
+    fn test_parse_serve_args() {
+        let config = get_config("py-spy serve --pid 1234").unwrap();
+        assert_eq!(config.pid, Some(1234));
+        assert_eq!(config.command, String::from("serve"));
+        assert_eq!(config.listen, Some(String::from("127.0.0.1:8080")));
+
+        let config = get_config("py-spy serve -p 1234 --listen 0.0.0.0:9000 --gil").unwrap();
+        assert_eq!(config.listen, Some(String::from("0.0.0.0:9000")));
+        assert!(config.gil_only);
+
+        // serve needs a pid to attach to
+        assert_eq!(
+            get_config("py-spy serve --listen 0.0.0.0:9000")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::MissingRequiredArgument
+        );
+    }
+
+    #[test]
+    fn test_parse_diff_args() {
+        let config = get_config("py-spy diff before.txt after.txt").unwrap();
+        assert_eq!(config.command, String::from("diff"));
+        assert_eq!(config.input_filenames, vec!["before.txt", "after.txt"]);
+        assert_eq!(config.filename, Some(String::from("diff.svg")));
+
+        let config = get_config("py-spy diff before.txt after.json -o out.svg").unwrap();
+        assert_eq!(config.filename, Some(String::from("out.svg")));
+
+        assert_eq!(
+            get_config("py-spy diff before.txt").unwrap_err().kind,
+            clap::ErrorKind::MissingRequiredArgument
+        );
+    }
+
+    #[test]
+    fn test_parse_convert_args() {
+        let config =
+            get_config("py-spy convert --from raw --to speedscope in.txt out.json").unwrap();
+        assert_eq!(config.command, String::from("convert"));
+        assert_eq!(config.input_format, Some(FileFormat::raw));
+        assert_eq!(config.format, Some(FileFormat::speedscope));
+        assert_eq!(config.input_filenames, vec!["in.txt"]);
+        assert_eq!(config.filename, Some(String::from("out.json")));
+
+        // only formats that can be parsed back in can be converted from
+        assert_eq!(
+            get_config("py-spy convert --from flamegraph --to raw in.svg out.txt")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::InvalidValue
+        );
+    }
+
+    #[test]
+    fn test_parse_report_args() {
+        let config = get_config("py-spy record -p 1234 -f pyspy").unwrap();
+        assert_eq!(config.format, Some(FileFormat::pyspy));
+
+        let config = get_config("py-spy report profile.pyspy -f speedscope --idle -t").unwrap();
+        assert_eq!(config.command, String::from("report"));
+        assert_eq!(config.input_filenames, vec!["profile.pyspy"]);
+        assert_eq!(config.format, Some(FileFormat::speedscope));
+        assert_eq!(config.filename, None);
+        assert!(config.include_idle);
+        assert!(config.include_thread_ids);
+        assert!(config.show_line_numbers);
+
+        // rendering a sample log to another sample log doesn't make sense
+        assert_eq!(
+            get_config("py-spy report profile.pyspy -f pyspy")
+                .unwrap_err()
+                .kind,
+            clap::ErrorKind::InvalidValue
+        );
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io;
 use std::io::{BufReader, Read, Write};
 use std::sync::{atomic, Arc, Mutex};
@@ -7,6 +7,9 @@ use std::vec::Vec;
 
 use anyhow::Error;
 use console::{style, Term};
+use regex::Regex;
+use remoteprocess::Pid;
+use serde_derive::Serialize;
 
 use crate::config::Config;
 use crate::stack_trace::{Frame, StackTrace};
@@ -14,7 +17,7 @@ use crate::version::Version;
 
 pub struct ConsoleViewer {
     #[allow(dead_code)]
-    console_config: os_impl::ConsoleConfig,
+    console_config: Option<os_impl::ConsoleConfig>,
     version: Option<Version>,
     command: String,
     sampling_rate: f64,
@@ -23,6 +26,7 @@ pub struct ConsoleViewer {
     stats: Stats,
     subprocesses: bool,
     config: Config,
+    refreshes: u64,
 }
 
 impl ConsoleViewer {
@@ -36,6 +40,22 @@ impl ConsoleViewer {
         let running = Arc::new(atomic::AtomicBool::new(true));
         let options = Arc::new(Mutex::new(Options::new(show_linenumbers)));
 
+        // batch mode just prints to stdout, without needing a terminal to read keys from
+        if config.batch {
+            return Ok(ConsoleViewer {
+                console_config: None,
+                version: version.clone(),
+                command: python_command.to_owned(),
+                running,
+                options,
+                sampling_rate,
+                subprocesses: config.subprocesses,
+                stats: Stats::new(),
+                config: config.clone(),
+                refreshes: 0,
+            });
+        }
+
         // listen for keyboard events in a separate thread to avoid blocking here
         let input_running = running.clone();
         let input_options = options.clone();
@@ -43,32 +63,20 @@ impl ConsoleViewer {
             let stdin = std::io::stdin();
             let mut buf_reader = BufReader::new(stdin);
             let mut buffer = [0u8; 1];
+            let mut decoder = KeyDecoder::default();
             while input_running.load(atomic::Ordering::Relaxed) {
                 // TODO: there isn't a non-blocking version of stdin, so this will capture the
                 // next keystroke after the ConsoleViewer object has been destroyed =(
                 if buf_reader.read_exact(&mut buffer).is_ok() {
-                    let mut options = input_options.lock().unwrap();
-                    options.dirty = true;
-                    let previous_usage = options.usage;
-                    match buffer[0] as char {
-                        'R' | 'r' => options.reset = true,
-                        'L' | 'l' => options.show_linenumbers = !options.show_linenumbers,
-                        'X' | 'x' => options.usage = false,
-                        '?' => options.usage = true,
-                        '1' => options.sort_column = 1,
-                        '2' => options.sort_column = 2,
-                        '3' => options.sort_column = 3,
-                        '4' => options.sort_column = 4,
-                        _ => {}
+                    if let Some(key) = decoder.decode(buffer[0] as char) {
+                        input_options.lock().unwrap().handle_key(key);
                     }
-
-                    options.reset_style = previous_usage != options.usage;
                 }
             }
         });
 
         Ok(ConsoleViewer {
-            console_config: os_impl::ConsoleConfig::new()?,
+            console_config: Some(os_impl::ConsoleConfig::new()?),
             version: version.clone(),
             command: python_command.to_owned(),
             running,
@@ -77,6 +85,7 @@ impl ConsoleViewer {
             subprocesses: config.subprocesses,
             stats: Stats::new(),
             config: config.clone(),
+            refreshes: 0,
         })
     }
 
@@ -84,13 +93,20 @@ impl ConsoleViewer {
         self.maybe_reset();
         self.stats.threads = 0;
         self.stats.processes = 0;
+        self.stats.interpreters = 0;
         let mut last_pid = None;
+        let mut last_interpreter = None;
         for trace in traces {
             self.stats.threads += 1;
+            self.stats.live_threads.insert((trace.pid, trace.thread_id));
             if last_pid != Some(trace.pid) {
                 self.stats.processes += 1;
                 last_pid = Some(trace.pid);
             }
+            if last_interpreter != Some((trace.pid, trace.interpreter_id)) {
+                self.stats.interpreters += 1;
+                last_interpreter = Some((trace.pid, trace.interpreter_id));
+            }
 
             if !(self.config.include_idle || trace.active) {
                 continue;
@@ -108,50 +124,94 @@ impl ConsoleViewer {
                 self.stats.active += 1
             }
 
-            update_function_statistics(&mut self.stats.line_counts, trace, |frame| {
+            // group functions by interpreter if requested, by prefixing with the interpreter id
+            let interpreter = match trace.interpreter_id {
+                Some(id) if self.config.include_interpreter_ids => format!("[interpreter {id}] "),
+                _ => String::new(),
+            };
+
+            let line_key = |frame: &Frame| {
                 let filename = match &frame.short_filename {
                     Some(f) => f,
                     None => &frame.filename,
                 };
                 if frame.line != 0 {
-                    format!("{} ({}:{})", frame.name, filename, frame.line)
+                    format!(
+                        "{}{} ({}:{})",
+                        interpreter,
+                        frame.display_name(),
+                        filename,
+                        frame.line
+                    )
+                } else if !filename.is_empty() {
+                    format!("{}{} ({})", interpreter, frame.display_name(), filename)
                 } else {
-                    format!("{} ({})", frame.name, filename)
+                    format!("{}{}", interpreter, frame.display_name())
                 }
-            });
+            };
 
-            update_function_statistics(&mut self.stats.function_counts, trace, |frame| {
+            let function_key = |frame: &Frame| {
                 let filename = match &frame.short_filename {
                     Some(f) => f,
                     None => &frame.filename,
                 };
-                format!("{} ({})", frame.name, filename)
-            });
+                if !filename.is_empty() {
+                    format!("{}{} ({})", interpreter, frame.display_name(), filename)
+                } else {
+                    format!("{}{}", interpreter, frame.display_name())
+                }
+            };
+
+            self.stats.counts.update(trace, line_key, function_key);
+
+            // keep statistics for each thread too, so that the view can be filtered by thread
+            let thread = (trace.pid, trace.thread_id);
+            if !self.stats.thread_counts.contains_key(&thread) {
+                let mut options = self.options.lock().unwrap();
+                if !options.threads.iter().any(|(t, _)| *t == thread) {
+                    let name = match &trace.thread_name {
+                        Some(name) => format!("{} ({})", name, trace.format_threadid()),
+                        None => trace.format_threadid(),
+                    };
+                    let name = if self.subprocesses {
+                        format!("{} in process {}", name, trace.pid)
+                    } else {
+                        name
+                    };
+                    options.threads.push((thread, name));
+                }
+            }
+            self.stats.thread_counts.entry(thread).or_default().update(
+                trace,
+                line_key,
+                function_key,
+            );
         }
         self.increment_common()?;
         Ok(())
     }
 
     pub fn display(&self) -> std::io::Result<()> {
+        if self.config.batch {
+            let stdout = std::io::stdout();
+            let mut out = stdout.lock();
+            self.write_batch(&mut out)?;
+            return out.flush();
+        }
+        let console_config = match self.console_config.as_ref() {
+            Some(console_config) => console_config,
+            None => return Ok(()),
+        };
+
         // Get the top aggregate function calls (either by line or by function as )
         let mut options = self.options.lock().unwrap();
         options.dirty = false;
-        let counts = if options.show_linenumbers {
-            &self.stats.line_counts
-        } else {
-            &self.stats.function_counts
+        let empty = Counts::default();
+        let thread_counts = match options.thread_filter {
+            Some(thread) => self.stats.thread_counts.get(&thread).unwrap_or(&empty),
+            None => &self.stats.counts,
         };
-        let mut counts: Vec<(&FunctionStatistics, &str)> =
-            counts.iter().map(|(x, y)| (y, x.as_ref())).collect();
-
-        // TODO: subsort ?
-        match options.sort_column {
-            1 => counts.sort_unstable_by(|a, b| b.0.current_own.cmp(&a.0.current_own)),
-            2 => counts.sort_unstable_by(|a, b| b.0.current_total.cmp(&a.0.current_total)),
-            3 => counts.sort_unstable_by(|a, b| b.0.overall_own.cmp(&a.0.overall_own)),
-            4 => counts.sort_unstable_by(|a, b| b.0.overall_total.cmp(&a.0.overall_total)),
-            _ => panic!("unknown sort column. this really shouldn't happen"),
-        }
+        let counts = sorted_function_counts(thread_counts, &options);
         let term = Term::stdout();
         let (height, width) = term.size();
         let width = width as usize;
@@ -166,12 +226,12 @@ impl ConsoleViewer {
 
         if options.reset_style {
             #[cfg(windows)]
-            self.console_config.reset_styles()?;
+            console_config.reset_styles()?;
             options.reset_style = false;
         }
 
-        self.console_config.reset_cursor()?;
-        let mut header_lines = if options.usage { 18 } else { 8 };
+        console_config.reset_cursor()?;
+        let mut header_lines = if options.usage { 23 } else { 8 };
 
         if let Some(delay) = self.stats.last_delay {
             let late_rate = self.stats.late_samples as f64 / self.stats.overall_samples as f64;
@@ -208,11 +268,25 @@ impl ConsoleViewer {
             out!("Total Samples {}", style(self.stats.overall_samples).bold());
         }
 
+        // free-threaded builds run without a GIL, so there is no usage to report
+        let gil = if self.version.as_ref().is_some_and(|v| v.free_threaded) {
+            "n/a".to_owned()
+        } else {
+            format!(
+                "{:.2}%",
+                100.0 * self.stats.gil as f64 / self.stats.current_samples as f64
+            )
+        };
         out!(
-            "GIL: {:.2}%, Active: {:>.2}%, Threads: {}{}",
-            style(100.0 * self.stats.gil as f64 / self.stats.current_samples as f64).bold(),
+            "GIL: {}, Active: {:>.2}%, Threads: {}{}{}",
+            style(gil).bold(),
             style(100.0 * self.stats.active as f64 / self.stats.current_samples as f64).bold(),
             style(self.stats.threads).bold(),
+            if self.config.include_interpreter_ids {
+                format!(", Interpreters {}", style(self.stats.interpreters).bold())
+            } else {
+                "".to_owned()
+            },
             if self.subprocesses {
                 format!(", Processes {}", style(self.stats.processes).bold())
             } else {
@@ -220,6 +294,20 @@ impl ConsoleViewer {
             }
         );
 
+        let mut filters = Vec::new();
+        if let Some(filter) = &options.filter {
+            filters.push(format!("matching '{}'", filter.as_str()));
+        }
+        if let Some(thread) = options.thread_filter {
+            if let Some((_, name)) = options.threads.iter().find(|(t, _)| *t == thread) {
+                filters.push(format!("from thread {name}"));
+            }
+        }
+        if !filters.is_empty() {
+            out!("Showing functions {}", style(filters.join(" ")).bold());
+            header_lines += 1;
+        }
+
         out!();
 
         // Build up the header for the table
@@ -235,10 +323,11 @@ impl ConsoleViewer {
             _ => {}
         }
 
-        let function_header = if options.show_linenumbers {
-            style("  Function (filename:line)").reverse()
-        } else {
-            style("  Function (filename)").reverse()
+        let function_header = match (options.tree, options.show_linenumbers) {
+            (true, true) => style("  Call tree (filename:line)").reverse(),
+            (true, false) => style("  Call tree (filename)").reverse(),
+            (false, true) => style("  Function (filename:line)").reverse(),
+            (false, false) => style("  Function (filename)").reverse(),
         };
 
         // If we aren't at least 50 characters wide, lets use two lines per entry
@@ -260,18 +349,62 @@ impl ConsoleViewer {
             width = max_function_width
         );
 
+        let rows = height as usize - header_lines;
+        options.page_size = rows;
         let mut written = 0;
-        for (samples, label) in counts.iter().take(height as usize - header_lines) {
-            out!(
-                "{:>6.2}% {:>6.2}% {:>7}s {:>8}s   {:.width$}",
-                100.0 * samples.current_own as f64 / (self.stats.current_samples as f64),
-                100.0 * samples.current_total as f64 / (self.stats.current_samples as f64),
-                display_time(samples.overall_own as f64 * self.sampling_rate),
-                display_time(samples.overall_total as f64 * self.sampling_rate),
-                label,
-                width = max_function_width - 2
-            );
-            written += 1;
+        if options.tree {
+            let tree = if options.show_linenumbers {
+                &thread_counts.line_tree
+            } else {
+                &thread_counts.function_tree
+            };
+            let tree_rows = update_call_tree_selection(tree, &mut options);
+
+            // scroll so that the selected node is always visible
+            let start = options.selected.saturating_sub(rows.saturating_sub(1));
+            for (i, row) in tree_rows.iter().enumerate().skip(start).take(rows) {
+                let marker = match (row.has_children, row.expanded) {
+                    (false, _) => " ",
+                    (true, false) => "+",
+                    (true, true) => "-",
+                };
+                let label = format!(
+                    "{:indent$}{} {}",
+                    "",
+                    marker,
+                    row.path.last().unwrap(),
+                    indent = 2 * (row.path.len() - 1)
+                );
+                let label = format!("{:.width$}", label, width = max_function_width - 2);
+                let label = if i == options.selected {
+                    style(label).reverse()
+                } else {
+                    style(label)
+                };
+                out!(
+                    "{:>6.2}% {:>6.2}% {:>7}s {:>8}s   {}",
+                    100.0 * row.stats.current_own as f64 / (self.stats.current_samples as f64),
+                    100.0 * row.stats.current_total as f64 / (self.stats.current_samples as f64),
+                    display_time(row.stats.overall_own as f64 * self.sampling_rate),
+                    display_time(row.stats.overall_total as f64 * self.sampling_rate),
+                    label
+                );
+                written += 1;
+            }
+        } else {
+            options.scroll = options.scroll.min(counts.len().saturating_sub(rows));
+            for (samples, label) in counts.iter().skip(options.scroll).take(rows) {
+                out!(
+                    "{:>6.2}% {:>6.2}% {:>7}s {:>8}s   {:.width$}",
+                    100.0 * samples.current_own as f64 / (self.stats.current_samples as f64),
+                    100.0 * samples.current_total as f64 / (self.stats.current_samples as f64),
+                    display_time(samples.overall_own as f64 * self.sampling_rate),
+                    display_time(samples.overall_total as f64 * self.sampling_rate),
+                    label,
+                    width = max_function_width - 2
+                );
+                written += 1;
+            }
         }
         for _ in written..height as usize - header_lines {
             out!();
@@ -311,13 +444,41 @@ impl ConsoleViewer {
                 "L,l",
                 "Toggle between aggregating by line number or by function"
             );
+            out!(
+                "{:^12}{:<}",
+                "C,c",
+                "Toggle between the flat function table and the call tree"
+            );
+            out!(
+                "{:^12}{:<}",
+                "arrows",
+                "Select a call tree node, and expand (right) or collapse (left) it"
+            );
+            out!(
+                "{:^12}{:<}",
+                "/",
+                "Filter by a regex of the function name or filename (Enter to apply)"
+            );
+            out!(
+                "{:^12}{:<}",
+                "T,t",
+                "Cycle through showing the functions from a single thread"
+            );
+            out!("{:^12}{:<}", "PgUp,PgDn", "Scroll through the table");
             out!("{:^12}{:<}", "R,r", "Reset statistics");
             out!("{:^12}{:<}", "X,x", "Exit this help screen");
             out!();
             //println!("{:^12}{:<}", "Control-C", "Quit py-spy");
+        } else if let Some(search) = &options.search {
+            out!("Search: {}", style(search).bold());
         } else {
+            let error = match &options.filter_error {
+                Some(error) => format!("{} ", style(error).red()),
+                None => String::new(),
+            };
             out!(
-                "Press {} to quit, or {} for help.",
+                "{}Press {} to quit, or {} for help.",
+                error,
                 style("Control-C").bold().reverse(),
                 style("?").bold().reverse()
             );
@@ -327,6 +488,85 @@ impl ConsoleViewer {
         Ok(())
     }
 
+    /// Writes the function table for the current refresh as plain text or as a line of JSON,
+    /// for running somewhere without a terminal
+    fn write_batch(&self, out: &mut dyn Write) -> std::io::Result<()> {
+        let options = self.options.lock().unwrap();
+        let mut counts = sorted_function_counts(&self.stats.counts, &options);
+        // only include functions that were seen since the last refresh
+        counts.retain(|(stats, _)| stats.current_total > 0);
+
+        let current_samples = self.stats.current_samples as f64;
+        // free-threaded builds run without a GIL, so there is no usage to report
+        let gil = if self.version.as_ref().is_some_and(|v| v.free_threaded) {
+            None
+        } else {
+            Some(100.0 * self.stats.gil as f64 / current_samples)
+        };
+        let active = 100.0 * self.stats.active as f64 / current_samples;
+
+        if self.config.batch_json {
+            let refresh = BatchRefresh {
+                total_samples: self.stats.overall_samples,
+                samples: self.stats.current_samples,
+                errors: self.stats.errors,
+                gil,
+                active,
+                threads: self.stats.threads,
+                functions: counts
+                    .iter()
+                    .map(|(stats, label)| BatchFunction {
+                        function: label,
+                        own: 100.0 * stats.current_own as f64 / current_samples,
+                        total: 100.0 * stats.current_total as f64 / current_samples,
+                        own_time: stats.overall_own as f64 * self.sampling_rate,
+                        total_time: stats.overall_total as f64 * self.sampling_rate,
+                    })
+                    .collect(),
+            };
+            writeln!(out, "{}", serde_json::to_string(&refresh)?)?;
+        } else {
+            writeln!(
+                out,
+                "Total Samples {}, GIL: {}, Active: {:.2}%, Threads: {}",
+                self.stats.overall_samples,
+                gil.map_or_else(|| "n/a".to_owned(), |gil| format!("{gil:.2}%")),
+                active,
+                self.stats.threads
+            )?;
+            let function_header = if options.show_linenumbers {
+                "Function (filename:line)"
+            } else {
+                "Function (filename)"
+            };
+            writeln!(
+                out,
+                "{:>7} {:>7} {:>8} {:>9}   {}",
+                "%Own", "%Total", "OwnTime", "TotalTime", function_header
+            )?;
+            for (stats, label) in &counts {
+                writeln!(
+                    out,
+                    "{:>6.2}% {:>6.2}% {:>7}s {:>8}s   {}",
+                    100.0 * stats.current_own as f64 / current_samples,
+                    100.0 * stats.current_total as f64 / current_samples,
+                    display_time(stats.overall_own as f64 * self.sampling_rate),
+                    display_time(stats.overall_total as f64 * self.sampling_rate),
+                    label
+                )?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the number of refreshes asked for in batch mode have been printed
+    pub fn finished(&self) -> bool {
+        self.config
+            .iterations
+            .is_some_and(|iterations| self.refreshes >= iterations)
+    }
+
     pub fn increment_error(&mut self, err: &Error) -> Result<(), Error> {
         self.maybe_reset();
         self.stats.errors += 1;
@@ -340,6 +580,11 @@ impl ConsoleViewer {
     }
 
     pub fn should_refresh(&self) -> bool {
+        // batch mode prints at a steady rate, since there's nobody watching the first samples come in
+        if self.config.batch {
+            return self.stats.elapsed >= self.config.refresh_seconds;
+        }
+
         // update faster if we only have a few samples, or if we changed options
         match self.stats.overall_samples {
             10 | 100 | 500 => true,
@@ -359,10 +604,26 @@ impl ConsoleViewer {
         if self.should_refresh() {
             self.display()?;
             self.stats.reset_current();
+            self.prune_threads();
+            self.refreshes += 1;
         }
         Ok(())
     }
 
+    /// Removes the threads that have exited from the list of threads to filter by
+    fn prune_threads(&mut self) {
+        let mut options = self.options.lock().unwrap();
+        let thread_counts = &self.stats.thread_counts;
+        options
+            .threads
+            .retain(|(thread, _)| thread_counts.contains_key(thread));
+        if let Some(thread) = options.thread_filter {
+            if !thread_counts.contains_key(&thread) {
+                options.thread_filter = None;
+            }
+        }
+    }
+
     fn maybe_reset(&mut self) {
         let mut options = self.options.lock().unwrap();
         if options.reset {
@@ -378,7 +639,7 @@ impl Drop for ConsoleViewer {
     }
 }
 
-#[derive(Eq, PartialEq, Ord, PartialOrd, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Debug, Default)]
 struct FunctionStatistics {
     current_own: u64,
     current_total: u64,
@@ -417,6 +678,284 @@ fn update_function_statistics<K>(
     }
 }
 
+/// Gets the rows of the flat function table (either by line or by function), filtered
+/// and sorted by the current options
+fn sorted_function_counts<'a>(
+    counts: &'a Counts,
+    options: &Options,
+) -> Vec<(&'a FunctionStatistics, &'a str)> {
+    let counts = if options.show_linenumbers {
+        &counts.line_counts
+    } else {
+        &counts.function_counts
+    };
+    let mut counts: Vec<(&FunctionStatistics, &str)> =
+        counts.iter().map(|(x, y)| (y, x.as_ref())).collect();
+    if let Some(filter) = &options.filter {
+        counts.retain(|(_, label)| filter.is_match(label));
+    }
+
+    // TODO: subsort ?
+    let sort_column = options.sort_column;
+    counts.sort_unstable_by_key(|(stats, _)| std::cmp::Reverse(sort_value(stats, sort_column)));
+    counts
+}
+
+/// A single refresh of the function table, as printed by `top --batch --json`
+#[derive(Serialize)]
+struct BatchRefresh<'a> {
+    total_samples: u64,
+    samples: u64,
+    errors: u64,
+    gil: Option<f64>,
+    active: f64,
+    threads: u64,
+    functions: Vec<BatchFunction<'a>>,
+}
+
+#[derive(Serialize)]
+struct BatchFunction<'a> {
+    function: &'a str,
+    own: f64,
+    total: f64,
+    own_time: f64,
+    total_time: f64,
+}
+
+/// A node in the call tree, keyed by the callers of the function from the root of the stack
+#[derive(Debug, Default)]
+struct CallNode {
+    stats: FunctionStatistics,
+    children: HashMap<String, CallNode>,
+}
+
+fn update_call_tree<K>(root: &mut CallNode, trace: &StackTrace, key_func: K)
+where
+    K: Fn(&Frame) -> String,
+{
+    // frames are stored from the leaf up, so walk them backwards to go from caller to callee
+    let mut node = root;
+    for (i, frame) in trace.frames.iter().enumerate().rev() {
+        node = node.children.entry(key_func(frame)).or_default();
+        node.stats.current_total += 1;
+        node.stats.overall_total += 1;
+
+        if i == 0 {
+            node.stats.current_own += 1;
+            node.stats.overall_own += 1;
+        }
+    }
+}
+
+fn reset_call_tree(node: &mut CallNode) {
+    for child in node.children.values_mut() {
+        child.stats.current_total = 0;
+        child.stats.current_own = 0;
+        reset_call_tree(child);
+    }
+}
+
+/// A visible row of the call tree, identified by the path of labels from the root to it
+struct CallTreeRow<'a> {
+    path: Vec<String>,
+    stats: &'a FunctionStatistics,
+    has_children: bool,
+    expanded: bool,
+}
+
+/// Flattens the expanded nodes of the call tree into rows, with each node's children sorted
+/// by the same column as the flat function table. When filtering, only nodes that match or
+/// that call a matching function are kept, and callers are expanded down to their matches
+fn flatten_call_tree<'a>(
+    node: &'a CallNode,
+    path: &mut Vec<String>,
+    options: &Options,
+    rows: &mut Vec<CallTreeRow<'a>>,
+) {
+    let mut children: Vec<(&String, &CallNode)> = node.children.iter().collect();
+    children.sort_unstable_by(|a, b| {
+        let a_value = sort_value(&a.1.stats, options.sort_column);
+        let b_value = sort_value(&b.1.stats, options.sort_column);
+        b_value.cmp(&a_value).then(a.0.cmp(b.0))
+    });
+
+    for (label, child) in children {
+        if let Some(filter) = &options.filter {
+            if !call_tree_matches(label, child, filter) {
+                continue;
+            }
+        }
+        path.push(label.clone());
+        let expanded = options.expanded.contains(path)
+            || matches!(&options.filter, Some(filter) if !filter.is_match(label));
+        rows.push(CallTreeRow {
+            path: path.clone(),
+            stats: &child.stats,
+            has_children: !child.children.is_empty(),
+            expanded,
+        });
+        if expanded {
+            flatten_call_tree(child, path, options, rows);
+        }
+        path.pop();
+    }
+}
+
+/// Whether a node in the call tree, or any of the functions it calls, matches a filter
+fn call_tree_matches(label: &str, node: &CallNode, filter: &Regex) -> bool {
+    filter.is_match(label)
+        || node
+            .children
+            .iter()
+            .any(|(label, child)| call_tree_matches(label, child, filter))
+}
+
+/// Applies any pending expand/collapse action to the selected node of the call tree, and
+/// returns the rows to display with the selection clamped to them
+fn update_call_tree_selection<'a>(
+    tree: &'a CallNode,
+    options: &mut Options,
+) -> Vec<CallTreeRow<'a>> {
+    let mut rows = Vec::new();
+    flatten_call_tree(tree, &mut Vec::new(), options, &mut rows);
+    options.selected = options.selected.min(rows.len().saturating_sub(1));
+
+    let action = match options.tree_action.take() {
+        Some(action) => action,
+        None => return rows,
+    };
+    let (path, has_children) = match rows.get(options.selected) {
+        Some(row) => (row.path.clone(), row.has_children),
+        None => return rows,
+    };
+    let expanded = options.expanded.contains(&path);
+    match action {
+        TreeAction::Expand | TreeAction::Toggle if has_children && !expanded => {
+            options.expanded.insert(path);
+        }
+        TreeAction::Collapse | TreeAction::Toggle if expanded => {
+            options.expanded.remove(&path);
+        }
+        // collapsing a node that isn't expanded moves up to its parent instead
+        TreeAction::Collapse => {
+            let parent = &path[..path.len() - 1];
+            if let Some(index) = rows.iter().position(|row| row.path == parent) {
+                options.selected = index;
+            }
+        }
+        _ => {}
+    }
+
+    let mut rows = Vec::new();
+    flatten_call_tree(tree, &mut Vec::new(), options, &mut rows);
+    rows
+}
+
+fn sort_value(stats: &FunctionStatistics, sort_column: i32) -> u64 {
+    match sort_column {
+        1 => stats.current_own,
+        2 => stats.current_total,
+        3 => stats.overall_own,
+        4 => stats.overall_total,
+        _ => panic!("unknown sort column. this really shouldn't happen"),
+    }
+}
+
+/// The statistics for each function and line, both as a flat table and as a call tree
+#[derive(Debug, Default)]
+struct Counts {
+    function_counts: HashMap<String, FunctionStatistics>,
+    line_counts: HashMap<String, FunctionStatistics>,
+    function_tree: CallNode,
+    line_tree: CallNode,
+}
+
+impl Counts {
+    fn update<L, F>(&mut self, trace: &StackTrace, line_key: L, function_key: F)
+    where
+        L: Fn(&Frame) -> String + Copy,
+        F: Fn(&Frame) -> String + Copy,
+    {
+        update_function_statistics(&mut self.line_counts, trace, line_key);
+        update_function_statistics(&mut self.function_counts, trace, function_key);
+        update_call_tree(&mut self.line_tree, trace, line_key);
+        update_call_tree(&mut self.function_tree, trace, function_key);
+    }
+
+    fn reset_current(&mut self) {
+        for val in self.line_counts.values_mut() {
+            val.current_total = 0;
+            val.current_own = 0;
+        }
+
+        for val in self.function_counts.values_mut() {
+            val.current_total = 0;
+            val.current_own = 0;
+        }
+
+        reset_call_tree(&mut self.line_tree);
+        reset_call_tree(&mut self.function_tree);
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Key {
+    Char(char),
+    Up,
+    Down,
+    Left,
+    Right,
+    PageUp,
+    PageDown,
+}
+
+/// Turns the escape sequences that terminals send for the arrow and page up/down keys
+/// (like '\x1b[A' or '\x1b[5~') into single keys
+#[derive(Default)]
+struct KeyDecoder {
+    escape: Option<String>,
+}
+
+impl KeyDecoder {
+    fn decode(&mut self, c: char) -> Option<Key> {
+        let mut sequence = match self.escape.take() {
+            Some(sequence) => sequence,
+            None if c == '\x1b' => {
+                self.escape = Some(String::new());
+                return None;
+            }
+            None => return Some(Key::Char(c)),
+        };
+
+        // an escape that isn't followed by '[' isn't a sequence we know about
+        if sequence.is_empty() && c != '[' {
+            return Some(Key::Char(c));
+        }
+        sequence.push(c);
+        if (c == '[' && sequence.len() == 1) || c.is_ascii_digit() {
+            self.escape = Some(sequence);
+            return None;
+        }
+
+        match sequence.as_str() {
+            "[A" => Some(Key::Up),
+            "[B" => Some(Key::Down),
+            "[C" => Some(Key::Right),
+            "[D" => Some(Key::Left),
+            "[5~" => Some(Key::PageUp),
+            "[6~" => Some(Key::PageDown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum TreeAction {
+    Expand,
+    Collapse,
+    Toggle,
+}
+
 struct Options {
     dirty: bool,
     usage: bool,
@@ -424,6 +963,17 @@ struct Options {
     sort_column: i32,
     show_linenumbers: bool,
     reset: bool,
+    tree: bool,
+    selected: usize,
+    expanded: HashSet<Vec<String>>,
+    tree_action: Option<TreeAction>,
+    scroll: usize,
+    page_size: usize,
+    search: Option<String>,
+    filter: Option<Regex>,
+    filter_error: Option<String>,
+    threads: Vec<((Pid, u64), String)>,
+    thread_filter: Option<(Pid, u64)>,
 }
 
 struct Stats {
@@ -434,10 +984,12 @@ struct Stats {
     late_samples: u64,
     threads: u64,
     processes: u64,
+    interpreters: u64,
     active: u64,
     gil: u64,
-    function_counts: HashMap<String, FunctionStatistics>,
-    line_counts: HashMap<String, FunctionStatistics>,
+    counts: Counts,
+    thread_counts: HashMap<(Pid, u64), Counts>,
+    live_threads: HashSet<(Pid, u64)>,
     last_error: Option<String>,
     last_delay: Option<std::time::Duration>,
 }
@@ -451,8 +1003,101 @@ impl Options {
             sort_column: 3,
             show_linenumbers,
             reset_style: false,
+            tree: false,
+            selected: 0,
+            expanded: HashSet::new(),
+            tree_action: None,
+            scroll: 0,
+            page_size: 1,
+            search: None,
+            filter: None,
+            filter_error: None,
+            threads: Vec::new(),
+            thread_filter: None,
         }
     }
+
+    fn handle_key(&mut self, key: Key) {
+        self.dirty = true;
+
+        // while typing a search, keys are added to it rather than being shortcuts
+        if let Some(search) = self.search.as_mut() {
+            match key {
+                Key::Char('\n') => {
+                    let search = self.search.take().unwrap_or_default();
+                    self.set_filter(&search);
+                }
+                Key::Char('\x7f' | '\x08') => {
+                    search.pop();
+                }
+                Key::Char(c) if !c.is_control() => search.push(c),
+                _ => {}
+            }
+            return;
+        }
+
+        let previous_usage = self.usage;
+        match key {
+            Key::Up if self.tree => self.selected = self.selected.saturating_sub(1),
+            Key::Down if self.tree => self.selected += 1,
+            Key::Up => self.scroll = self.scroll.saturating_sub(1),
+            Key::Down => self.scroll += 1,
+            Key::PageUp if self.tree => {
+                self.selected = self.selected.saturating_sub(self.page_size)
+            }
+            Key::PageDown if self.tree => self.selected += self.page_size,
+            Key::PageUp => self.scroll = self.scroll.saturating_sub(self.page_size),
+            Key::PageDown => self.scroll += self.page_size,
+            Key::Right => self.tree_action = Some(TreeAction::Expand),
+            Key::Left => self.tree_action = Some(TreeAction::Collapse),
+            Key::Char('R' | 'r') => self.reset = true,
+            Key::Char('L' | 'l') => self.show_linenumbers = !self.show_linenumbers,
+            Key::Char('C' | 'c') => self.tree = !self.tree,
+            Key::Char(' ' | '\n') => self.tree_action = Some(TreeAction::Toggle),
+            Key::Char('T' | 't') => self.next_thread_filter(),
+            Key::Char('/') => self.search = Some(String::new()),
+            Key::Char('X' | 'x') => self.usage = false,
+            Key::Char('?') => self.usage = true,
+            Key::Char('1') => self.sort_column = 1,
+            Key::Char('2') => self.sort_column = 2,
+            Key::Char('3') => self.sort_column = 3,
+            Key::Char('4') => self.sort_column = 4,
+            _ => {}
+        }
+
+        self.reset_style = previous_usage != self.usage;
+    }
+
+    /// Filters the functions shown to those matching a regex, or shows every function again
+    /// if the regex is empty
+    fn set_filter(&mut self, pattern: &str) {
+        self.filter = None;
+        self.filter_error = None;
+        if !pattern.is_empty() {
+            match Regex::new(pattern) {
+                Ok(filter) => self.filter = Some(filter),
+                Err(_) => self.filter_error = Some(format!("Invalid regex '{pattern}'.")),
+            }
+        }
+        self.scroll = 0;
+        self.selected = 0;
+    }
+
+    /// Moves on to showing only the next thread, going back to showing all threads
+    /// after the last one
+    fn next_thread_filter(&mut self) {
+        let next = match self.thread_filter {
+            Some(thread) => self
+                .threads
+                .iter()
+                .position(|(t, _)| *t == thread)
+                .map_or(0, |i| i + 1),
+            None => 0,
+        };
+        self.thread_filter = self.threads.get(next).map(|(thread, _)| *thread);
+        self.scroll = 0;
+        self.selected = 0;
+    }
 }
 
 impl Stats {
@@ -465,10 +1110,12 @@ impl Stats {
             late_samples: 0,
             threads: 0,
             processes: 0,
+            interpreters: 0,
             gil: 0,
             active: 0,
-            line_counts: HashMap::new(),
-            function_counts: HashMap::new(),
+            counts: Counts::default(),
+            thread_counts: HashMap::new(),
+            live_threads: HashSet::new(),
             last_error: None,
             last_delay: None,
         }
@@ -476,15 +1123,15 @@ impl Stats {
 
     pub fn reset_current(&mut self) {
         // reset current statistics
-        for val in self.line_counts.values_mut() {
-            val.current_total = 0;
-            val.current_own = 0;
-        }
-
-        for val in self.function_counts.values_mut() {
-            val.current_total = 0;
-            val.current_own = 0;
+        self.counts.reset_current();
+        for counts in self.thread_counts.values_mut() {
+            counts.reset_current();
         }
+        // forget the threads that haven't been seen since the last reset, which have exited
+        let live_threads = &self.live_threads;
+        self.thread_counts
+            .retain(|thread, _| live_threads.contains(thread));
+        self.live_threads.clear();
         self.gil = 0;
         self.active = 0;
         self.current_samples = 0;
@@ -678,3 +1325,186 @@ mod os_impl {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(names: &[&str]) -> StackTrace {
+        crate::stack_trace::tests::trace(
+            names
+                .iter()
+                .map(|name| crate::stack_trace::tests::frame(name, "app.py", 0))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_call_tree() {
+        let key = |frame: &Frame| frame.name.clone();
+        let mut tree = CallNode::default();
+        update_call_tree(&mut tree, &trace(&["helper", "slow", "main"]), key);
+        update_call_tree(&mut tree, &trace(&["helper", "slow", "main"]), key);
+        update_call_tree(&mut tree, &trace(&["helper", "fast", "main"]), key);
+        update_call_tree(&mut tree, &trace(&["main"]), key);
+
+        let main = &tree.children["main"];
+        assert_eq!(main.stats.overall_total, 4);
+        assert_eq!(main.stats.overall_own, 1);
+        assert_eq!(
+            main.children["slow"].children["helper"].stats.overall_own,
+            2
+        );
+        assert_eq!(
+            main.children["fast"].children["helper"].stats.overall_own,
+            1
+        );
+
+        // only the root is visible until it's expanded
+        let mut options = Options::new(false);
+        options.sort_column = 4;
+        let rows = update_call_tree_selection(&tree, &mut options);
+        assert_eq!(rows.len(), 1);
+        assert!(rows[0].has_children);
+
+        options.tree_action = Some(TreeAction::Expand);
+        let rows = update_call_tree_selection(&tree, &mut options);
+        let paths: Vec<String> = rows.iter().map(|row| row.path.join(";")).collect();
+        assert_eq!(paths, vec!["main", "main;slow", "main;fast"]);
+
+        // collapsing a child that isn't expanded selects its parent
+        options.selected = 2;
+        options.tree_action = Some(TreeAction::Collapse);
+        update_call_tree_selection(&tree, &mut options);
+        assert_eq!(options.selected, 0);
+
+        options.tree_action = Some(TreeAction::Toggle);
+        let rows = update_call_tree_selection(&tree, &mut options);
+        assert_eq!(rows.len(), 1);
+
+        // the selection is clamped to the visible rows
+        options.selected = 10;
+        update_call_tree_selection(&tree, &mut options);
+        assert_eq!(options.selected, 0);
+
+        reset_call_tree(&mut tree);
+        assert_eq!(tree.children["main"].stats.current_total, 0);
+        assert_eq!(tree.children["main"].stats.overall_total, 4);
+    }
+
+    #[test]
+    fn test_key_decoder() {
+        let mut decoder = KeyDecoder::default();
+        let keys: Vec<Key> = "a\x1b[A\x1b[5~\x1b[6~\x1bx\x1b[C"
+            .chars()
+            .filter_map(|c| decoder.decode(c))
+            .collect();
+        assert_eq!(
+            keys,
+            vec![
+                Key::Char('a'),
+                Key::Up,
+                Key::PageUp,
+                Key::PageDown,
+                Key::Char('x'),
+                Key::Right
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filters() {
+        let mut options = Options::new(false);
+        for c in "/wor[\x7fk\n".chars() {
+            options.handle_key(Key::Char(c));
+        }
+        assert!(options.search.is_none());
+        assert_eq!(options.filter.as_ref().unwrap().as_str(), "work");
+
+        // the filter shows any callers of a matching function in the call tree, expanded
+        // down to the matching functions themselves
+        let key = |frame: &Frame| frame.name.clone();
+        let mut tree = CallNode::default();
+        update_call_tree(&mut tree, &trace(&["helper", "work", "main"]), key);
+        update_call_tree(&mut tree, &trace(&["sleep", "idle"]), key);
+        let rows = update_call_tree_selection(&tree, &mut options);
+        let paths: Vec<String> = rows.iter().map(|row| row.path.join(";")).collect();
+        assert_eq!(paths, vec!["main", "main;work"]);
+        assert!(rows[0].expanded);
+        assert!(!rows[1].expanded);
+
+        for c in "/(\n".chars() {
+            options.handle_key(Key::Char(c));
+        }
+        assert!(options.filter.is_none());
+        assert!(options.filter_error.is_some());
+
+        // cycling through the threads ends back at showing every thread
+        options.threads = vec![((1, 1), String::from("a")), ((1, 2), String::from("b"))];
+        options.handle_key(Key::Char('t'));
+        assert_eq!(options.thread_filter, Some((1, 1)));
+        options.handle_key(Key::Char('t'));
+        assert_eq!(options.thread_filter, Some((1, 2)));
+        options.handle_key(Key::Char('t'));
+        assert_eq!(options.thread_filter, None);
+
+        options.page_size = 10;
+        options.handle_key(Key::PageDown);
+        options.handle_key(Key::Up);
+        assert_eq!(options.scroll, 9);
+    }
+
+    #[test]
+    fn test_reset_exited_threads() {
+        let mut stats = Stats::new();
+        stats.thread_counts.insert((1, 1), Counts::default());
+        stats.thread_counts.insert((1, 2), Counts::default());
+        stats.live_threads.insert((1, 2));
+        stats.reset_current();
+        let threads: Vec<&(Pid, u64)> = stats.thread_counts.keys().collect();
+        assert_eq!(threads, vec![&(1, 2)]);
+        assert!(stats.live_threads.is_empty());
+    }
+
+    #[test]
+    fn test_batch_output() {
+        let config = Config {
+            batch: true,
+            batch_json: true,
+            iterations: Some(1),
+            ..Default::default()
+        };
+        let mut viewer = ConsoleViewer::new(false, "python", &None, &config).unwrap();
+        viewer
+            .stats
+            .counts
+            .update(&trace(&["work", "main"]), key_func, key_func);
+        viewer.stats.current_samples = 2;
+        viewer.stats.overall_samples = 2;
+        viewer.stats.threads = 1;
+
+        let mut out = Vec::new();
+        viewer.write_batch(&mut out).unwrap();
+        let refresh: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(refresh["total_samples"], 2);
+        assert_eq!(refresh["functions"][0]["function"], "work");
+        assert_eq!(refresh["functions"][0]["own"], 50.0);
+        assert_eq!(refresh["functions"][1]["function"], "main");
+        assert_eq!(refresh["functions"][1]["own"], 0.0);
+
+        viewer.config.batch_json = false;
+        let mut out = Vec::new();
+        viewer.write_batch(&mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("Total Samples 2, GIL: 0.00%, Active: 0.00%, Threads: 1\n"));
+        assert!(out.contains(" 50.00%  50.00%   0.010s    0.010s   work\n"));
+
+        assert!(!viewer.finished());
+        viewer.refreshes = 1;
+        assert!(viewer.finished());
+    }
+
+    fn key_func(frame: &Frame) -> String {
+        frame.name.clone()
+    }
+}
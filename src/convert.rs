@@ -0,0 +1,244 @@
+use std::fs::File;
+use std::io::BufWriter;
+
+use anyhow::{Context, Error};
+
+use crate::chrometrace;
+use crate::config::{Config, FileFormat};
+use crate::flamegraph;
+use crate::new_recorder;
+use crate::speedscope;
+use crate::stack_trace::{Frame, StackTrace};
+
+/// Converts a saved profile to a different file format, by reading the samples back in
+/// and replaying them through the recorder for the new format
+pub fn convert(config: &Config) -> Result<(), Error> {
+    let input = config
+        .input_filenames
+        .first()
+        .ok_or_else(|| format_err!("A profile to convert is required"))?;
+    let output = config
+        .filename
+        .as_deref()
+        .ok_or_else(|| format_err!("An output filename is required"))?;
+
+    let contents = std::fs::read_to_string(input)
+        .with_context(|| format!("Failed to read profile '{}'", input))?;
+    let samples = read_samples(&contents, config)
+        .with_context(|| format!("Failed to parse profile '{}'", input))?;
+
+    let mut recorder = new_recorder(config)?;
+    for (timestamp, trace) in samples.iter() {
+        // profiles recorded with --cpu-time keep their weights, except in chrome traces which
+        // place each sample on a timeline instead
+        match trace.cpu_time {
+            Some(cpu_time) if config.format != Some(FileFormat::chrometrace) => {
+                recorder.increment_cpu_time(trace, cpu_time)?
+            }
+            _ => recorder.increment_at(trace, *timestamp)?,
+        }
+    }
+
+    let mut out = BufWriter::new(
+        File::create(output).with_context(|| format!("Failed to create file '{}'", output))?,
+    );
+    recorder.write(&mut out)?;
+    println!("Wrote {} samples to '{}'", samples.len(), output);
+    Ok(())
+}
+
+/// Reads the samples from a saved profile, along with the time in microseconds
+/// since the start of the profile that each sample was taken
+fn read_samples(contents: &str, config: &Config) -> Result<Vec<(u64, StackTrace)>, Error> {
+    let mut samples = match config.input_format {
+        Some(FileFormat::raw) => read_raw_samples(contents, config.sampling_rate)?,
+        Some(FileFormat::speedscope) => speedscope::read_traces(contents, config.sampling_rate)?,
+        Some(FileFormat::chrometrace) => chrometrace::read_traces(contents, config.sampling_rate)?,
+        Some(format) => {
+            return Err(format_err!(
+                "Converting from {:?} files isn't supported",
+                format
+            ))
+        }
+        None => return Err(format_err!("An input file format is required")),
+    };
+    // samples from different threads get read separately, interleave them back together
+    samples.sort_by_key(|(timestamp, _)| *timestamp);
+    Ok(samples)
+}
+
+/// Raw files only store the number of times each stack was seen, without any thread or timing
+/// information, so the samples are given consecutive timestamps
+fn read_raw_samples(contents: &str, sampling_rate: u64) -> Result<Vec<(u64, StackTrace)>, Error> {
+    let mut counts: Vec<(String, usize)> = flamegraph::read_raw(contents)?.into_iter().collect();
+    counts.sort();
+
+    let interval = 1_000_000 / sampling_rate.max(1);
+    let mut samples = Vec::new();
+    for (stack, count) in counts {
+        let trace = StackTrace {
+            pid: 0,
+            thread_id: 0,
+            thread_name: None,
+            os_thread_id: None,
+            interpreter_id: None,
+            task_name: None,
+            active: true,
+            owns_gil: false,
+            cpu_time: None,
+            labels: Vec::new(),
+            frames: stack.split(';').rev().map(parse_frame).collect(),
+            process_info: None,
+        };
+        for _ in 0..count {
+            samples.push((samples.len() as u64 * interval, trace.clone()));
+        }
+    }
+    Ok(samples)
+}
+
+/// Parses a frame from a raw file, formatted like 'function (filename:line)'
+fn parse_frame(frame: &str) -> Frame {
+    let (name, filename, line) = match frame
+        .strip_suffix(')')
+        .and_then(|frame| frame.rsplit_once(" ("))
+    {
+        Some((name, location)) => match location.rsplit_once(':') {
+            Some((filename, line)) if line.parse::<i32>().is_ok() => {
+                (name, filename, line.parse().unwrap_or(0))
+            }
+            _ => (name, location, 0),
+        },
+        None => (frame, "", 0),
+    };
+    Frame {
+        name: name.to_owned(),
+        qualname: None,
+        filename: filename.to_owned(),
+        module: None,
+        short_filename: None,
+        line,
+        columns: None,
+        instruction: None,
+        locals: None,
+        is_entry: true,
+        is_shim_entry: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn replay(samples: &[(u64, StackTrace)], config: &Config) -> String {
+        let mut recorder = new_recorder(config).unwrap();
+        for (timestamp, trace) in samples {
+            recorder.increment_at(trace, *timestamp).unwrap();
+        }
+        let mut out = Vec::new();
+        recorder.write(&mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_convert_round_trip() {
+        let frame = parse_frame("process (app.py:12)");
+        assert_eq!(frame.name, "process");
+        assert_eq!(frame.filename, "app.py");
+        assert_eq!(frame.line, 12);
+        assert_eq!(parse_frame("<lambda> (app.py)").filename, "app.py");
+        assert_eq!(parse_frame("thread (0x7F00)").filename, "0x7F00");
+
+        let raw = "main (app.py:1);work (app.py:5) 3\nmain (app.py:1) 1\n";
+        let config = Config {
+            input_format: Some(FileFormat::raw),
+            show_line_numbers: true,
+            ..Default::default()
+        };
+        let samples = read_samples(raw, &config).unwrap();
+        assert_eq!(samples.len(), 4);
+        assert_eq!(samples[1].0, 10_000);
+
+        // converting through each of the timeline formats should keep the same samples
+        for format in [FileFormat::speedscope, FileFormat::chrometrace] {
+            let converted = replay(
+                &samples,
+                &Config {
+                    format: Some(format),
+                    ..config.clone()
+                },
+            );
+            let round_trip = read_samples(
+                &converted,
+                &Config {
+                    input_format: Some(format),
+                    ..config.clone()
+                },
+            )
+            .unwrap();
+
+            let raw_config = Config {
+                format: Some(FileFormat::raw),
+                ..config.clone()
+            };
+            let mut lines: Vec<String> = replay(&round_trip, &raw_config)
+                .lines()
+                .map(|line| line.to_owned())
+                .collect();
+            lines.sort();
+            assert_eq!(
+                lines,
+                vec!["main (app.py:1) 1", "main (app.py:1);work (app.py:5) 3"],
+                "failed to convert {:?}",
+                format
+            );
+        }
+    }
+
+    #[test]
+    fn test_speedscope_threads() {
+        let frame = Frame {
+            name: String::from("run"),
+            qualname: None,
+            filename: String::from("worker.py"),
+            module: None,
+            short_filename: None,
+            line: 7,
+            columns: None,
+            instruction: None,
+            locals: None,
+            is_entry: true,
+            is_shim_entry: false,
+        };
+        let trace = StackTrace {
+            pid: 1234,
+            thread_id: 0x7f00,
+            thread_name: Some(String::from("worker")),
+            os_thread_id: Some(42),
+            interpreter_id: None,
+            task_name: None,
+            active: true,
+            owns_gil: false,
+            cpu_time: None,
+            labels: Vec::new(),
+            frames: vec![frame],
+            process_info: None,
+        };
+        let config = Config {
+            format: Some(FileFormat::speedscope),
+            input_format: Some(FileFormat::speedscope),
+            show_line_numbers: true,
+            subprocesses: true,
+            ..Default::default()
+        };
+        let converted = replay(&[(0, trace)], &config);
+        let samples = read_samples(&converted, &config).unwrap();
+        assert_eq!(samples.len(), 1);
+        let trace = &samples[0].1;
+        assert_eq!(trace.pid, 1234);
+        #[cfg(not(target_os = "macos"))]
+        assert_eq!(trace.os_thread_id, Some(42));
+        assert_eq!(trace.thread_name.as_deref(), Some("worker"));
+        assert_eq!(trace.frames[0].line, 7);
+    }
+}
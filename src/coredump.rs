@@ -1,3 +1,4 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
 use std::ffi::OsStr;
 use std::fs::File;
@@ -9,27 +10,38 @@ use std::path::PathBuf;
 use anyhow::{Context, Error, Result};
 use console::style;
 use log::info;
-use remoteprocess::ProcessMemory;
+use memmap2::Mmap;
+use remoteprocess::{Pid, ProcessMemory};
+use serde_derive::Serialize;
 
 use crate::binary_parser::{parse_binary, BinaryInfo};
 use crate::config::Config;
-use crate::dump::print_trace;
+use crate::dump::{print_interpreter, print_trace};
+#[cfg(feature = "unwind")]
+use crate::native_coredump::{CoreSymbolicator, CoreUnwinder};
+#[cfg(feature = "unwind")]
+use crate::native_stack_trace::NativeStack;
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
 use crate::python_bindings::{
-    v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0, v3_9_5,
+    v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_13_0t, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0,
+    v3_9_5,
 };
 use crate::python_data_access::format_variable;
+use crate::python_debug_offsets::DebugInterpreterState;
 use crate::python_interpreters::InterpreterState;
 use crate::python_process_info::{
-    get_interpreter_address, get_python_version, get_threadstate_address, is_python_lib,
-    ContainsAddr, PythonProcessInfo,
+    get_debug_offsets, get_interpreter_address, get_python_version, get_threadstate_address,
+    is_python_lib, ContainsAddr, PythonProcessInfo,
 };
-use crate::python_threading::thread_names_from_interpreter;
-use crate::stack_trace::{get_stack_traces, StackTrace};
+use crate::python_threading::thread_names_from_interpreters;
+use crate::stack_trace::{get_stack_traces, ProcessInfo, StackTrace};
 use crate::version::Version;
 
 #[derive(Debug, Clone)]
 pub struct CoreMapRange {
     pub pathname: Option<PathBuf>,
+    /// The offset into the mapped file that this segment starts at
+    pub file_offset: u64,
     pub segment: goblin::elf::ProgramHeader,
 }
 
@@ -66,9 +78,10 @@ impl ContainsAddr for Vec<CoreMapRange> {
 pub struct CoreDump {
     filename: PathBuf,
     contents: Vec<u8>,
-    maps: Vec<CoreMapRange>,
+    pub(crate) maps: Vec<CoreMapRange>,
     psinfo: Option<elfcore::elf_prpsinfo>,
     status: Vec<elfcore::elf_prstatus>,
+    mapped_files: RefCell<HashMap<PathBuf, Option<Mmap>>>,
 }
 
 impl CoreDump {
@@ -91,7 +104,9 @@ impl CoreDump {
                 psinfo = Some(unsafe {
                     std::ptr::read_unaligned(note.desc.as_ptr() as *const elfcore::elf_prpsinfo)
                 });
-            } else if note.n_type == goblin::elf::note::NT_PRSTATUS {
+            } else if note.n_type == goblin::elf::note::NT_PRSTATUS
+                && note.desc.len() >= std::mem::size_of::<elfcore::elf_prstatus>()
+            {
                 let thread_status: elfcore::elf_prstatus = unsafe {
                     std::ptr::read_unaligned(note.desc.as_ptr() as *const elfcore::elf_prstatus)
                 };
@@ -101,7 +116,7 @@ impl CoreDump {
                 let ptrs = data.as_ptr() as *const usize;
 
                 let count = unsafe { std::ptr::read_unaligned(ptrs) };
-                let _page_size = unsafe { std::ptr::read_unaligned(ptrs.offset(1)) };
+                let page_size = unsafe { std::ptr::read_unaligned(ptrs.offset(1)) };
 
                 let string_table = &data[(std::mem::size_of::<usize>() * (2 + count * 3))..];
 
@@ -110,11 +125,11 @@ impl CoreDump {
                         let i = i as isize;
                         let start = unsafe { std::ptr::read_unaligned(ptrs.offset(i * 3 + 2)) };
                         let _end = unsafe { std::ptr::read_unaligned(ptrs.offset(i * 3 + 3)) };
-                        let _page_offset =
+                        let page_offset =
                             unsafe { std::ptr::read_unaligned(ptrs.offset(i * 3 + 4)) };
 
                         let pathname = Path::new(&OsStr::from_bytes(filename)).to_path_buf();
-                        filenames.insert(start, pathname);
+                        filenames.insert(start, (pathname, (page_offset * page_size) as u64));
                     }
                 }
             }
@@ -123,9 +138,13 @@ impl CoreDump {
         let mut maps = Vec::new();
         for ph in elf.program_headers {
             if ph.p_type == goblin::elf::program_header::PT_LOAD {
-                let pathname = filenames.get(&(ph.p_vaddr as _));
+                let (pathname, file_offset) = match filenames.get(&(ph.p_vaddr as _)) {
+                    Some((pathname, file_offset)) => (Some(pathname.clone()), *file_offset),
+                    None => (None, 0),
+                };
                 let map = CoreMapRange {
-                    pathname: pathname.cloned(),
+                    pathname,
+                    file_offset,
                     segment: ph,
                 };
                 info!(
@@ -150,37 +169,91 @@ impl CoreDump {
             maps,
             psinfo,
             status,
+            mapped_files: RefCell::new(HashMap::new()),
+        })
+    }
+
+    /// Gets the pid and command line of the process from the core dump
+    pub fn process_info(&self) -> Option<ProcessInfo> {
+        let psinfo = self.psinfo.as_ref()?;
+        // pr_psargs is a fixed size, nul terminated buffer
+        let args = &psinfo.pr_psargs;
+        let length = args.iter().position(|&c| c == 0).unwrap_or(args.len());
+        Some(ProcessInfo {
+            pid: psinfo.pr_pid as Pid,
+            command_line: String::from_utf8_lossy(&args[..length])
+                .trim_end()
+                .to_owned(),
+            parent: None,
         })
     }
+
+    /// Reads memory from a single segment, returning the number of bytes read. Since the read
+    /// could span multiple segments, this can be less than the size of the buffer
+    fn read_segment(&self, addr: u64, buf: &mut [u8]) -> Option<usize> {
+        let map = self.maps.iter().find(|map| {
+            let ph = &map.segment;
+            addr >= ph.p_vaddr && addr < ph.p_vaddr + ph.p_memsz
+        })?;
+        let ph = &map.segment;
+        let segment_offset = addr - ph.p_vaddr;
+        let length = buf.len().min((ph.p_memsz - segment_offset) as usize);
+
+        // read from the core dump if this memory was written out to it
+        if segment_offset < ph.p_filesz {
+            let length = length.min((ph.p_filesz - segment_offset) as usize);
+            let offset = (ph.p_offset + segment_offset) as usize;
+            let data = self.contents.get(offset..offset + length)?;
+            buf[..length].copy_from_slice(data);
+            return Some(length);
+        }
+
+        // By default, linux doesn't include read only file backed mappings in core dumps (like
+        // the code and constant data of the python binary). Read these from the mapped file
+        let pathname = map.pathname.as_ref()?;
+        let mut mapped_files = self.mapped_files.borrow_mut();
+        let mapped_file = mapped_files.entry(pathname.clone()).or_insert_with(|| {
+            let file = File::open(pathname).ok()?;
+            unsafe { Mmap::map(&file).ok() }
+        });
+        let offset = (map.file_offset + segment_offset) as usize;
+        let data = mapped_file.as_ref()?.get(offset..offset + length)?;
+        buf[..length].copy_from_slice(data);
+        Some(length)
+    }
 }
 
 impl ProcessMemory for CoreDump {
     fn read(&self, addr: usize, buf: &mut [u8]) -> Result<(), remoteprocess::Error> {
-        let start = addr as u64;
-        let _end = (addr + buf.len()) as u64;
-
-        for map in &self.maps {
-            // TODO: one issue here is the bss addr spans multiple mmap segments - so checking the 'end'
-            // here means we skip it. Instead we're just checking if the start address exists in
-            // the segment
-            let ph = &map.segment;
-            if start >= ph.p_vaddr && start <= (ph.p_vaddr + ph.p_memsz) {
-                let offset = (start - ph.p_vaddr + ph.p_offset) as usize;
-                buf.copy_from_slice(&self.contents[offset..(offset + buf.len())]);
-                return Ok(());
+        let mut addr = addr as u64;
+        let mut buf = buf;
+        while !buf.is_empty() {
+            match self.read_segment(addr, buf) {
+                Some(length) if length > 0 => {
+                    addr += length as u64;
+                    buf = &mut buf[length..];
+                }
+                _ => {
+                    let io_error = std::io::Error::from_raw_os_error(libc::EFAULT);
+                    return Err(remoteprocess::Error::IOError(io_error));
+                }
             }
         }
-
-        let io_error = std::io::Error::from_raw_os_error(libc::EFAULT);
-        Err(remoteprocess::Error::IOError(io_error))
+        Ok(())
     }
 }
 
 pub struct PythonCoreDump {
     core: CoreDump,
     version: Version,
+    debug_offsets: Option<_Py_DebugOffsets>,
     interpreter_address: usize,
     threadstate_address: usize,
+    // only needed to symbolicate native frames
+    #[cfg(feature = "unwind")]
+    python_binary: Option<BinaryInfo>,
+    #[cfg(feature = "unwind")]
+    libpython_binary: Option<BinaryInfo>,
 }
 
 impl PythonCoreDump {
@@ -241,27 +314,41 @@ impl PythonCoreDump {
             get_python_version(&python_info, &core).context("failed to get python version")?;
         info!("Got python version {}", version);
 
-        let interpreter_address = get_interpreter_address(&python_info, &core, &version)?;
+        let debug_offsets = get_debug_offsets(&python_info, &core, &version)?;
+
+        let interpreter_address =
+            get_interpreter_address(&python_info, &core, debug_offsets.as_ref(), &version)?;
         info!("Found interpreter at 0x{:016x}", interpreter_address);
 
         // lets us figure out which thread has the GIL
         let config = Config::default();
-        let threadstate_address =
-            get_threadstate_address(interpreter_address, &python_info, &core, &version, &config)?;
+        let threadstate_address = get_threadstate_address(
+            interpreter_address,
+            &python_info,
+            &core,
+            debug_offsets.as_ref(),
+            &version,
+            &config,
+        )?;
         info!("found threadstate at 0x{:016x}", threadstate_address);
 
         Ok(PythonCoreDump {
             core,
             version,
+            debug_offsets,
             interpreter_address,
             threadstate_address,
+            #[cfg(feature = "unwind")]
+            python_binary: python_info.python_binary,
+            #[cfg(feature = "unwind")]
+            libpython_binary: python_info.libpython_binary,
         })
     }
 
     pub fn get_stack(&self, config: &Config) -> Result<Vec<StackTrace>, Error> {
-        if config.native {
+        if config.native && !cfg!(feature = "unwind") {
             return Err(format_err!(
-                "Native unwinding isn't yet supported with coredumps"
+                "Native unwinding isn't supported with coredumps on your platform"
             ));
         }
 
@@ -271,6 +358,12 @@ impl PythonCoreDump {
             ));
         }
 
+        if config.async_tasks {
+            return Err(format_err!(
+                "asyncio tasks aren't yet supported with coredumps"
+            ));
+        }
+
         // different versions have different layouts, check as appropriate
         match self.version {
             Version {
@@ -313,11 +406,22 @@ impl PythonCoreDump {
                 minor: 12,
                 ..
             } => self._get_stack::<v3_12_0::_is>(config),
+            Version {
+                major: 3,
+                minor: 13,
+                free_threaded: true,
+                ..
+            } => self._get_stack::<v3_13_0t::_is>(config),
             Version {
                 major: 3,
                 minor: 13,
                 ..
             } => self._get_stack::<v3_13_0::_is>(config),
+            Version {
+                major: 3,
+                minor: 14..,
+                ..
+            } => self._get_stack::<DebugInterpreterState>(config),
             _ => Err(format_err!(
                 "Unsupported version of Python: {}",
                 self.version
@@ -329,27 +433,79 @@ impl PythonCoreDump {
         let mut traces = get_stack_traces::<I, CoreDump>(
             self.interpreter_address,
             &self.core,
+            self.debug_offsets.as_ref(),
             self.threadstate_address,
             Some(config),
         )?;
-        let thread_names = thread_names_from_interpreter::<I, CoreDump>(
+        let thread_names = thread_names_from_interpreters::<I, CoreDump>(
             self.interpreter_address,
             &self.core,
+            self.debug_offsets.as_ref(),
             &self.version,
         )
         .ok();
+        let pid = self
+            .core
+            .process_info()
+            .map(|process_info| process_info.pid);
+
+        #[cfg(feature = "unwind")]
+        let mut native = if config.native {
+            let symbolicator = CoreSymbolicator::new(&self.core.maps);
+            Some((
+                CoreUnwinder::new(&self.core.maps),
+                NativeStack::with_symbolicator(
+                    self.python_binary.clone(),
+                    self.libpython_binary.clone(),
+                    Box::new(symbolicator),
+                ),
+            ))
+        } else {
+            None
+        };
 
         for trace in &mut traces {
+            // the process info is printed once as a header, like `dump` does for live processes
+            if let Some(pid) = pid {
+                trace.pid = pid;
+            }
+
             if let Some(ref thread_names) = thread_names {
                 trace.thread_name = thread_names.get(&trace.thread_id).cloned();
             }
 
+            if trace.os_thread_id.is_none() {
+                trace.os_thread_id = self.os_thread_id(trace.thread_id);
+            }
+
+            // a thread we can't unwind still gets its python frames, rather than failing the dump
+            #[cfg(feature = "unwind")]
+            if let Some((unwinder, native)) = native.as_mut() {
+                let merged = trace
+                    .os_thread_id
+                    .and_then(|id| self.core.status.iter().find(|s| s.pr_pid as u64 == id))
+                    .ok_or_else(|| format_err!("failed to find registers for the thread"))
+                    .and_then(|status| unwinder.unwind(&self.core, &status.pr_reg))
+                    .and_then(|native_stack| {
+                        native.merge_native_stack(&trace.frames, native_stack)
+                    });
+                match merged {
+                    Ok(frames) => trace.frames = frames,
+                    Err(e) => log::warn!(
+                        "Failed to get native stack for thread {}: {}",
+                        trace.format_threadid(),
+                        e
+                    ),
+                }
+            }
+
             for frame in &mut trace.frames {
                 if let Some(locals) = frame.locals.as_mut() {
                     let max_length = (128 * config.dump_locals) as isize;
                     for local in locals {
                         let repr = format_variable::<I, CoreDump>(
                             &self.core,
+                            self.debug_offsets.as_ref(),
                             &self.version,
                             local.addr,
                             max_length,
@@ -362,9 +518,50 @@ impl PythonCoreDump {
         Ok(traces)
     }
 
+    /// Gets the OS thread id for a python thread id, by matching it up against the registers
+    /// of each thread in the core dump. On x86_64 the python thread id is the pthread_t of the
+    /// thread, which is the same as the fs_base register
+    #[cfg(target_arch = "x86_64")]
+    fn os_thread_id(&self, python_thread_id: u64) -> Option<u64> {
+        const FS_BASE: usize = 21;
+        self.core
+            .status
+            .iter()
+            .find(|status| status.pr_reg[FS_BASE] == python_thread_id)
+            .map(|status| status.pr_pid as u64)
+    }
+
+    #[cfg(not(target_arch = "x86_64"))]
+    fn os_thread_id(&self, _python_thread_id: u64) -> Option<u64> {
+        None
+    }
+
+    /// Formats the stack traces as json, with the process they came from as a header like
+    /// the process line in the text output
+    fn json(&self, traces: &[StackTrace]) -> Result<String, Error> {
+        #[derive(Serialize)]
+        struct JsonProcess {
+            pid: Pid,
+            command_line: String,
+        }
+        #[derive(Serialize)]
+        struct JsonDump<'a> {
+            process: Option<JsonProcess>,
+            threads: &'a [StackTrace],
+        }
+        let dump = JsonDump {
+            process: self.core.process_info().map(|process_info| JsonProcess {
+                pid: process_info.pid,
+                command_line: process_info.command_line,
+            }),
+            threads: traces,
+        };
+        Ok(serde_json::to_string_pretty(&dump)?)
+    }
+
     pub fn print_traces(&self, traces: &Vec<StackTrace>, config: &Config) -> Result<(), Error> {
         if config.dump_json {
-            println!("{}", serde_json::to_string_pretty(&traces)?);
+            println!("{}", self.json(traces)?);
             return Ok(());
         }
 
@@ -376,16 +573,20 @@ impl PythonCoreDump {
             );
         }
 
-        if let Some(psinfo) = self.core.psinfo {
+        if let Some(process_info) = self.core.process_info() {
             println!(
                 "Process {}: {}",
-                style(psinfo.pr_pid).bold().yellow(),
-                OsStr::from_bytes(&psinfo.pr_psargs).to_string_lossy()
+                style(process_info.pid).bold().yellow(),
+                process_info.command_line
             );
         }
         println!("Python v{}", style(&self.version).bold());
         println!();
+        let mut last_interpreter = None;
         for trace in traces.iter().rev() {
+            if config.include_interpreter_ids {
+                print_interpreter(trace, &mut last_interpreter);
+            }
             print_trace(trace, false);
         }
         Ok(())
@@ -423,10 +624,23 @@ mod elfcore {
         pub pr_stime: timeval,
         pub pr_cutime: timeval,
         pub pr_cstime: timeval,
-        // TODO: has registers next for thread next - don't need them right now, but if we want to do
-        // unwinding we will
+        pub pr_reg: elf_gregset_t,
+        pub pr_fpvalid: ::std::os::raw::c_int,
     }
 
+    #[cfg(target_arch = "x86_64")]
+    #[allow(non_camel_case_types)]
+    pub type elf_gregset_t = [::std::os::raw::c_ulong; 27];
+
+    #[cfg(target_arch = "aarch64")]
+    #[allow(non_camel_case_types)]
+    pub type elf_gregset_t = [::std::os::raw::c_ulong; 34];
+
+    // we only read the registers on x86_64 and aarch64
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    #[allow(non_camel_case_types)]
+    pub type elf_gregset_t = [::std::os::raw::c_ulong; 0];
+
     #[repr(C)]
     #[derive(Debug, Copy, Clone)]
     pub struct elf_prpsinfo {
@@ -464,12 +678,18 @@ mod test {
             patch: 13,
             release_flags: "".to_owned(),
             build_metadata: None,
+            free_threaded: false,
         };
         let python_core = PythonCoreDump {
             core,
             version,
+            debug_offsets: None,
             interpreter_address: 0x000055a8293dbe20,
             threadstate_address: 0x000055a82745fe18,
+            #[cfg(feature = "unwind")]
+            python_binary: None,
+            #[cfg(feature = "unwind")]
+            libpython_binary: None,
         };
 
         let config = Config::default();
@@ -482,11 +702,92 @@ mod test {
         assert_eq!(main_thread.frames.len(), 1);
         assert_eq!(main_thread.frames[0].name, "<module>");
         assert_eq!(main_thread.thread_name, Some("MainThread".to_owned()));
+        assert_eq!(main_thread.interpreter_id, Some(0));
 
         let child_thread = &traces[0];
         assert_eq!(child_thread.frames.len(), 5);
         assert_eq!(child_thread.frames[0].name, "dump_sum");
         assert_eq!(child_thread.frames[0].line, 16);
         assert_eq!(child_thread.thread_name, Some("child_thread".to_owned()));
+        assert!(main_thread.process_info.is_none());
+        assert_eq!(main_thread.pid, child_thread.pid);
+
+        // the json output has the process as a header, rather than on each trace
+        let json: serde_json::Value =
+            serde_json::from_str(&python_core.json(&traces).unwrap()).unwrap();
+        assert_eq!(json["threads"].as_array().unwrap().len(), 2);
+        assert_eq!(json["process"]["pid"], main_thread.pid);
+        assert!(json["threads"][0]["process_info"].is_null());
+    }
+
+    #[test]
+    fn test_process_info() {
+        let mut psinfo: elfcore::elf_prpsinfo = unsafe { std::mem::zeroed() };
+        psinfo.pr_pid = 1234;
+        let args = b"python -c print(1) ";
+        psinfo.pr_psargs[..args.len()].copy_from_slice(args);
+
+        let core = CoreDump {
+            filename: PathBuf::from("core"),
+            contents: Vec::new(),
+            maps: Vec::new(),
+            psinfo: Some(psinfo),
+            status: Vec::new(),
+            mapped_files: RefCell::new(HashMap::new()),
+        };
+        let process_info = core.process_info().unwrap();
+        assert_eq!(process_info.pid, 1234);
+        assert_eq!(process_info.command_line, "python -c print(1)");
+    }
+
+    #[test]
+    fn test_read_file_backed_memory() {
+        use std::io::Write;
+
+        let mut mapped = tempfile::NamedTempFile::new().unwrap();
+        mapped.write_all(b"headerrodata string\0").unwrap();
+
+        let segment = |p_vaddr, p_offset, p_filesz, p_memsz| goblin::elf::ProgramHeader {
+            p_vaddr,
+            p_offset,
+            p_filesz,
+            p_memsz,
+            ..Default::default()
+        };
+
+        // the first segment is contained in the core dump, the second segment is a read only
+        // mapping of a file that isn't, and immediately follows the first
+        let core = CoreDump {
+            filename: PathBuf::from("core"),
+            contents: b"heap".to_vec(),
+            maps: vec![
+                CoreMapRange {
+                    pathname: None,
+                    file_offset: 0,
+                    segment: segment(0x1000, 0, 4, 4),
+                },
+                CoreMapRange {
+                    pathname: Some(mapped.path().to_path_buf()),
+                    file_offset: 6,
+                    segment: segment(0x1004, 4, 0, 0x1000),
+                },
+            ],
+            psinfo: None,
+            status: Vec::new(),
+            mapped_files: RefCell::new(HashMap::new()),
+        };
+
+        let mut buf = [0_u8; 6];
+        core.read(0x1004, &mut buf).unwrap();
+        assert_eq!(&buf, b"rodata");
+
+        // reads spanning multiple segments should combine the memory from each
+        let mut buf = [0_u8; 10];
+        core.read(0x1000, &mut buf).unwrap();
+        assert_eq!(&buf, b"heaprodata");
+
+        // memory that isn't in the core dump or the mapped file should error instead of panicking
+        assert!(core.read(0x1010, &mut [0_u8; 16]).is_err());
+        assert!(core.read(0x3000, &mut [0_u8; 1]).is_err());
     }
 }
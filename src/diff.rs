@@ -0,0 +1,231 @@
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::BufWriter;
+
+use anyhow::{Context, Error};
+use console::style;
+use inferno::flamegraph::{Direction, Options};
+
+use crate::config::Config;
+use crate::flamegraph;
+use crate::speedscope;
+
+/// The number of functions to show in the table of changes
+const MAX_CHANGES: usize = 20;
+
+/// Compares two recorded profiles, writing out a differential flamegraph and printing the
+/// functions whose share of the samples changed the most
+pub fn diff(config: &Config) -> Result<(), Error> {
+    let (before_filename, after_filename) = match &config.input_filenames[..] {
+        [before, after] => (before, after),
+        _ => return Err(format_err!("Two profiles are required to compare")),
+    };
+    let before = read_profile(before_filename)?;
+    let after = read_profile(after_filename)?;
+
+    let filename = config
+        .filename
+        .as_deref()
+        .ok_or_else(|| format_err!("An output filename is required"))?;
+    write_flamegraph(&before, &after, filename)?;
+    print_changes(&before, &after);
+
+    println!(
+        "\nWrote differential flamegraph to '{filename}'. Red frames are slower in '{after_filename}', blue frames are faster."
+    );
+    Ok(())
+}
+
+/// Reads the counts for each stack from either a raw or a speedscope file
+fn read_profile(filename: &str) -> Result<HashMap<String, usize>, Error> {
+    let contents = std::fs::read_to_string(filename)
+        .with_context(|| format!("Failed to read profile '{}'", filename))?;
+    let counts = if contents.trim_start().starts_with('{') {
+        speedscope::read_collapsed(&contents)
+    } else {
+        flamegraph::read_raw(&contents)
+    };
+    counts.with_context(|| format!("Failed to parse profile '{}'", filename))
+}
+
+fn collapsed_lines(counts: &HashMap<String, usize>) -> String {
+    counts
+        .iter()
+        .map(|(stack, count)| format!("{stack} {count}\n"))
+        .collect()
+}
+
+fn write_flamegraph(
+    before: &HashMap<String, usize>,
+    after: &HashMap<String, usize>,
+    filename: &str,
+) -> Result<(), Error> {
+    // profiles will generally have been recorded for different amounts of time, so scale
+    // the counts in the first profile to match the second
+    let diff_options = inferno::differential::Options {
+        normalize: true,
+        ..Default::default()
+    };
+    let mut folded = Vec::new();
+    inferno::differential::from_readers(
+        diff_options,
+        collapsed_lines(before).as_bytes(),
+        collapsed_lines(after).as_bytes(),
+        &mut folded,
+    )
+    .map_err(|e| format_err!("Failed to compare profiles: {}", e))?;
+
+    let mut opts = Options::default();
+    opts.direction = Direction::Inverted;
+    opts.min_width = 0.1;
+    opts.title = std::env::args().collect::<Vec<String>>().join(" ");
+
+    let out = BufWriter::new(
+        File::create(filename).with_context(|| format!("Failed to create file '{}'", filename))?,
+    );
+    inferno::flamegraph::from_reader(&mut opts, &folded[..], out)
+        .map_err(|e| format_err!("Failed to write flamegraph: {}", e))?;
+    Ok(())
+}
+
+/// The fraction of samples a function was the leaf frame in (own), and was anywhere
+/// on the stack for (total)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct FunctionShare {
+    own: f64,
+    total: f64,
+}
+
+fn function_shares(counts: &HashMap<String, usize>) -> HashMap<String, FunctionShare> {
+    let samples: usize = counts.values().sum();
+    let mut shares: HashMap<String, FunctionShare> = HashMap::new();
+    if samples == 0 {
+        return shares;
+    }
+
+    for (stack, &count) in counts {
+        let share = count as f64 / samples as f64;
+        let functions: Vec<String> = stack.split(';').map(function_name).collect();
+        if let Some(leaf) = functions.last() {
+            shares.entry(leaf.clone()).or_default().own += share;
+        }
+        // only count recursive functions once per stack
+        let unique: HashSet<&String> = functions.iter().collect();
+        for function in unique {
+            shares.entry(function.clone()).or_default().total += share;
+        }
+    }
+    shares
+}
+
+/// Removes the line number from a frame, so that all the samples in a function are
+/// grouped together: 'foo (file.py:12)' becomes 'foo (file.py)'
+fn function_name(frame: &str) -> String {
+    if let Some(location) = frame.strip_suffix(')') {
+        if let Some((location, line)) = location.rsplit_once(':') {
+            if !line.is_empty() && line.bytes().all(|c| c.is_ascii_digit()) {
+                return format!("{location})");
+            }
+        }
+    }
+    frame.to_owned()
+}
+
+/// Returns the functions whose share of samples changed the most between the profiles,
+/// along with their shares before and after
+fn largest_changes(
+    before: &HashMap<String, usize>,
+    after: &HashMap<String, usize>,
+) -> Vec<(String, FunctionShare, FunctionShare)> {
+    let before = function_shares(before);
+    let after = function_shares(after);
+
+    let functions: HashSet<&String> = before.keys().chain(after.keys()).collect();
+    let mut changes: Vec<(String, FunctionShare, FunctionShare)> = functions
+        .into_iter()
+        .map(|function| {
+            let b = before.get(function).copied().unwrap_or_default();
+            let a = after.get(function).copied().unwrap_or_default();
+            (function.clone(), b, a)
+        })
+        .filter(|(_, b, a)| b != a)
+        .collect();
+
+    let magnitude =
+        |b: &FunctionShare, a: &FunctionShare| (a.own - b.own).abs().max((a.total - b.total).abs());
+    changes.sort_by(|(x, xb, xa), (y, yb, ya)| {
+        magnitude(yb, ya)
+            .partial_cmp(&magnitude(xb, xa))
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| x.cmp(y))
+    });
+    changes.truncate(MAX_CHANGES);
+    changes
+}
+
+fn print_changes(before: &HashMap<String, usize>, after: &HashMap<String, usize>) {
+    let changes = largest_changes(before, after);
+    if changes.is_empty() {
+        println!("No differences found between profiles");
+        return;
+    }
+
+    println!(
+        "{:>24}  {:>24}",
+        style("%Own").bold(),
+        style("%Total").bold()
+    );
+    println!(
+        "{:>7} {:>7} {:>8}  {:>7} {:>7} {:>8}  {}",
+        style("before").bold(),
+        style("after").bold(),
+        style("change").bold(),
+        style("before").bold(),
+        style("after").bold(),
+        style("change").bold(),
+        style("Function (filename)").bold()
+    );
+    for (function, before, after) in changes {
+        println!(
+            "{:>6.2}% {:>6.2}% {:>+7.2}%  {:>6.2}% {:>6.2}% {:>+7.2}%  {}",
+            100.0 * before.own,
+            100.0 * after.own,
+            100.0 * (after.own - before.own),
+            100.0 * before.total,
+            100.0 * after.total,
+            100.0 * (after.total - before.total),
+            function
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_largest_changes() {
+        assert_eq!(function_name("foo (file.py:12)"), "foo (file.py)");
+        assert_eq!(function_name("foo (file.py)"), "foo (file.py)");
+        assert_eq!(function_name("thread (0x7F00)"), "thread (0x7F00)");
+
+        let before = flamegraph::read_raw(
+            "main (app.py:1);slow (app.py:5) 3\nmain (app.py:1);fast (app.py:9) 1\n",
+        )
+        .unwrap();
+        let after = flamegraph::read_raw(
+            "main (app.py:1);slow (app.py:6) 1\nmain (app.py:2);fast (app.py:9) 3\n",
+        )
+        .unwrap();
+
+        let changes = largest_changes(&before, &after);
+        // main is the root of every stack in both profiles, so hasn't changed
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].0, "fast (app.py)");
+        assert_eq!(changes[0].1.own, 0.25);
+        assert_eq!(changes[0].2.own, 0.75);
+        assert_eq!(changes[1].0, "slow (app.py)");
+        assert_eq!(changes[1].1.total, 0.75);
+        assert_eq!(changes[1].2.total, 0.25);
+    }
+}
@@ -0,0 +1,203 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+
+use anyhow::Error;
+
+use crate::stack_trace::StackTrace;
+
+/*
+ * This file contains code to write out an annotated disassembly of each sampled python
+ * function, for 'record --instructions'. Each bytecode instruction that was sampled is listed
+ * with the number of samples it was running in (own) or was on the stack for (total), which
+ * shows which instructions in a hot loop are taking the time. In python 3.11+ the opcodes
+ * here are the specialized adaptive versions that the interpreter was actually running.
+ *
+ * Only the sampled instructions are shown, since the full bytecode isn't read from the
+ * profiled process.
+ */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct InstructionKey {
+    offset: u32,
+    opcode: u8,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct InstructionStatistics {
+    own: u64,
+    total: u64,
+    line: i32,
+    name: Option<&'static str>,
+}
+
+#[derive(Default)]
+struct FunctionStatistics {
+    total: u64,
+    instructions: BTreeMap<InstructionKey, InstructionStatistics>,
+}
+
+pub struct Disassembly {
+    // keyed by (filename, function name)
+    functions: HashMap<(String, String), FunctionStatistics>,
+    samples: u64,
+}
+
+impl Disassembly {
+    pub fn new() -> Disassembly {
+        Disassembly {
+            functions: HashMap::new(),
+            samples: 0,
+        }
+    }
+
+    pub fn increment(&mut self, trace: &StackTrace) -> std::io::Result<()> {
+        self.samples += 1;
+
+        // deduplicate so that recursive calls don't count the same instruction multiple times
+        let mut seen_functions = HashSet::new();
+        let mut seen_instructions = HashSet::new();
+        for (i, frame) in trace.frames.iter().enumerate() {
+            // native frames and the frames added for threads/processes have no bytecode
+            let instruction = match frame.instruction {
+                Some(instruction) => instruction,
+                None => continue,
+            };
+
+            let function = (frame.filename.clone(), frame.display_name().to_owned());
+            let key = InstructionKey {
+                offset: instruction.offset,
+                opcode: instruction.opcode,
+            };
+
+            if seen_functions.insert(function.clone()) {
+                self.functions.entry(function.clone()).or_default().total += 1;
+            }
+            let is_new = seen_instructions.insert((function.clone(), key));
+
+            let stats = self
+                .functions
+                .entry(function)
+                .or_default()
+                .instructions
+                .entry(key)
+                .or_insert_with(|| InstructionStatistics {
+                    line: frame.line,
+                    name: instruction.name,
+                    ..Default::default()
+                });
+            if i == 0 {
+                stats.own += 1;
+            }
+            if is_new {
+                stats.total += 1;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        writeln!(w, "Annotated disassembly from {} samples", self.samples)?;
+
+        // show the functions that were sampled the most first
+        let mut functions: Vec<_> = self.functions.iter().collect();
+        functions
+            .sort_by(|(a_name, a), (b_name, b)| b.total.cmp(&a.total).then(a_name.cmp(b_name)));
+
+        for ((filename, name), function) in functions {
+            writeln!(w)?;
+            writeln!(
+                w,
+                "{} ({}) ({:.2}% of samples)",
+                name,
+                filename,
+                self.percent(function.total)
+            )?;
+            writeln!(
+                w,
+                "{:>8} {:>8} {:>6} {:>6}  Instruction",
+                "Own", "Total", "Offset", "Line"
+            )?;
+
+            for (key, stats) in &function.instructions {
+                let opcode = match stats.name {
+                    Some(name) => name.to_owned(),
+                    None => format!("<{}>", key.opcode),
+                };
+                writeln!(
+                    w,
+                    "{:>7.2}% {:>7.2}% {:>6} {:>6}  {}",
+                    self.percent(stats.own),
+                    self.percent(stats.total),
+                    key.offset,
+                    stats.line,
+                    opcode
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn percent(&self, count: u64) -> f64 {
+        100.0 * count as f64 / self.samples.max(1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack_trace::tests::trace;
+    use crate::stack_trace::{Frame, Instruction};
+
+    fn frame(name: &str, line: i32, instruction: Option<(u32, u8, Option<&'static str>)>) -> Frame {
+        Frame {
+            instruction: instruction.map(|(offset, opcode, name)| Instruction {
+                offset,
+                opcode,
+                name,
+            }),
+            ..crate::stack_trace::tests::frame(name, "/app/loop.py", line)
+        }
+    }
+
+    #[test]
+    fn test_disassembly() {
+        let mut disassembly = Disassembly::new();
+        let add = trace(vec![
+            frame("total", 4, Some((24, 122, Some("BINARY_OP_ADD_INT")))),
+            frame("<module>", 10, Some((8, 171, Some("CALL")))),
+        ]);
+        let recursive = trace(vec![
+            frame("total", 5, Some((40, 251, None))),
+            frame("total", 5, Some((40, 251, None))),
+            frame("<module>", 10, Some((8, 171, Some("CALL")))),
+            frame("thread (0x1)", 0, None),
+        ]);
+        disassembly.increment(&add).unwrap();
+        disassembly.increment(&add).unwrap();
+        disassembly.increment(&recursive).unwrap();
+
+        let function =
+            &disassembly.functions[&(String::from("/app/loop.py"), String::from("total"))];
+        assert_eq!(function.total, 3);
+        let stats = &function.instructions[&InstructionKey {
+            offset: 40,
+            opcode: 251,
+        }];
+        assert_eq!((stats.own, stats.total, stats.line), (1, 1, 5));
+
+        let mut output = Vec::new();
+        disassembly.write(&mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "Annotated disassembly from 3 samples");
+        // ties are sorted by name, so '<module>' comes first
+        assert_eq!(lines[2], "<module> (/app/loop.py) (100.00% of samples)");
+        assert_eq!(lines[4], "   0.00%  100.00%      8     10  CALL");
+        assert_eq!(lines[6], "total (/app/loop.py) (100.00% of samples)");
+        assert_eq!(
+            lines[8],
+            "  66.67%   66.67%     24      4  BINARY_OP_ADD_INT"
+        );
+        assert_eq!(lines[9], "  33.33%   33.33%     40      5  <251>");
+    }
+}
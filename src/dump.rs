@@ -1,9 +1,11 @@
 use anyhow::Error;
 use console::{style, Term};
+use serde_derive::Serialize;
 
 use crate::config::Config;
+use crate::python_asyncio::AsyncTask;
 use crate::python_spy::PythonSpy;
-use crate::stack_trace::StackTrace;
+use crate::stack_trace::{Frame, StackTrace};
 
 use remoteprocess::Pid;
 
@@ -11,7 +13,21 @@ pub fn print_traces(pid: Pid, config: &Config, parent: Option<Pid>) -> Result<()
     let mut process = PythonSpy::new(pid, config)?;
     if config.dump_json {
         let traces = process.get_stack_traces()?;
-        println!("{}", serde_json::to_string_pretty(&traces)?);
+        if config.async_tasks {
+            #[derive(Serialize)]
+            struct AsyncDump {
+                threads: Vec<StackTrace>,
+                tasks: Vec<AsyncTask>,
+            }
+            let tasks = process.get_async_tasks()?;
+            let dump = AsyncDump {
+                threads: traces,
+                tasks,
+            };
+            println!("{}", serde_json::to_string_pretty(&dump)?);
+        } else {
+            println!("{}", serde_json::to_string_pretty(&traces)?);
+        }
         return Ok(());
     }
 
@@ -37,7 +53,11 @@ pub fn print_traces(pid: Pid, config: &Config, parent: Option<Pid>) -> Result<()
     }
     println!();
     let traces = process.get_stack_traces()?;
+    let mut last_interpreter = None;
     for trace in traces.iter().rev() {
+        if config.include_interpreter_ids {
+            print_interpreter(trace, &mut last_interpreter);
+        }
         print_trace(trace, true);
         if config.subprocesses {
             for (childpid, parentpid) in process
@@ -58,9 +78,28 @@ pub fn print_traces(pid: Pid, config: &Config, parent: Option<Pid>) -> Result<()
             }
         }
     }
+
+    if config.async_tasks {
+        let tasks = process.get_async_tasks()?;
+        println!();
+        println!("Tasks:");
+        for task in tasks.iter() {
+            print_task(task);
+        }
+    }
     Ok(())
 }
 
+/// Prints out the interpreter id for a trace, if it is different from the previous trace
+pub fn print_interpreter(trace: &StackTrace, last_interpreter: &mut Option<i64>) {
+    if let Some(interpreter_id) = trace.interpreter_id {
+        if *last_interpreter != Some(interpreter_id) {
+            println!("Interpreter {}:", style(interpreter_id).bold().yellow());
+            *last_interpreter = Some(interpreter_id);
+        }
+    }
+}
+
 pub fn print_trace(trace: &StackTrace, include_activity: bool) {
     let thread_id = trace.format_threadid();
 
@@ -72,21 +111,55 @@ pub fn print_trace(trace: &StackTrace, include_activity: bool) {
         "".to_owned()
     };
 
+    let task = match trace.task_name.as_ref() {
+        Some(task_name) => format!(" running \"{}\"", task_name),
+        None => "".to_owned(),
+    };
+
     match trace.thread_name.as_ref() {
         Some(name) => {
             println!(
-                "Thread {}{}: \"{}\"",
+                "Thread {}{}: \"{}\"{}",
                 style(thread_id).bold().yellow(),
                 status,
-                name
+                name,
+                task
             );
         }
         None => {
-            println!("Thread {}{}", style(thread_id).bold().yellow(), status);
+            println!(
+                "Thread {}{}{}",
+                style(thread_id).bold().yellow(),
+                status,
+                task
+            );
         }
     };
 
-    for frame in &trace.frames {
+    print_frames(&trace.frames);
+}
+
+/// Prints out an asyncio task, along with the chain of coroutines it is awaiting on
+pub fn print_task(task: &AsyncTask) {
+    let status = if task.running { "running" } else { "pending" };
+    match task.awaiting.as_ref() {
+        Some(awaiting) => println!(
+            "Task \"{}\" ({}, awaiting \"{}\")",
+            style(&task.name).bold().yellow(),
+            status,
+            awaiting
+        ),
+        None => println!(
+            "Task \"{}\" ({})",
+            style(&task.name).bold().yellow(),
+            status
+        ),
+    }
+    print_frames(&task.frames);
+}
+
+fn print_frames(frames: &[Frame]) {
+    for frame in frames {
         let filename = match &frame.short_filename {
             Some(f) => f,
             None => &frame.filename,
@@ -94,14 +167,14 @@ pub fn print_trace(trace: &StackTrace, include_activity: bool) {
         if frame.line != 0 {
             println!(
                 "    {} ({}:{})",
-                style(&frame.name).green(),
+                style(frame.display_name()).green(),
                 style(&filename).cyan(),
                 style(frame.line).dim()
             );
         } else {
             println!(
                 "    {} ({})",
-                style(&frame.name).green(),
+                style(frame.display_name()).green(),
                 style(&filename).cyan()
             );
         }
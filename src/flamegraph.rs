@@ -37,6 +37,8 @@ use crate::stack_trace::StackTrace;
 pub struct Flamegraph {
     pub counts: HashMap<String, usize>,
     pub show_linenumbers: bool,
+    /// Whether the counts are CPU times in microseconds, rather than the number of samples
+    pub cpu_time: bool,
 }
 
 impl Flamegraph {
@@ -44,10 +46,22 @@ impl Flamegraph {
         Flamegraph {
             counts: HashMap::new(),
             show_linenumbers,
+            cpu_time: false,
         }
     }
 
     pub fn increment(&mut self, trace: &StackTrace) -> std::io::Result<()> {
+        self.increment_by(trace, 1)
+    }
+
+    /// Records a stack trace weighted by the CPU time in microseconds the thread used
+    /// since the previous sample
+    pub fn increment_cpu_time(&mut self, trace: &StackTrace, cpu_time: u64) -> std::io::Result<()> {
+        self.cpu_time = true;
+        self.increment_by(trace, cpu_time as usize)
+    }
+
+    fn increment_by(&mut self, trace: &StackTrace, count: usize) -> std::io::Result<()> {
         // convert the frame into a single ';' delimited String
         let frame = trace
             .frames
@@ -59,17 +73,17 @@ impl Flamegraph {
                     None => &frame.filename,
                 };
                 if self.show_linenumbers && frame.line != 0 {
-                    format!("{} ({}:{})", frame.name, filename, frame.line)
+                    format!("{} ({}:{})", frame.display_name(), filename, frame.line)
                 } else if !filename.is_empty() {
-                    format!("{} ({})", frame.name, filename)
+                    format!("{} ({})", frame.display_name(), filename)
                 } else {
-                    frame.name.clone()
+                    frame.display_name().to_owned()
                 }
             })
             .collect::<Vec<String>>()
             .join(";");
         // update counts for that frame
-        *self.counts.entry(frame).or_insert(0) += 1;
+        *self.counts.entry(frame).or_insert(0) += count;
         Ok(())
     }
 
@@ -85,6 +99,9 @@ impl Flamegraph {
         opts.direction = Direction::Inverted;
         opts.min_width = 0.1;
         opts.title = std::env::args().collect::<Vec<String>>().join(" ");
+        if self.cpu_time {
+            opts.count_name = "microseconds".to_owned();
+        }
 
         let lines = self.get_lines();
         inferno::flamegraph::from_lines(&mut opts, lines.iter().map(|x| x.as_str()), w)
@@ -100,3 +117,19 @@ impl Flamegraph {
         Ok(())
     }
 }
+
+/// Reads the counts for each stack from a file written by `Flamegraph::write_raw`
+pub fn read_raw(contents: &str) -> Result<HashMap<String, usize>, Error> {
+    let mut counts = HashMap::new();
+    for line in contents.lines().filter(|line| !line.trim().is_empty()) {
+        let (stack, count) = line
+            .rsplit_once(' ')
+            .ok_or_else(|| format_err!("Invalid line in raw profile: '{}'", line))?;
+        let count: usize = count
+            .trim()
+            .parse()
+            .map_err(|_| format_err!("Invalid sample count in raw profile: '{}'", line))?;
+        *counts.entry(stack.to_owned()).or_insert(0) += count;
+    }
+    Ok(counts)
+}
@@ -36,11 +36,17 @@ pub mod coredump;
 #[cfg(feature = "unwind")]
 mod cython;
 pub mod dump;
+#[cfg(all(target_os = "linux", feature = "unwind"))]
+mod native_coredump;
 #[cfg(feature = "unwind")]
 mod native_stack_trace;
+pub mod python_asyncio;
 mod python_bindings;
 mod python_data_access;
+mod python_debug_offsets;
 mod python_interpreters;
+mod python_labels;
+mod python_opcodes;
 pub mod python_process_info;
 pub mod python_spy;
 mod python_threading;
@@ -3,26 +3,41 @@ extern crate anyhow;
 #[macro_use]
 extern crate log;
 
+mod annotate;
 mod binary_parser;
 mod chrometrace;
 mod config;
 mod console_viewer;
+mod convert;
 #[cfg(target_os = "linux")]
 mod coredump;
 #[cfg(feature = "unwind")]
 mod cython;
+mod diff;
+mod disassembly;
 mod dump;
 mod flamegraph;
+#[cfg(all(target_os = "linux", feature = "unwind"))]
+mod native_coredump;
 #[cfg(feature = "unwind")]
 mod native_stack_trace;
+mod pprof;
+mod python_asyncio;
 mod python_bindings;
 mod python_data_access;
+mod python_debug_offsets;
 mod python_interpreters;
+mod python_labels;
+mod python_opcodes;
 mod python_process_info;
 mod python_spy;
 mod python_threading;
+mod report;
+mod sample_log;
 mod sampler;
+mod serve;
 mod speedscope;
+mod stack_filter;
 mod stack_trace;
 mod timer;
 mod utils;
@@ -31,16 +46,17 @@ mod version;
 use std::io::{Read, Write};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use anyhow::Error;
 use console::style;
 
 use config::{Config, FileFormat, RecordDuration};
 use console_viewer::ConsoleViewer;
+use sampler::Sample;
 use stack_trace::{Frame, StackTrace};
 
-use chrono::{Local, SecondsFormat};
+use chrono::{DateTime, Local, SecondsFormat};
 
 #[cfg(unix)]
 fn permission_denied(err: &Error) -> bool {
@@ -67,20 +83,28 @@ fn sample_console(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
 
     let mut console =
         ConsoleViewer::new(config.show_line_numbers, &display, &sampler.version, config)?;
-    for sample in sampler {
+    let stack_filter = stack_filter::StackFilter::new(config)?;
+    for mut sample in sampler {
         if let Some(elapsed) = sample.late {
             console.increment_late_sample(elapsed);
         }
 
+        if let Some(stack_filter) = &stack_filter {
+            stack_filter.filter_sample(&mut sample);
+        }
+
         if let Some(errors) = sample.sampling_errors {
             for (_, error) in errors {
                 console.increment_error(&error)?
             }
         }
         console.increment(&sample.traces)?;
+        if console.finished() {
+            return Ok(());
+        }
     }
 
-    if !config.subprocesses {
+    if !config.subprocesses && !config.batch {
         println!("\nprocess {pid} ended");
     }
     Ok(())
@@ -89,6 +113,54 @@ fn sample_console(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
 pub trait Recorder {
     fn increment(&mut self, trace: &StackTrace) -> Result<(), Error>;
     fn write(&self, w: &mut dyn Write) -> Result<(), Error>;
+
+    /// Records a stack trace sampled `timestamp` microseconds after the start of the profile,
+    /// for replaying saved samples. Only formats with a timeline need to know when samples were taken
+    fn increment_at(&mut self, trace: &StackTrace, _timestamp: u64) -> Result<(), Error> {
+        self.increment(trace)
+    }
+
+    /// Records a stack trace weighted by the CPU time in microseconds that the thread used since
+    /// the previous sample. Formats that can't store weights count this as a single sample
+    fn increment_cpu_time(&mut self, trace: &StackTrace, _cpu_time: u64) -> Result<(), Error> {
+        self.increment(trace)
+    }
+
+    /// Records the stack traces from a sample that match the config, returning how many
+    /// were recorded. Any frames for the thread and process are added to the traces here
+    fn increment_sample(&mut self, sample: &mut Sample, config: &Config) -> Result<usize, Error> {
+        self.increment_traces(&mut sample.traces, config, None)
+    }
+
+    /// Records the stack traces that match the config like `increment_sample`, for traces that
+    /// were sampled `timestamp` microseconds after the start of the profile if given
+    fn increment_traces(
+        &mut self,
+        traces: &mut [StackTrace],
+        config: &Config,
+        timestamp: Option<u64>,
+    ) -> Result<usize, Error> {
+        let mut recorded = 0;
+        for trace in traces.iter_mut() {
+            // threads that didn't run since the last sample have no CPU time to attribute, and
+            // threads whose CPU time couldn't be read would mix sample counts into the times
+            if !include_trace(trace, config)
+                || trace.cpu_time == Some(0)
+                || (config.cpu_time && trace.cpu_time.is_none())
+            {
+                continue;
+            }
+
+            decorate_trace(trace, config);
+            match (trace.cpu_time, timestamp) {
+                (Some(cpu_time), _) => self.increment_cpu_time(trace, cpu_time)?,
+                (None, Some(timestamp)) => self.increment_at(trace, timestamp)?,
+                (None, None) => self.increment(trace)?,
+            }
+            recorded += 1;
+        }
+        Ok(recorded)
+    }
 }
 
 impl Recorder for speedscope::Stats {
@@ -98,6 +170,9 @@ impl Recorder for speedscope::Stats {
     fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
         self.write(w)
     }
+    fn increment_cpu_time(&mut self, trace: &StackTrace, cpu_time: u64) -> Result<(), Error> {
+        Ok(self.record_cpu_time(trace, cpu_time)?)
+    }
 }
 
 impl Recorder for flamegraph::Flamegraph {
@@ -107,6 +182,9 @@ impl Recorder for flamegraph::Flamegraph {
     fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
         self.write(w)
     }
+    fn increment_cpu_time(&mut self, trace: &StackTrace, cpu_time: u64) -> Result<(), Error> {
+        Ok(self.increment_cpu_time(trace, cpu_time)?)
+    }
 }
 
 impl Recorder for chrometrace::Chrometrace {
@@ -116,6 +194,56 @@ impl Recorder for chrometrace::Chrometrace {
     fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
         self.write(w)
     }
+    fn increment_at(&mut self, trace: &StackTrace, timestamp: u64) -> Result<(), Error> {
+        Ok(self.increment_at(trace, timestamp)?)
+    }
+}
+
+impl Recorder for pprof::Pprof {
+    fn increment(&mut self, trace: &StackTrace) -> Result<(), Error> {
+        Ok(self.increment(trace)?)
+    }
+    fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        self.write(w)
+    }
+    fn increment_cpu_time(&mut self, trace: &StackTrace, cpu_time: u64) -> Result<(), Error> {
+        Ok(self.increment_cpu_time(trace, cpu_time)?)
+    }
+}
+
+impl Recorder for annotate::Annotate {
+    fn increment(&mut self, trace: &StackTrace) -> Result<(), Error> {
+        Ok(self.increment(trace)?)
+    }
+    fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        self.write(w)
+    }
+}
+
+impl Recorder for disassembly::Disassembly {
+    fn increment(&mut self, trace: &StackTrace) -> Result<(), Error> {
+        Ok(self.increment(trace)?)
+    }
+    fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        self.write(w)
+    }
+}
+
+impl Recorder for sample_log::SampleLog {
+    fn increment(&mut self, trace: &StackTrace) -> Result<(), Error> {
+        self.increment(trace)
+    }
+    fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        self.write(w)
+    }
+    fn increment_at(&mut self, trace: &StackTrace, timestamp: u64) -> Result<(), Error> {
+        self.increment_at(trace, timestamp)
+    }
+    // keep every trace undecorated, so that they can be filtered when the log is rendered
+    fn increment_sample(&mut self, sample: &mut Sample, _config: &Config) -> Result<usize, Error> {
+        self.increment_sample(sample)?;
+        Ok(sample.traces.len())
+    }
 }
 
 pub struct RawFlamegraph(flamegraph::Flamegraph);
@@ -125,13 +253,20 @@ impl Recorder for RawFlamegraph {
         Ok(self.0.increment(trace)?)
     }
 
+    fn increment_cpu_time(&mut self, trace: &StackTrace, cpu_time: u64) -> Result<(), Error> {
+        Ok(self.0.increment_cpu_time(trace, cpu_time)?)
+    }
+
     fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
         self.0.write_raw(w)
     }
 }
 
-fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error> {
-    let mut output: Box<dyn Recorder> = match config.format {
+fn new_recorder(config: &Config) -> Result<Box<dyn Recorder>, Error> {
+    if config.instructions {
+        return Ok(Box::new(disassembly::Disassembly::new()));
+    }
+    Ok(match config.format {
         Some(FileFormat::flamegraph) => {
             Box::new(flamegraph::Flamegraph::new(config.show_line_numbers))
         }
@@ -139,36 +274,218 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
         Some(FileFormat::raw) => Box::new(RawFlamegraph(flamegraph::Flamegraph::new(
             config.show_line_numbers,
         ))),
-        Some(FileFormat::chrometrace) => {
-            Box::new(chrometrace::Chrometrace::new(config.show_line_numbers))
+        Some(FileFormat::chrometrace) => Box::new(chrometrace::Chrometrace::new(config)),
+        Some(FileFormat::pprof) => Box::new(pprof::Pprof::new(config)),
+        Some(FileFormat::pyspy) => Box::new(sample_log::SampleLog::new(config)),
+        Some(FileFormat::annotate) => Box::new(annotate::Annotate::new()),
+        None => return Err(format_err!("A file format is required to record samples")),
+    })
+}
+
+fn file_extension(format: &FileFormat) -> &'static str {
+    match format {
+        FileFormat::flamegraph => "svg",
+        FileFormat::speedscope => "json",
+        FileFormat::raw => "txt",
+        FileFormat::chrometrace => "json",
+        FileFormat::pprof => "pb.gz",
+        FileFormat::pyspy => "pyspy",
+        FileFormat::annotate => "txt",
+    }
+}
+
+/// Gets the filename to write a profile to. When rotating output files, the start time of
+/// each window is added to the filename so that every window gets written to a new file
+fn output_filename(config: &Config, start_time: &DateTime<Local>) -> Result<String, Error> {
+    let ext = match config.format.as_ref() {
+        _ if config.instructions => "txt".to_owned(),
+        // speedscope and chrometrace (or raw and annotate) files would overwrite each other
+        // when recorded together, so the format is added to the extension to tell them apart
+        Some(format)
+            if config.formats.iter().any(|other| {
+                other != format && file_extension(other) == file_extension(format)
+            }) =>
+        {
+            format!("{:?}.{}", format, file_extension(format))
         }
+        Some(format) => file_extension(format).to_owned(),
         None => return Err(format_err!("A file format is required to record samples")),
     };
+    let local_time = start_time.to_rfc3339_opts(SecondsFormat::Secs, true);
+    let default_name = || {
+        let name = match config.python_program.as_ref() {
+            Some(prog) => prog[0].to_string(),
+            None => match config.pid.as_ref() {
+                Some(pid) => pid.to_string(),
+                None => String::from("unknown"),
+            },
+        };
+        format!("{name}-{local_time}.{ext}")
+    };
 
-    let filename = match config.filename.clone() {
-        Some(filename) => filename,
-        None => {
-            let ext = match config.format.as_ref() {
-                Some(FileFormat::flamegraph) => "svg",
-                Some(FileFormat::speedscope) => "json",
-                Some(FileFormat::raw) => "txt",
-                Some(FileFormat::chrometrace) => "json",
-                None => return Err(format_err!("A file format is required to record samples")),
-            };
-            let local_time = Local::now().to_rfc3339_opts(SecondsFormat::Secs, true);
-            let name = match config.python_program.as_ref() {
-                Some(prog) => prog[0].to_string(),
-                None => match config.pid.as_ref() {
-                    Some(pid) => pid.to_string(),
-                    None => String::from("unknown"),
-                },
-            };
-            format!("{name}-{local_time}.{ext}")
+    Ok(match config.filename.as_ref() {
+        Some(filename) if filename == "-" => filename.clone(),
+        Some(directory) if config::is_output_directory(directory) => {
+            std::path::Path::new(directory)
+                .join(default_name())
+                .to_string_lossy()
+                .into_owned()
+        }
+        Some(filename) if config.rotate_seconds.is_some() => {
+            match filename.strip_suffix(&format!(".{ext}")) {
+                Some(name) => format!("{name}-{local_time}.{ext}"),
+                None => {
+                    let path = std::path::Path::new(filename);
+                    match path.extension() {
+                        Some(ext) => format!(
+                            "{}-{local_time}.{}",
+                            path.with_extension("").display(),
+                            ext.to_string_lossy()
+                        ),
+                        None => format!("{filename}-{local_time}"),
+                    }
+                }
+            }
         }
-    };
+        Some(filename) => filename.clone(),
+        None => default_name(),
+    })
+}
+
+/// Writes out a recorder to a file, or to stdout if the filename is '-'
+fn write_output(output: &dyn Recorder, filename: &str) -> Result<(), Error> {
+    if filename == "-" {
+        let stdout = std::io::stdout();
+        let mut stdout = stdout.lock();
+        output.write(&mut stdout)?;
+        stdout.flush()?;
+    } else {
+        let mut out_file = std::fs::File::create(filename)?;
+        output.write(&mut out_file)?;
+    }
+    Ok(())
+}
+
+/// Whether a stack trace should be recorded, based off the idle and GIL options. Traces with
+/// CPU times are weighted by how much the thread ran instead of being filtered when idle
+fn include_trace(trace: &StackTrace, config: &Config) -> bool {
+    (config.include_idle || trace.active || trace.cpu_time.is_some())
+        && (!config.gil_only || trace.owns_gil)
+}
+
+/// Adds frames to the root of a stack trace to show the asyncio task, thread, interpreter
+/// and process that the sample came from
+fn decorate_trace(trace: &mut StackTrace, config: &Config) {
+    if let Some(task_name) = &trace.task_name {
+        trace
+            .frames
+            .push(Frame::shim(format!("task ({task_name})")));
+    }
+
+    if config.include_thread_ids {
+        let threadid = trace.format_threadid();
+        let thread_fmt = if let Some(thread_name) = &trace.thread_name {
+            format!("thread ({threadid}): {thread_name}")
+        } else {
+            format!("thread ({threadid})")
+        };
+        trace.frames.push(Frame::shim(thread_fmt));
+    }
+
+    if config.include_interpreter_ids {
+        if let Some(interpreter_id) = trace.interpreter_id {
+            trace
+                .frames
+                .push(Frame::shim(format!("interpreter ({interpreter_id})")));
+        }
+    }
+
+    // pprof and speedscope keep labels separate from the stack, so only add frames for them
+    // in formats that have no other way of showing them
+    let labels_in_format = matches!(
+        config.format,
+        Some(FileFormat::pprof) | Some(FileFormat::speedscope) | Some(FileFormat::pyspy)
+    );
+    if !labels_in_format {
+        for (name, value) in &trace.labels {
+            trace.frames.push(Frame::shim(format!("{name}={value}")));
+        }
+    }
+
+    if let Some(process_info) = trace.process_info.as_ref() {
+        trace.frames.push(process_info.to_frame());
+        let mut parent = process_info.parent.as_ref();
+        while parent.is_some() {
+            if let Some(process_info) = parent {
+                trace.frames.push(process_info.to_frame());
+                parent = process_info.parent.as_ref();
+            }
+        }
+    }
+}
+
+/// The recorder for one of the formats passed to 'record'. Each output has a copy of the config
+/// with its own format and filename, so that traces are decorated and written out for that format
+struct RecordOutput {
+    config: Config,
+    recorder: Box<dyn Recorder>,
+    samples: usize,
+    window_samples: usize,
+}
+
+fn record_outputs(config: &Config) -> Result<Vec<RecordOutput>, Error> {
+    let mut outputs = Vec::new();
+    for (i, format) in config.formats.iter().enumerate() {
+        // a single --output is either the filename for a single format, or a directory
+        let filename = match config.filenames.get(i) {
+            Some(filename) => Some(filename.clone()),
+            None => config.filenames.first().cloned(),
+        };
+        if let Some(directory) = filename.as_ref() {
+            if config::is_output_directory(directory) {
+                std::fs::create_dir_all(directory)?;
+            }
+        }
+
+        let config = Config {
+            format: Some(*format),
+            filename,
+            ..config.clone()
+        };
+        outputs.push(RecordOutput {
+            recorder: new_recorder(&config)?,
+            config,
+            samples: 0,
+            window_samples: 0,
+        });
+    }
+    if outputs.is_empty() {
+        return Err(format_err!("A file format is required to record samples"));
+    }
+    Ok(outputs)
+}
+
+fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error> {
+    let mut outputs = record_outputs(config)?;
+    let stack_filter = stack_filter::StackFilter::new(config)?;
+    let mut window_start = Instant::now();
+    let mut window_start_time = Local::now();
+    let mut files_written = 0;
 
     let sampler = sampler::Sampler::new(pid, config)?;
 
+    // when writing a profile to stdout, our own messages go to stderr instead
+    let to_stdout = config.filenames.iter().any(|f| f == "-");
+    macro_rules! status {
+        ($($arg:tt)*) => {
+            if to_stdout {
+                eprintln!($($arg)*)
+            } else {
+                println!($($arg)*)
+            }
+        };
+    }
+
     // if we're not showing a progress bar, it's probably because we've spawned the process and
     // are displaying its stderr/stdout. In that case add a prefix to our println messages so
     // that we can distinguish
@@ -180,16 +497,19 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
 
     let max_intervals = match &config.duration {
         RecordDuration::Unlimited => {
-            println!(
+            status!(
                 "{}Sampling process {} times a second. Press Control-C to exit.",
-                lede, config.sampling_rate
+                lede,
+                config.sampling_rate
             );
             None
         }
         RecordDuration::Seconds(sec) => {
-            println!(
+            status!(
                 "{}Sampling process {} times a second for {} seconds. Press Control-C to exit.",
-                lede, config.sampling_rate, sec
+                lede,
+                config.sampling_rate,
+                sec
             );
             Some(sec * config.sampling_rate)
         }
@@ -217,8 +537,7 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
 
     let mut errors = 0;
     let mut intervals = 0;
-    let mut samples = 0;
-    println!();
+    status!();
 
     let running = Arc::new(AtomicBool::new(true));
     let r = running.clone();
@@ -232,12 +551,12 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
     for mut sample in sampler {
         if let Some(delay) = sample.late {
             if delay > Duration::from_secs(1) {
-                if config.hide_progress {
+                if config.hide_progress || to_stdout {
                     // display a message if we're late, but don't spam the log
                     let now = std::time::Instant::now();
                     if now - last_late_message > Duration::from_secs(1) {
                         last_late_message = now;
-                        println!("{lede}{delay:.2?} behind in sampling, results may be inaccurate. Try reducing the sampling rate")
+                        status!("{lede}{delay:.2?} behind in sampling, results may be inaccurate. Try reducing the sampling rate")
                     }
                 } else {
                     let term = console::Term::stdout();
@@ -261,47 +580,36 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
             }
         }
 
-        for trace in sample.traces.iter_mut() {
-            if !(config.include_idle || trace.active) {
-                continue;
-            }
-
-            if config.gil_only && !trace.owns_gil {
-                continue;
-            }
+        if let Some(stack_filter) = &stack_filter {
+            stack_filter.filter_sample(&mut sample);
+        }
 
-            if config.include_thread_ids {
-                let threadid = trace.format_threadid();
-                let thread_fmt = if let Some(thread_name) = &trace.thread_name {
-                    format!("thread ({threadid}): {thread_name}")
-                } else {
-                    format!("thread ({threadid})")
-                };
-                trace.frames.push(Frame {
-                    name: thread_fmt,
-                    filename: String::from(""),
-                    module: None,
-                    short_filename: None,
-                    line: 0,
-                    locals: None,
-                    is_entry: true,
-                    is_shim_entry: true,
+        let last = outputs.len() - 1;
+        for (i, output) in outputs.iter_mut().enumerate() {
+            // traces are decorated for each format, so all but the last output get a copy
+            let recorded = if i == last {
+                output
+                    .recorder
+                    .increment_sample(&mut sample, &output.config)?
+            } else {
+                // errors can't be cloned, so copies get a new error with the same message
+                let sampling_errors = sample.sampling_errors.as_ref().map(|errors| {
+                    errors
+                        .iter()
+                        .map(|(pid, e)| (*pid, format_err!("{e:#}")))
+                        .collect()
                 });
-            }
-
-            if let Some(process_info) = trace.process_info.as_ref() {
-                trace.frames.push(process_info.to_frame());
-                let mut parent = process_info.parent.as_ref();
-                while parent.is_some() {
-                    if let Some(process_info) = parent {
-                        trace.frames.push(process_info.to_frame());
-                        parent = process_info.parent.as_ref();
-                    }
-                }
-            }
-
-            samples += 1;
-            output.increment(trace)?;
+                let mut copy = Sample {
+                    traces: sample.traces.clone(),
+                    sampling_errors,
+                    late: sample.late,
+                };
+                output
+                    .recorder
+                    .increment_sample(&mut copy, &output.config)?
+            };
+            output.samples += recorded;
+            output.window_samples += recorded;
         }
 
         if let Some(sampling_errors) = sample.sampling_errors {
@@ -311,7 +619,32 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
             }
         }
 
+        if let Some(rotate_seconds) = config.rotate_seconds {
+            if window_start.elapsed() >= Duration::from_secs(rotate_seconds) {
+                for output in outputs.iter_mut() {
+                    let filename = output_filename(&output.config, &window_start_time)?;
+                    write_output(output.recorder.as_ref(), &filename)?;
+                    let msg = format!(
+                        "{lede}Wrote '{filename}'. Samples: {}",
+                        output.window_samples
+                    );
+                    if config.hide_progress {
+                        status!("{msg}");
+                    } else {
+                        progress.println(msg);
+                    }
+
+                    output.recorder = new_recorder(&output.config)?;
+                    output.window_samples = 0;
+                }
+                window_start = Instant::now();
+                window_start_time = Local::now();
+                files_written += 1;
+            }
+        }
+
         if config.duration == RecordDuration::Unlimited {
+            let samples = outputs[0].samples;
             let msg = if errors > 0 {
                 format!("Collected {samples} samples ({errors} errors)")
             } else {
@@ -324,44 +657,81 @@ fn record_samples(pid: remoteprocess::Pid, config: &Config) -> Result<(), Error>
     progress.finish();
     // write out a message here (so as not to interfere with progress bar) if we ended earlier
     if !exit_message.is_empty() {
-        println!("\n{lede}{exit_message}");
+        status!("\n{lede}{exit_message}");
     }
 
-    {
-        let mut out_file = std::fs::File::create(&filename)?;
-        output.write(&mut out_file)?;
-    }
-
-    match config.format.as_ref().unwrap() {
-        FileFormat::flamegraph => {
-            println!(
-                "{lede}Wrote flamegraph data to '{filename}'. Samples: {samples} Errors: {errors}"
-            );
-            // open generated flame graph in the browser on OSX (theory being that on linux
-            // you might be SSH'ed into a server somewhere and this isn't desired, but on
-            // that is pretty unlikely for osx) (note to self: xdg-open will open on linux)
-            #[cfg(target_os = "macos")]
-            std::process::Command::new("open").arg(&filename).spawn()?;
-        }
-        FileFormat::speedscope => {
-            println!(
-                "{lede}Wrote speedscope file to '{filename}'. Samples: {samples} Errors: {errors}"
-            );
-            println!("{lede}Visit https://www.speedscope.app/ to view");
+    for output in &outputs {
+        // when rotating, there is no need to write out an empty file for the final window
+        if files_written > 0 && output.window_samples == 0 {
+            continue;
         }
-        FileFormat::raw => {
-            println!(
-                "{lede}Wrote raw flamegraph data to '{filename}'. Samples: {samples} Errors: {errors}"
-            );
-            println!("{lede}You can use the flamegraph.pl script from https://github.com/brendangregg/flamegraph to generate a SVG");
-        }
-        FileFormat::chrometrace => {
-            println!(
-                "{lede}Wrote chrome trace to '{filename}'. Samples: {samples} Errors: {errors}"
+
+        let filename = output_filename(&output.config, &window_start_time)?;
+        write_output(output.recorder.as_ref(), &filename)?;
+        let destination = if filename == "-" {
+            String::from("stdout")
+        } else {
+            format!("'{filename}'")
+        };
+        let samples = output.samples;
+
+        if config.instructions {
+            status!(
+                "{lede}Wrote annotated disassembly to {destination}. Samples: {samples} Errors: {errors}"
             );
-            println!("{lede}Visit chrome://tracing or https://ui.perfetto.dev/ to view");
-        }
-    };
+            continue;
+        }
+
+        match output.config.format.as_ref().unwrap() {
+            FileFormat::flamegraph => {
+                status!(
+                    "{lede}Wrote flamegraph data to {destination}. Samples: {samples} Errors: {errors}"
+                );
+                // open generated flame graph in the browser on OSX (theory being that on linux
+                // you might be SSH'ed into a server somewhere and this isn't desired, but on
+                // that is pretty unlikely for osx) (note to self: xdg-open will open on linux)
+                #[cfg(target_os = "macos")]
+                if filename != "-" {
+                    std::process::Command::new("open").arg(&filename).spawn()?;
+                }
+            }
+            FileFormat::speedscope => {
+                status!(
+                    "{lede}Wrote speedscope file to {destination}. Samples: {samples} Errors: {errors}"
+                );
+                status!("{lede}Visit https://www.speedscope.app/ to view");
+            }
+            FileFormat::raw => {
+                status!(
+                    "{lede}Wrote raw flamegraph data to {destination}. Samples: {samples} Errors: {errors}"
+                );
+                status!("{lede}You can use the flamegraph.pl script from https://github.com/brendangregg/flamegraph to generate a SVG");
+            }
+            FileFormat::chrometrace => {
+                status!(
+                    "{lede}Wrote chrome trace to {destination}. Samples: {samples} Errors: {errors}"
+                );
+                status!("{lede}Visit chrome://tracing or https://ui.perfetto.dev/ to view");
+            }
+            FileFormat::pprof => {
+                status!(
+                    "{lede}Wrote pprof profile to {destination}. Samples: {samples} Errors: {errors}"
+                );
+                status!("{lede}You can use 'go tool pprof' to view");
+            }
+            FileFormat::pyspy => {
+                status!(
+                    "{lede}Wrote sample log to {destination}. Samples: {samples} Errors: {errors}"
+                );
+                status!("{lede}You can use 'py-spy report {filename} --format <format>' to view");
+            }
+            FileFormat::annotate => {
+                status!(
+                    "{lede}Wrote annotated source to {destination}. Samples: {samples} Errors: {errors}"
+                );
+            }
+        };
+    }
 
     Ok(())
 }
@@ -377,6 +747,9 @@ fn run_spy_command(pid: remoteprocess::Pid, config: &config::Config) -> Result<(
         "top" => {
             sample_console(pid, config)?;
         }
+        "serve" => {
+            serve::serve(pid, config)?;
+        }
         _ => {
             // shouldn't happen
             return Err(format_err!("Unknown command {}", config.command));
@@ -388,6 +761,14 @@ fn run_spy_command(pid: remoteprocess::Pid, config: &config::Config) -> Result<(
 fn pyspy_main() -> Result<(), Error> {
     let config = config::Config::from_commandline();
 
+    // comparing and converting profiles only reads files, so doesn't need any elevated permissions
+    match config.command.as_str() {
+        "diff" => return diff::diff(&config),
+        "convert" => return convert::convert(&config),
+        "report" => return report::report(&config),
+        _ => {}
+    }
+
     #[cfg(target_os = "macos")]
     {
         if unsafe { libc::geteuid() } != 0 {
@@ -435,6 +816,10 @@ fn pyspy_main() -> Result<(), Error> {
                 .stdin(std::process::Stdio::null())
                 .stdout(process_output.reopen()?)
                 .stderr(process_output.reopen()?)
+        } else if config.command == "record" && config.filenames.iter().any(|f| f == "-") {
+            // the profile is being written to our stdout, so send the program's output to
+            // stderr instead of mixing it in with the profile
+            command = command.stdout(std::io::stderr());
         }
 
         let mut command = command
@@ -0,0 +1,520 @@
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use addr2line::gimli::{
+    BaseAddresses, CfaRule, EhFrame, LittleEndian, Register, RegisterRule, UnwindContext,
+    UnwindSection,
+};
+use addr2line::Loader;
+use anyhow::Error;
+use memmap2::Mmap;
+use remoteprocess::{ProcessMemory, StackFrame};
+
+use crate::coredump::CoreMapRange;
+use crate::native_stack_trace::Symbolicate;
+
+/*
+ * This file contains code to unwind and symbolicate the native stacks of the threads in
+ * a core dump. Since there isn't a running process here, we can't use the unwinder and
+ * symbolicator from remoteprocess: instead the stacks are unwound starting from the registers
+ * saved in the core, using the .eh_frame call frame information of each mapped binary
+ * (falling back to following frame pointers), and then symbolicated with addr2line.
+ */
+
+#[cfg(target_arch = "x86_64")]
+mod arch {
+    pub const SUPPORTED: bool = true;
+    /// The index of each DWARF register in the elf_gregset_t of a core dump
+    pub const DWARF_REGISTERS: &[usize] = &[
+        10, // rax
+        12, // rdx
+        11, // rcx
+        5,  // rbx
+        13, // rsi
+        14, // rdi
+        4,  // rbp
+        19, // rsp
+        9,  // r8
+        8,  // r9
+        7,  // r10
+        6,  // r11
+        3,  // r12
+        2,  // r13
+        1,  // r14
+        0,  // r15
+        16, // return address (rip)
+    ];
+    pub const PROGRAM_COUNTER: usize = 16;
+    pub const RETURN_ADDRESS: u16 = 16;
+    pub const STACK_POINTER: u16 = 7;
+    pub const FRAME_POINTER: u16 = 6;
+}
+
+#[cfg(target_arch = "aarch64")]
+mod arch {
+    pub const SUPPORTED: bool = true;
+    /// x0-x30 and sp have the same DWARF register numbers as their index in elf_gregset_t
+    pub const DWARF_REGISTERS: &[usize] = &[
+        0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24,
+        25, 26, 27, 28, 29, 30, 31,
+    ];
+    pub const PROGRAM_COUNTER: usize = 32;
+    pub const RETURN_ADDRESS: u16 = 30;
+    pub const STACK_POINTER: u16 = 31;
+    pub const FRAME_POINTER: u16 = 29;
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+mod arch {
+    pub const SUPPORTED: bool = false;
+    pub const DWARF_REGISTERS: &[usize] = &[];
+    pub const PROGRAM_COUNTER: usize = 0;
+    pub const RETURN_ADDRESS: u16 = 0;
+    pub const STACK_POINTER: u16 = 0;
+    pub const FRAME_POINTER: u16 = 0;
+}
+
+/// Limits how many frames we will unwind, in case the stack in the core dump is corrupt
+const MAX_FRAMES: usize = 1024;
+
+/// A binary that was mapped into the address space of the process
+struct Module {
+    filename: PathBuf,
+    start: u64,
+    end: u64,
+    /// The difference between the address the binary was loaded at and its virtual addresses
+    bias: u64,
+}
+
+impl Module {
+    fn contains(&self, addr: u64) -> bool {
+        addr >= self.start && addr < self.end
+    }
+}
+
+/// Gets the binaries mapped into the core dump, along with the address each was loaded at
+fn get_modules(maps: &[CoreMapRange]) -> Vec<Module> {
+    let mut ranges: HashMap<&Path, (u64, u64)> = HashMap::new();
+    for map in maps {
+        if let Some(filename) = map.filename() {
+            let start = map.start() as u64;
+            let end = start + map.size() as u64;
+            let range = ranges.entry(filename).or_insert((start, end));
+            range.0 = range.0.min(start);
+            range.1 = range.1.max(end);
+        }
+    }
+
+    let mut modules = Vec::new();
+    for (filename, (start, end)) in ranges {
+        // figure out the load bias from the lowest loadable segment in the binary. This is zero
+        // for non-PIE executables, and the start of the mapping for shared libraries
+        let base_vaddr = match File::open(filename)
+            .and_then(|file| unsafe { Mmap::map(&file) })
+            .map_err(Error::from)
+            .and_then(|data| {
+                let elf = goblin::elf::Elf::parse(&data)?;
+                Ok(elf
+                    .program_headers
+                    .iter()
+                    .filter(|ph| ph.p_type == goblin::elf::program_header::PT_LOAD)
+                    .map(|ph| ph.p_vaddr & !(page_size::get() as u64 - 1))
+                    .min()
+                    .unwrap_or(0))
+            }) {
+            Ok(base_vaddr) => base_vaddr,
+            Err(e) => {
+                info!("Failed to load binary {}: {}", filename.display(), e);
+                continue;
+            }
+        };
+
+        modules.push(Module {
+            filename: filename.to_owned(),
+            start,
+            end,
+            bias: start.wrapping_sub(base_vaddr),
+        });
+    }
+    modules
+}
+
+/// Call frame information loaded from the .eh_frame section of a binary
+struct UnwindInfo {
+    data: Mmap,
+    eh_frame: (usize, usize),
+    eh_frame_addr: u64,
+    text_addr: u64,
+}
+
+impl UnwindInfo {
+    fn new(filename: &Path) -> Result<UnwindInfo, Error> {
+        let file = File::open(filename)?;
+        let data = unsafe { Mmap::map(&file)? };
+        let elf = goblin::elf::Elf::parse(&data)?;
+
+        let mut eh_frame = None;
+        let mut text_addr = 0;
+        for section in &elf.section_headers {
+            match elf.shdr_strtab.get_at(section.sh_name) {
+                Some(".eh_frame") => eh_frame = Some(section.clone()),
+                Some(".text") => text_addr = section.sh_addr,
+                _ => {}
+            }
+        }
+        let eh_frame = eh_frame
+            .ok_or_else(|| format_err!("No .eh_frame section in {}", filename.display()))?;
+
+        let start = eh_frame.sh_offset as usize;
+        let end = start + eh_frame.sh_size as usize;
+        if end > data.len() {
+            return Err(format_err!(
+                "Invalid .eh_frame section in {}",
+                filename.display()
+            ));
+        }
+
+        Ok(UnwindInfo {
+            eh_frame: (start, end),
+            eh_frame_addr: eh_frame.sh_addr,
+            text_addr,
+            data,
+        })
+    }
+}
+
+/// Unwinds the native stacks of threads in a core dump
+pub struct CoreUnwinder {
+    modules: Vec<(Module, Option<UnwindInfo>)>,
+}
+
+impl CoreUnwinder {
+    pub fn new(maps: &[CoreMapRange]) -> CoreUnwinder {
+        let modules = get_modules(maps)
+            .into_iter()
+            .map(|module| {
+                let info = UnwindInfo::new(&module.filename)
+                    .map_err(|e| info!("Failed to load unwind info: {}", e))
+                    .ok();
+                (module, info)
+            })
+            .collect();
+        CoreUnwinder { modules }
+    }
+
+    /// Unwinds a thread starting from the registers saved in the core dump, returning the
+    /// instruction pointer of each frame. The addresses of the calling frames are adjusted to
+    /// point inside the call instruction, so that they symbolicate to the line of the call
+    pub fn unwind<P: ProcessMemory>(
+        &self,
+        memory: &P,
+        registers: &[u64],
+    ) -> Result<Vec<u64>, Error> {
+        if !arch::SUPPORTED {
+            return Err(format_err!(
+                "Native unwinding of core dumps isn't supported on this architecture"
+            ));
+        }
+
+        let mut regs: Vec<Option<u64>> = arch::DWARF_REGISTERS
+            .iter()
+            .map(|&index| registers.get(index).copied())
+            .collect();
+        let mut pc = registers
+            .get(arch::PROGRAM_COUNTER)
+            .copied()
+            .ok_or_else(|| format_err!("Failed to get program counter from core dump"))?;
+
+        let mut stack = Vec::new();
+        while pc != 0 && stack.len() < MAX_FRAMES {
+            let addr = if stack.is_empty() { pc } else { pc - 1 };
+            stack.push(addr);
+
+            let sp = regs[arch::STACK_POINTER as usize];
+            pc = match self.step(memory, addr, &mut regs) {
+                Ok(Some(pc)) => pc,
+                Ok(None) => break,
+                Err(e) => {
+                    info!("Failed to unwind frame at 0x{:x}: {}", addr, e);
+                    break;
+                }
+            };
+
+            // the stack grows down, so if the stack pointer didn't increase we're looping
+            if regs[arch::STACK_POINTER as usize] <= sp {
+                info!("Stack pointer didn't increase unwinding 0x{:x}", addr);
+                break;
+            }
+        }
+        Ok(stack)
+    }
+
+    /// Updates the registers to the values they had in the calling frame, and returns the
+    /// return address (or None if we've reached the end of the stack)
+    fn step<P: ProcessMemory>(
+        &self,
+        memory: &P,
+        addr: u64,
+        regs: &mut [Option<u64>],
+    ) -> Result<Option<u64>, Error> {
+        let info = self
+            .modules
+            .iter()
+            .find(|(module, _)| module.contains(addr))
+            .and_then(|(module, info)| info.as_ref().map(|info| (module, info)));
+
+        if let Some((module, info)) = info {
+            match self.step_cfi(memory, module, info, addr, regs) {
+                Ok(ret) => return Ok(ret),
+                Err(e) => debug!("Failed to unwind 0x{:x} with cfi: {}", addr, e),
+            }
+        }
+        self.step_frame_pointer(memory, regs)
+    }
+
+    fn step_cfi<P: ProcessMemory>(
+        &self,
+        memory: &P,
+        module: &Module,
+        info: &UnwindInfo,
+        addr: u64,
+        regs: &mut [Option<u64>],
+    ) -> Result<Option<u64>, Error> {
+        let eh_frame = EhFrame::new(&info.data[info.eh_frame.0..info.eh_frame.1], LittleEndian);
+        let bases = BaseAddresses::default()
+            .set_eh_frame(info.eh_frame_addr)
+            .set_text(info.text_addr);
+        let mut ctx = UnwindContext::new();
+        let row = eh_frame.unwind_info_for_address(
+            &bases,
+            &mut ctx,
+            addr.wrapping_sub(module.bias),
+            EhFrame::cie_from_offset,
+        )?;
+
+        let cfa = match row.cfa() {
+            CfaRule::RegisterAndOffset { register, offset } => regs
+                .get(register.0 as usize)
+                .copied()
+                .flatten()
+                .ok_or_else(|| format_err!("Unknown CFA register {}", register.0))?
+                .wrapping_add(*offset as u64),
+            CfaRule::Expression(_) => return Err(format_err!("Unsupported CFA expression")),
+        };
+
+        let mut caller = regs.to_vec();
+        for (register, value) in caller.iter_mut().enumerate() {
+            *value = match row.register(Register(register as u16)) {
+                // registers without a rule are callee saved, and keep their value
+                RegisterRule::Undefined | RegisterRule::SameValue => *value,
+                RegisterRule::Offset(offset) => {
+                    Some(memory.copy_struct::<u64>(cfa.wrapping_add(offset as u64) as usize)?)
+                }
+                RegisterRule::ValOffset(offset) => Some(cfa.wrapping_add(offset as u64)),
+                RegisterRule::Register(other) => regs.get(other.0 as usize).copied().flatten(),
+                _ => None,
+            };
+        }
+
+        // an undefined return address marks the outermost frame of the thread
+        if let RegisterRule::Undefined = row.register(Register(arch::RETURN_ADDRESS)) {
+            return Ok(None);
+        }
+
+        caller[arch::STACK_POINTER as usize] = Some(cfa);
+        let return_address = caller[arch::RETURN_ADDRESS as usize];
+        regs.copy_from_slice(&caller);
+        Ok(return_address)
+    }
+
+    fn step_frame_pointer<P: ProcessMemory>(
+        &self,
+        memory: &P,
+        regs: &mut [Option<u64>],
+    ) -> Result<Option<u64>, Error> {
+        // both x86_64 and aarch64 store the previous frame pointer at the frame pointer,
+        // with the return address directly after it
+        let fp = match regs[arch::FRAME_POINTER as usize] {
+            Some(fp) if fp != 0 => fp,
+            _ => return Ok(None),
+        };
+        // a corrupt frame pointer at the top of the address space ends the unwind
+        let sp = match fp.checked_add(16) {
+            Some(sp) => sp,
+            None => return Ok(None),
+        };
+        let caller_fp = memory.copy_struct::<u64>(fp as usize)?;
+        let return_address = memory.copy_struct::<u64>(fp as usize + 8)?;
+
+        regs[arch::FRAME_POINTER as usize] = Some(caller_fp);
+        regs[arch::STACK_POINTER as usize] = Some(sp);
+        regs[arch::RETURN_ADDRESS as usize] = Some(return_address);
+        Ok(Some(return_address))
+    }
+}
+
+/// Symbolicates addresses in a core dump, using the binaries that were mapped into the process
+pub struct CoreSymbolicator {
+    modules: Vec<(Module, Option<Loader>)>,
+}
+
+impl CoreSymbolicator {
+    pub fn new(maps: &[CoreMapRange]) -> CoreSymbolicator {
+        let modules = get_modules(maps)
+            .into_iter()
+            .map(|module| {
+                let loader = Loader::new(&module.filename)
+                    .map_err(|e| {
+                        info!(
+                            "Failed to load symbols for {}: {}",
+                            module.filename.display(),
+                            e
+                        )
+                    })
+                    .ok();
+                (module, loader)
+            })
+            .collect();
+        CoreSymbolicator { modules }
+    }
+}
+
+impl Symbolicate for CoreSymbolicator {
+    fn symbolicate(
+        &self,
+        addr: u64,
+        line_info: bool,
+        callback: &mut dyn FnMut(&StackFrame),
+    ) -> Result<(), remoteprocess::Error> {
+        let (module, loader) = self
+            .modules
+            .iter()
+            .find(|(module, _)| module.contains(addr))
+            .and_then(|(module, loader)| loader.as_ref().map(|loader| (module, loader)))
+            .ok_or(remoteprocess::Error::NoBinaryForAddress(addr))?;
+
+        let probe = addr.wrapping_sub(module.bias);
+        let filename = module.filename.to_string_lossy().to_string();
+
+        // use the debug info if we have it, which includes inlined functions
+        if line_info {
+            if let Ok(mut frames) = loader.find_frames(probe) {
+                let mut found = false;
+                while let Ok(Some(frame)) = frames.next() {
+                    let function = match frame.function.as_ref().map(|f| f.raw_name()) {
+                        Some(Ok(name)) => name.to_string(),
+                        _ => continue,
+                    };
+                    let location = frame.location.as_ref();
+                    callback(&StackFrame {
+                        line: location.and_then(|l| l.line).map(|line| line as u64),
+                        filename: location.and_then(|l| l.file).map(|f| f.to_owned()),
+                        function: Some(function),
+                        module: filename.clone(),
+                        addr,
+                    });
+                    found = true;
+                }
+                if found {
+                    return Ok(());
+                }
+            }
+        }
+
+        // otherwise fall back to the symbol table
+        callback(&StackFrame {
+            line: None,
+            filename: None,
+            function: loader.find_symbol(probe).map(|s| s.to_owned()),
+            module: filename,
+            addr,
+        });
+        Ok(())
+    }
+
+    fn reload(&mut self) -> Result<(), remoteprocess::Error> {
+        // the binaries mapped into a core dump can't change
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Gets the file backed mappings of the current process, in the same form as a core dump
+    fn local_maps() -> Vec<CoreMapRange> {
+        let maps = std::fs::read_to_string("/proc/self/maps").unwrap();
+        maps.lines()
+            .filter_map(|line| {
+                let parts: Vec<&str> = line.split_whitespace().collect();
+                let path = parts.get(5).filter(|path| path.starts_with('/'))?;
+                let (start, end) = parts[0].split_once('-')?;
+                let start = u64::from_str_radix(start, 16).ok()?;
+                let end = u64::from_str_radix(end, 16).ok()?;
+
+                let mut segment = goblin::elf::ProgramHeader::new();
+                segment.p_vaddr = start;
+                segment.p_memsz = end - start;
+                if parts[1].contains('x') {
+                    segment.p_flags |= goblin::elf::program_header::PF_X;
+                }
+                Some(CoreMapRange {
+                    pathname: Some(PathBuf::from(path)),
+                    file_offset: u64::from_str_radix(parts[2], 16).ok()?,
+                    segment,
+                })
+            })
+            .collect()
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[inline(never)]
+    fn unwind_current_thread() -> Vec<String> {
+        let (rip, rsp, rbp): (u64, u64, u64);
+        unsafe {
+            std::arch::asm!("lea {0}, [rip]", "mov {1}, rsp", "mov {2}, rbp",
+                out(reg) rip, out(reg) rsp, out(reg) rbp);
+        }
+        let mut registers = [0; 27];
+        registers[16] = rip;
+        registers[19] = rsp;
+        registers[4] = rbp;
+
+        let maps = local_maps();
+        let stack = CoreUnwinder::new(&maps)
+            .unwind(&remoteprocess::LocalProcess, &registers)
+            .unwrap();
+
+        let symbolicator = CoreSymbolicator::new(&maps);
+        let mut functions = Vec::new();
+        for addr in stack {
+            let _ = symbolicator.symbolicate(addr, false, &mut |frame| {
+                functions.extend(frame.function.clone());
+            });
+        }
+        functions
+    }
+
+    #[test]
+    fn test_step_frame_pointer_overflow() {
+        let unwinder = CoreUnwinder::new(&[]);
+        let mut regs = vec![None; 64];
+        regs[arch::FRAME_POINTER as usize] = Some(u64::MAX - 8);
+        let next = unwinder
+            .step_frame_pointer(&remoteprocess::LocalProcess, &mut regs)
+            .unwrap();
+        assert_eq!(next, None);
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn test_unwind_local() {
+        let functions = unwind_current_thread();
+        assert!(functions
+            .iter()
+            .any(|f| f.contains("unwind_current_thread")));
+        assert!(functions.iter().any(|f| f.contains("test_unwind_local")));
+    }
+}
@@ -12,16 +12,43 @@ use crate::cython;
 use crate::stack_trace::Frame;
 use crate::utils::resolve_filename;
 
+/// Looks up the function names and source locations for native addresses
+pub trait Symbolicate {
+    fn symbolicate(
+        &self,
+        addr: u64,
+        line_info: bool,
+        callback: &mut dyn FnMut(&remoteprocess::StackFrame),
+    ) -> Result<(), remoteprocess::Error>;
+
+    fn reload(&mut self) -> Result<(), remoteprocess::Error>;
+}
+
+impl Symbolicate for remoteprocess::Symbolicator {
+    fn symbolicate(
+        &self,
+        addr: u64,
+        line_info: bool,
+        callback: &mut dyn FnMut(&remoteprocess::StackFrame),
+    ) -> Result<(), remoteprocess::Error> {
+        remoteprocess::Symbolicator::symbolicate(self, addr, line_info, callback)
+    }
+
+    fn reload(&mut self) -> Result<(), remoteprocess::Error> {
+        remoteprocess::Symbolicator::reload(self)
+    }
+}
+
 pub struct NativeStack {
     should_reload: bool,
     python: Option<BinaryInfo>,
     libpython: Option<BinaryInfo>,
     cython_maps: cython::SourceMaps,
-    unwinder: remoteprocess::Unwinder,
-    symbolicator: remoteprocess::Symbolicator,
+    unwinder: Option<remoteprocess::Unwinder>,
+    symbolicator: Box<dyn Symbolicate>,
     // TODO: right now on windows if we don't hold on the process handle unwinding will fail
     #[allow(dead_code)]
-    process: remoteprocess::Process,
+    process: Option<remoteprocess::Process>,
     symbol_cache: LruCache<u64, remoteprocess::StackFrame>,
 }
 
@@ -39,16 +66,36 @@ impl NativeStack {
 
         Ok(NativeStack {
             cython_maps,
-            unwinder,
-            symbolicator,
+            unwinder: Some(unwinder),
+            symbolicator: Box::new(symbolicator),
             should_reload: false,
             python,
             libpython,
-            process,
+            process: Some(process),
             symbol_cache: LruCache::new(NonZeroUsize::new(65536).unwrap()),
         })
     }
 
+    /// Creates a NativeStack that merges native stacks that have already been unwound, like
+    /// the stacks of the threads in a core dump, rather than unwinding a running process
+    #[cfg(target_os = "linux")]
+    pub fn with_symbolicator(
+        python: Option<BinaryInfo>,
+        libpython: Option<BinaryInfo>,
+        symbolicator: Box<dyn Symbolicate>,
+    ) -> NativeStack {
+        NativeStack {
+            cython_maps: cython::SourceMaps::new(),
+            unwinder: None,
+            symbolicator,
+            should_reload: false,
+            python,
+            libpython,
+            process: None,
+            symbol_cache: LruCache::new(NonZeroUsize::new(65536).unwrap()),
+        }
+    }
+
     pub fn merge_native_thread(
         &mut self,
         frames: &Vec<Frame>,
@@ -148,7 +195,10 @@ impl NativeStack {
                     merged.push(Frame {
                         filename: "?".to_owned(),
                         name: format!("0x{:x}", addr),
+                        qualname: None,
                         line: 0,
+                        columns: None,
+                        instruction: None,
                         short_filename: None,
                         module: None,
                         locals: None,
@@ -290,7 +340,10 @@ impl NativeStack {
                 Some(Frame {
                     filename,
                     line,
+                    columns: None,
+                    instruction: None,
                     name,
+                    qualname: None,
                     short_filename: None,
                     module: Some(frame.module.clone()),
                     locals: None,
@@ -301,8 +354,11 @@ impl NativeStack {
             None => Some(Frame {
                 filename: frame.module.clone(),
                 name: format!("0x{:x}", frame.addr),
+                qualname: None,
                 locals: None,
                 line: 0,
+                columns: None,
+                instruction: None,
                 short_filename: None,
                 module: Some(frame.module.clone()),
                 is_entry: true,
@@ -312,8 +368,12 @@ impl NativeStack {
     }
 
     fn get_thread(&mut self, thread: &remoteprocess::Thread) -> Result<Vec<u64>, Error> {
+        let unwinder = self
+            .unwinder
+            .as_ref()
+            .ok_or_else(|| format_err!("Native unwinding requires a running process"))?;
         let mut stack = Vec::new();
-        for ip in self.unwinder.cursor(thread)? {
+        for ip in unwinder.cursor(thread)? {
             stack.push(ip?);
         }
         Ok(stack)
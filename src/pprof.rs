@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::Error;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+
+use crate::config::Config;
+use crate::stack_trace::StackTrace;
+
+/*
+ * This file contains code to export py-spy profiles in the pprof format, as used by
+ * 'go tool pprof' and most continuous profiling services.
+ *
+ * The protobuf definition of this format can be found here:
+ * https://github.com/google/pprof/blob/main/proto/profile.proto
+ *
+ * Rather than pulling in a protobuf code generator for a single message type, the handful
+ * of fields we need are encoded by hand below. The encoded profile is gzip compressed,
+ * which is what pprof expects to read.
+ */
+
+#[derive(Debug, Clone, Hash, Eq, PartialEq)]
+struct Label {
+    key: i64,
+    str: i64,
+    num: i64,
+}
+
+#[derive(Debug, Clone)]
+struct Function {
+    id: u64,
+    name: i64,
+    filename: i64,
+}
+
+#[derive(Debug, Clone)]
+struct Location {
+    id: u64,
+    function_id: u64,
+    line: i64,
+}
+
+pub struct Pprof {
+    strings: Vec<String>,
+    string_index: HashMap<String, i64>,
+    functions: Vec<Function>,
+    function_index: HashMap<(String, String), u64>,
+    locations: Vec<Location>,
+    location_index: HashMap<(u64, i64), u64>,
+    samples: HashMap<(Vec<u64>, Vec<Label>), (i64, i64)>,
+    show_linenumbers: bool,
+    cpu_time: bool,
+    sampling_rate: u64,
+    start_time: SystemTime,
+    start_ts: Instant,
+}
+
+impl Pprof {
+    pub fn new(config: &Config) -> Pprof {
+        let mut pprof = Pprof {
+            strings: Vec::new(),
+            string_index: HashMap::new(),
+            functions: Vec::new(),
+            function_index: HashMap::new(),
+            locations: Vec::new(),
+            location_index: HashMap::new(),
+            samples: HashMap::new(),
+            show_linenumbers: config.show_line_numbers,
+            cpu_time: false,
+            sampling_rate: config.sampling_rate,
+            start_time: SystemTime::now(),
+            start_ts: Instant::now(),
+        };
+        // the first entry in the string table is required to be the empty string
+        for s in ["", "samples", "count", "wall", "cpu", "nanoseconds"] {
+            pprof.string(s);
+        }
+        pprof
+    }
+
+    pub fn increment(&mut self, trace: &StackTrace) -> std::io::Result<()> {
+        let period = 1_000_000_000 / self.sampling_rate.max(1) as i64;
+        self.add_sample(trace, period)
+    }
+
+    /// Records a stack trace along with the CPU time in microseconds that the thread used
+    /// since the previous sample. Profiles with CPU times are written with a 'cpu' sample type
+    pub fn increment_cpu_time(&mut self, trace: &StackTrace, cpu_time: u64) -> std::io::Result<()> {
+        self.cpu_time = true;
+        self.add_sample(trace, cpu_time as i64 * 1000)
+    }
+
+    fn add_sample(&mut self, trace: &StackTrace, nanoseconds: i64) -> std::io::Result<()> {
+        // pprof expects locations ordered from the leaf frame to the root, which
+        // matches the order of the frames in the stack trace
+        let locations = trace
+            .frames
+            .iter()
+            .map(|frame| {
+                let line = if self.show_linenumbers {
+                    frame.line as i64
+                } else {
+                    0
+                };
+                let function_id = self.function(frame.display_name(), &frame.filename);
+                self.location(function_id, line)
+            })
+            .collect();
+
+        let mut labels = vec![
+            self.num_label("thread_id", trace.thread_id as i64),
+            self.num_label("pid", trace.pid as i64),
+            self.str_label("status", trace.status_str()),
+        ];
+        if let Some(os_thread_id) = trace.os_thread_id {
+            labels.push(self.num_label("os_thread_id", os_thread_id as i64));
+        }
+        if let Some(thread_name) = trace.thread_name.as_ref() {
+            labels.push(self.str_label("thread_name", thread_name));
+        }
+        if let Some(interpreter_id) = trace.interpreter_id {
+            labels.push(self.num_label("interpreter_id", interpreter_id));
+        }
+        if let Some(task_name) = trace.task_name.as_ref() {
+            labels.push(self.str_label("task", task_name));
+        }
+        if trace.owns_gil {
+            labels.push(self.str_label("gil", "true"));
+        }
+        if !trace.active {
+            labels.push(self.str_label("idle", "true"));
+        }
+        for (name, value) in &trace.labels {
+            labels.push(self.str_label(name, value));
+        }
+
+        let value = self.samples.entry((locations, labels)).or_insert((0, 0));
+        value.0 += 1;
+        value.1 += nanoseconds;
+        Ok(())
+    }
+
+    pub fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        let mut encoder = GzEncoder::new(w, Compression::default());
+        encoder.write_all(&self.encode())?;
+        encoder.finish()?;
+        Ok(())
+    }
+
+    fn string(&mut self, s: &str) -> i64 {
+        if let Some(index) = self.string_index.get(s) {
+            return *index;
+        }
+        let index = self.strings.len() as i64;
+        self.strings.push(s.to_owned());
+        self.string_index.insert(s.to_owned(), index);
+        index
+    }
+
+    fn function(&mut self, name: &str, filename: &str) -> u64 {
+        let key = (name.to_owned(), filename.to_owned());
+        if let Some(id) = self.function_index.get(&key) {
+            return *id;
+        }
+        let id = self.functions.len() as u64 + 1;
+        let function = Function {
+            id,
+            name: self.string(name),
+            filename: self.string(filename),
+        };
+        self.functions.push(function);
+        self.function_index.insert(key, id);
+        id
+    }
+
+    fn location(&mut self, function_id: u64, line: i64) -> u64 {
+        let locations = &mut self.locations;
+        *self
+            .location_index
+            .entry((function_id, line))
+            .or_insert_with(|| {
+                let id = locations.len() as u64 + 1;
+                locations.push(Location {
+                    id,
+                    function_id,
+                    line,
+                });
+                id
+            })
+    }
+
+    fn num_label(&mut self, key: &str, num: i64) -> Label {
+        Label {
+            key: self.string(key),
+            str: 0,
+            num,
+        }
+    }
+
+    fn str_label(&mut self, key: &str, value: &str) -> Label {
+        Label {
+            key: self.string(key),
+            str: self.string(value),
+            num: 0,
+        }
+    }
+
+    /// Encodes the profile as a serialized 'perftools.profiles.Profile' protobuf message
+    fn encode(&self) -> Vec<u8> {
+        let samples = self.string_index["samples"];
+        let count = self.string_index["count"];
+        let time = if self.cpu_time {
+            self.string_index["cpu"]
+        } else {
+            self.string_index["wall"]
+        };
+        let nanoseconds = self.string_index["nanoseconds"];
+        let period = 1_000_000_000 / self.sampling_rate.max(1) as i64;
+
+        let mut profile = ProtoEncoder::new();
+
+        // sample_type
+        for (value_type, unit) in [(samples, count), (time, nanoseconds)] {
+            profile.message(1, |m| {
+                m.int64(1, value_type);
+                m.int64(2, unit);
+            });
+        }
+
+        // sample
+        for ((locations, labels), (count, nanos)) in &self.samples {
+            profile.message(2, |m| {
+                m.packed(1, locations.iter().copied());
+                m.packed(2, [*count, *nanos].iter().map(|&x| x as u64));
+                for label in labels {
+                    m.message(3, |l| {
+                        l.int64(1, label.key);
+                        l.int64(2, label.str);
+                        l.int64(3, label.num);
+                    });
+                }
+            });
+        }
+
+        // location
+        for location in &self.locations {
+            profile.message(4, |m| {
+                m.uint64(1, location.id);
+                m.message(4, |l| {
+                    l.uint64(1, location.function_id);
+                    l.int64(2, location.line);
+                });
+            });
+        }
+
+        // function
+        for function in &self.functions {
+            profile.message(5, |m| {
+                m.uint64(1, function.id);
+                m.int64(2, function.name);
+                m.int64(3, function.name);
+                m.int64(4, function.filename);
+            });
+        }
+
+        // string_table
+        for s in &self.strings {
+            profile.bytes(6, s.as_bytes());
+        }
+
+        let time_nanos = self
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as i64)
+            .unwrap_or(0);
+        profile.int64(9, time_nanos);
+        profile.int64(10, self.start_ts.elapsed().as_nanos() as i64);
+
+        // period_type / period
+        profile.message(11, |m| {
+            m.int64(1, time);
+            m.int64(2, nanoseconds);
+        });
+        profile.int64(12, period);
+
+        profile.buf
+    }
+}
+
+/// Minimal protobuf encoder, supporting only the wire types needed for profile.proto
+struct ProtoEncoder {
+    buf: Vec<u8>,
+}
+
+impl ProtoEncoder {
+    fn new() -> ProtoEncoder {
+        ProtoEncoder { buf: Vec::new() }
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn key(&mut self, field: u32, wire_type: u8) {
+        self.varint(((field as u64) << 3) | wire_type as u64);
+    }
+
+    fn uint64(&mut self, field: u32, value: u64) {
+        // default values don't need to be written out in proto3
+        if value != 0 {
+            self.key(field, 0);
+            self.varint(value);
+        }
+    }
+
+    fn int64(&mut self, field: u32, value: i64) {
+        self.uint64(field, value as u64);
+    }
+
+    fn bytes(&mut self, field: u32, data: &[u8]) {
+        self.key(field, 2);
+        self.varint(data.len() as u64);
+        self.buf.extend_from_slice(data);
+    }
+
+    fn packed<I: IntoIterator<Item = u64>>(&mut self, field: u32, values: I) {
+        let mut packed = ProtoEncoder::new();
+        for value in values {
+            packed.varint(value);
+        }
+        if !packed.buf.is_empty() {
+            self.bytes(field, &packed.buf);
+        }
+    }
+
+    fn message<F: FnOnce(&mut ProtoEncoder)>(&mut self, field: u32, f: F) {
+        let mut message = ProtoEncoder::new();
+        f(&mut message);
+        self.bytes(field, &message.buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack_trace::Frame;
+    use flate2::read::GzDecoder;
+    use std::io::Read;
+
+    #[test]
+    fn test_pprof_encoding() {
+        let mut encoder = ProtoEncoder::new();
+        encoder.uint64(1, 300);
+        assert_eq!(encoder.buf, vec![0x08, 0xac, 0x02]);
+
+        let config = Config {
+            show_line_numbers: true,
+            ..Default::default()
+        };
+        let mut pprof = Pprof::new(&config);
+
+        let frame = Frame {
+            name: String::from("test"),
+            qualname: None,
+            filename: String::from("test.py"),
+            module: None,
+            short_filename: None,
+            line: 12,
+            columns: None,
+            instruction: None,
+            locals: None,
+            is_entry: true,
+            is_shim_entry: false,
+        };
+
+        let trace = StackTrace {
+            pid: 1,
+            thread_id: 1,
+            thread_name: Some(String::from("MainThread")),
+            os_thread_id: None,
+            interpreter_id: Some(0),
+            task_name: None,
+            active: true,
+            owns_gil: true,
+            cpu_time: None,
+            labels: Vec::new(),
+            frames: vec![frame.clone(), frame],
+            process_info: None,
+        };
+
+        pprof.increment(&trace).unwrap();
+        pprof.increment(&trace).unwrap();
+        assert_eq!(pprof.functions.len(), 1);
+        assert_eq!(pprof.locations.len(), 1);
+        assert_eq!(pprof.samples.len(), 1);
+
+        let mut compressed = Vec::new();
+        pprof.write(&mut compressed).unwrap();
+        let mut decoded = Vec::new();
+        GzDecoder::new(&compressed[..])
+            .read_to_end(&mut decoded)
+            .unwrap();
+        let contains = |needle: &[u8]| decoded.windows(needle.len()).any(|w| w == needle);
+        assert!(contains(b"test.py"));
+        assert!(contains(b"MainThread"));
+        assert!(contains(b"active+gil"));
+    }
+}
@@ -0,0 +1,701 @@
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use anyhow::{Context, Error};
+use remoteprocess::ProcessMemory;
+use serde_derive::Serialize;
+
+use crate::config::LineNo;
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
+use crate::python_bindings::{v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_7_0};
+use crate::python_data_access::{
+    copy_long, copy_set, copy_string, instance_dict_iterator, object_header_size, object_type_name,
+    DictIterator,
+};
+use crate::python_interpreters::{
+    copy_pointer, CodeObject, FrameObject, InterpreterState, RemoteStruct, ThreadState,
+};
+use crate::stack_trace::{get_location, get_qualname, Frame, StackTrace};
+use crate::version::Version;
+
+/*
+ * This file contains code to reconstruct the logical stacks of asyncio tasks. A coroutine that
+ * is suspended in an await isn't on any thread's call stack - instead we find all the tasks
+ * through the bookkeeping in the 'asyncio.tasks' module, and then follow the chain of awaited
+ * coroutines for each task by reading the object on top of the value stack of each frame.
+ *
+ * None of the asyncio or generator objects we need are exposed in our bindings, so like the
+ * pyruntime offsets, the few fields we need here are hardcoded per python version.
+ */
+
+/// How often to look for the 'asyncio.tasks' module in interpreters that haven't imported it yet
+pub const FIND_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Returns an error for versions of python that we don't know the layout of the task and
+/// generator objects for
+pub fn check_version(version: &Version) -> Result<(), Error> {
+    match version {
+        Version {
+            major: 3,
+            minor: 7..=13,
+            ..
+        } => Ok(()),
+        _ => Err(format_err!(
+            "asyncio tasks aren't supported for Python {}",
+            version
+        )),
+    }
+}
+
+/// An asyncio task in the target process
+#[derive(Debug, Clone, Serialize)]
+pub struct AsyncTask {
+    /// The name of the task, as returned by Task.get_name()
+    pub name: String,
+    /// Whether or not the task is currently running in its event loop
+    pub running: bool,
+    /// The python thread id of the event loop running this task (only set for running tasks)
+    pub thread_id: Option<u64>,
+    /// The name of the task that this task is waiting on, if any
+    pub awaiting: Option<String>,
+    /// The logical call stack of the task, following the chain of awaited coroutines.
+    /// Like with StackTrace, the innermost frame comes first
+    pub frames: Vec<Frame>,
+    #[serde(skip)]
+    address: usize,
+    #[serde(skip)]
+    fut_waiter: usize,
+}
+
+/// Addresses of the objects used by the 'asyncio.tasks' module to keep track of tasks
+#[derive(Debug, Clone, Copy)]
+pub struct AsyncioTasks {
+    /// dict of event loop: running task
+    current_tasks: usize,
+    /// WeakSet of all pending tasks ('_all_tasks' before python 3.12, '_scheduled_tasks' after)
+    all_tasks: usize,
+    /// set of tasks that are eagerly executing (python 3.12+)
+    eager_tasks: usize,
+}
+
+impl AsyncioTasks {
+    /// Finds the task tracking objects from the 'asyncio.tasks' module for an interpreter.
+    /// Returns None if asyncio hasn't been imported.
+    pub fn find<I, P>(
+        interpreter_address: usize,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+        version: &Version,
+    ) -> Result<Option<AsyncioTasks>, Error>
+    where
+        I: InterpreterState,
+        P: ProcessMemory,
+    {
+        if version.major != 3 || version.minor < 7 {
+            return Err(format_err!(
+                "asyncio tasks are only supported for python 3.7+"
+            ));
+        }
+
+        let modules_ptr_ptr = I::modules_ptr_ptr(interpreter_address, offsets);
+        let modules: *const I::Object = process
+            .copy_pointer(modules_ptr_ptr)
+            .context("Failed to copy modules PyObject")?;
+
+        for entry in DictIterator::from(process, version, modules as usize)? {
+            let (key, value) = entry?;
+            let module_name = copy_string(key as *const I::StringObject, process, offsets)?;
+            if module_name != "asyncio.tasks" {
+                continue;
+            }
+
+            let mut tasks = AsyncioTasks {
+                current_tasks: 0,
+                all_tasks: 0,
+                eager_tasks: 0,
+            };
+            for entry in instance_dict_iterator::<I, P>(process, offsets, version, value)? {
+                let (key, value) = entry?;
+                match copy_string(key as *const I::StringObject, process, offsets)?.as_str() {
+                    "_current_tasks" => tasks.current_tasks = value,
+                    "_all_tasks" | "_scheduled_tasks" => tasks.all_tasks = value,
+                    "_eager_tasks" => tasks.eager_tasks = value,
+                    _ => {}
+                }
+            }
+
+            if tasks.current_tasks == 0 || tasks.all_tasks == 0 {
+                return Err(format_err!(
+                    "Failed to find tasks in 'asyncio.tasks' module"
+                ));
+            }
+            return Ok(Some(tasks));
+        }
+        Ok(None)
+    }
+
+    /// Returns the tasks that are currently running in an event loop. Since running coroutines
+    /// are on the thread's call stack, the frames here only include the task's own coroutine
+    pub fn running_tasks<I, P>(
+        &self,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+        version: &Version,
+        lineno: LineNo,
+        qualified_names: bool,
+        arg_names: &mut HashMap<usize, String>,
+    ) -> Result<Vec<AsyncTask>, Error>
+    where
+        I: InterpreterState,
+        P: ProcessMemory,
+    {
+        let mut tasks = Vec::new();
+        for (task_address, thread_id) in self.running::<I, P>(process, offsets, version)? {
+            let mut task = get_task::<I, P>(
+                process,
+                offsets,
+                version,
+                task_address,
+                lineno,
+                qualified_names,
+                false,
+                arg_names,
+            )?;
+            task.running = true;
+            task.thread_id = thread_id;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    /// Returns all the pending tasks, with the logical stack for each task
+    pub fn all_tasks<I, P>(
+        &self,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+        version: &Version,
+        lineno: LineNo,
+        qualified_names: bool,
+        arg_names: &mut HashMap<usize, String>,
+    ) -> Result<Vec<AsyncTask>, Error>
+    where
+        I: InterpreterState,
+        P: ProcessMemory,
+    {
+        let running = self.running::<I, P>(process, offsets, version)?;
+
+        // the WeakSet stores its weakrefs in a set in its 'data' attribute
+        let mut data = 0;
+        for entry in instance_dict_iterator::<I, P>(process, offsets, version, self.all_tasks)? {
+            let (key, value) = entry?;
+            if copy_string(key as *const I::StringObject, process, offsets)? == "data" {
+                data = value;
+                break;
+            }
+        }
+        if data == 0 {
+            return Err(format_err!("Failed to find data for asyncio tasks WeakSet"));
+        }
+
+        let mut addresses = Vec::new();
+        for weakref in copy_set(process, version, data)? {
+            // deleted set entries use a dummy key, which we skip by checking the type
+            if !object_type_name::<I, P>(process, offsets, weakref)?.starts_with("weakref") {
+                continue;
+            }
+            // PyWeakReference stores the referenced object directly after the PyObject header
+            let task: usize = process.copy_struct(weakref + object_header_size(version))?;
+            addresses.push(task);
+        }
+        if self.eager_tasks != 0 {
+            addresses.extend(copy_set(process, version, self.eager_tasks)?);
+        }
+        addresses.extend(running.keys());
+
+        let mut seen = HashSet::new();
+        let mut tasks = Vec::new();
+        for address in addresses {
+            if !seen.insert(address) {
+                continue;
+            }
+            // weakrefs to tasks that have been garbage collected point at None, and tasks
+            // can finish while we're reading them - so just skip tasks we can't read
+            let thread_id = running.get(&address);
+            match get_task::<I, P>(
+                process,
+                offsets,
+                version,
+                address,
+                lineno,
+                qualified_names,
+                thread_id.is_none(),
+                arg_names,
+            ) {
+                Ok(mut task) => {
+                    task.running = thread_id.is_some();
+                    task.thread_id = thread_id.copied().flatten();
+                    tasks.push(task);
+                }
+                Err(e) => info!("Failed to get asyncio task at 0x{:x}: {}", address, e),
+            }
+        }
+
+        // figure out which tasks are waiting on other tasks
+        let names: HashMap<usize, String> = tasks
+            .iter()
+            .map(|task| (task.address, task.name.clone()))
+            .collect();
+        for task in tasks.iter_mut() {
+            task.awaiting = names.get(&task.fut_waiter).cloned();
+        }
+        Ok(tasks)
+    }
+
+    /// Returns a map of running task address: python thread id of the event loop (if known)
+    fn running<I, P>(
+        &self,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+        version: &Version,
+    ) -> Result<HashMap<usize, Option<u64>>, Error>
+    where
+        I: InterpreterState,
+        P: ProcessMemory,
+    {
+        let mut running = HashMap::new();
+        for entry in DictIterator::from(process, version, self.current_tasks)? {
+            let (event_loop, task) = entry?;
+            running.insert(
+                task,
+                loop_thread_id::<I, P>(process, offsets, version, event_loop),
+            );
+        }
+        Ok(running)
+    }
+}
+
+/// Attributes a stack trace to the asyncio task running in its thread (if any), by removing the
+/// event loop frames from below the coroutine of the task
+pub fn attribute_to_task(trace: &mut StackTrace, tasks: &[AsyncTask]) {
+    for task in tasks {
+        if !task.running || task.thread_id.is_some_and(|id| id != trace.thread_id) {
+            continue;
+        }
+        let Some(coroutine) = task.frames.last() else {
+            continue;
+        };
+
+        // the task's coroutine is the outermost matching frame on the stack, anything
+        // below it is the event loop calling into the task
+        if let Some(index) = trace
+            .frames
+            .iter()
+            .rposition(|frame| frame.name == coroutine.name && frame.filename == coroutine.filename)
+        {
+            trace.frames.truncate(index + 1);
+            trace.task_name = Some(task.name.clone());
+            return;
+        }
+    }
+}
+
+/// Reads a single task object, and optionally follows the chain of awaited coroutines
+#[allow(clippy::too_many_arguments)]
+fn get_task<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    addr: usize,
+    lineno: LineNo,
+    qualified_names: bool,
+    follow_awaits: bool,
+    arg_names: &mut HashMap<usize, String>,
+) -> Result<AsyncTask, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let (coroutine, name, fut_waiter) = task_fields::<I, P>(process, offsets, version, addr)?;
+    let name = format_task_name::<I, P>(process, offsets, version, name)?;
+
+    let mut frames = Vec::new();
+    let mut coroutine = coroutine;
+    while let Some(frame_address) = coroutine_frame_address(process, version, coroutine)? {
+        frames.push(get_frame::<I, P>(
+            process,
+            offsets,
+            frame_address,
+            lineno,
+            qualified_names,
+            arg_names,
+        )?);
+        if frames.len() > 4096 {
+            return Err(format_err!("Max await recursion depth reached"));
+        }
+        if !follow_awaits {
+            break;
+        }
+
+        // for a suspended coroutine, the top of the value stack is the object being awaited
+        match frame_stack_top(process, version, frame_address)? {
+            Some(awaited)
+                if is_coroutine_type(&object_type_name::<I, P>(process, offsets, awaited)?) =>
+            {
+                coroutine = awaited
+            }
+            _ => break,
+        }
+    }
+    frames.reverse();
+
+    Ok(AsyncTask {
+        name,
+        running: false,
+        thread_id: None,
+        awaiting: None,
+        frames,
+        address: addr,
+        fut_waiter,
+    })
+}
+
+/// Returns the (coroutine, name, fut_waiter) addresses of a task object
+fn task_fields<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    addr: usize,
+) -> Result<(usize, usize, usize), Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    // the pure python Task stores everything in its instance dict
+    if let Ok(dict) = instance_dict_iterator::<I, P>(process, offsets, version, addr) {
+        let (mut coroutine, mut name, mut fut_waiter) = (0, 0, 0);
+        for entry in dict {
+            let (key, value) = entry?;
+            match copy_string(key as *const I::StringObject, process, offsets)?.as_str() {
+                "_coro" => coroutine = value,
+                "_name" => name = value,
+                "_fut_waiter" => fut_waiter = value,
+                _ => {}
+            }
+        }
+        if coroutine != 0 {
+            return Ok((coroutine, name, fut_waiter));
+        }
+    }
+
+    // The C Task from the _asyncio module has a layout that changes between versions, but the
+    // task_fut_waiter/task_coro/task_name members always come in that order. Rather than
+    // hardcoding offsets, find the coroutine by checking the type of each member
+    let ptr_size = std::mem::size_of::<usize>();
+    let max_members = 32;
+    let members = process.copy(addr + object_header_size(version), max_members * ptr_size)?;
+    let members: Vec<usize> = members
+        .chunks_exact(ptr_size)
+        .map(|member| {
+            let mut value = [0_u8; std::mem::size_of::<usize>()];
+            value.copy_from_slice(member);
+            usize::from_ne_bytes(value)
+        })
+        .collect();
+
+    for i in 1..max_members - 1 {
+        if members[i] == 0 {
+            continue;
+        }
+        // members that aren't pointers will fail to be read here, which is fine
+        if let Ok(type_name) = object_type_name::<I, P>(process, offsets, members[i]) {
+            if is_coroutine_type(&type_name) {
+                return Ok((members[i], members[i + 1], members[i - 1]));
+            }
+        }
+    }
+    Err(format_err!(
+        "Failed to find coroutine for task at 0x{:x}",
+        addr
+    ))
+}
+
+/// Formats the name of a task. Python 3.12+ lazily creates names, storing only the task
+/// counter until the name is requested
+fn format_task_name<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    addr: usize,
+) -> Result<String, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    if addr == 0 {
+        return Ok("Task".to_owned());
+    }
+    match object_type_name::<I, P>(process, offsets, addr)?.as_str() {
+        "str" => copy_string(addr as *const I::StringObject, process, offsets),
+        "int" => Ok(format!("Task-{}", copy_long(process, version, addr)?.0)),
+        type_name => Ok(format!("<{type_name} at 0x{addr:x}>")),
+    }
+}
+
+/// Returns the python thread id that an event loop is running in. This relies on the
+/// '_thread_id' attribute of asyncio's BaseEventLoop, and so won't work for other loops
+fn loop_thread_id<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    event_loop: usize,
+) -> Option<u64>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    for entry in instance_dict_iterator::<I, P>(process, offsets, version, event_loop).ok()? {
+        let (key, value) = entry.ok()?;
+        if copy_string(key as *const I::StringObject, process, offsets).ok()? == "_thread_id" {
+            if object_type_name::<I, P>(process, offsets, value).ok()? != "int" {
+                return None;
+            }
+            return copy_long(process, version, value)
+                .ok()
+                .map(|(thread_id, _)| thread_id as u64);
+        }
+    }
+    None
+}
+
+fn is_coroutine_type(type_name: &str) -> bool {
+    type_name == "coroutine" || type_name == "generator"
+}
+
+/// Gets a Frame for a coroutine's frame object
+fn get_frame<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    frame_address: usize,
+    lineno: LineNo,
+    qualified_names: bool,
+    arg_names: &mut HashMap<usize, String>,
+) -> Result<Frame, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let frame =
+        <I::ThreadState as ThreadState>::FrameObject::copy_from(process, frame_address, offsets)
+            .context("Failed to copy coroutine frame")?;
+    let code =
+        copy_pointer(process, frame.code(), offsets).context("Failed to copy PyCodeObject")?;
+    let filename =
+        copy_string(code.filename(), process, offsets).context("Failed to copy filename")?;
+    let name =
+        copy_string(code.name(), process, offsets).context("Failed to copy function name")?;
+
+    let (line, columns) = match lineno {
+        LineNo::NoLine => (0, None),
+        LineNo::First => (code.first_lineno(), None),
+        LineNo::LastInstruction => {
+            get_location(&code, frame.lasti(), process, offsets).unwrap_or((0, None))
+        }
+    };
+
+    let qualname = if qualified_names {
+        let frame_ptr = frame_address as *const <I::ThreadState as ThreadState>::FrameObject;
+        get_qualname::<I::ThreadState, P>(
+            &code, frame_ptr, &frame, process, offsets, &name, arg_names,
+        )
+    } else {
+        None
+    };
+
+    Ok(Frame {
+        name,
+        qualname,
+        filename,
+        module: None,
+        short_filename: None,
+        line,
+        columns,
+        instruction: None,
+        locals: None,
+        is_entry: true,
+        is_shim_entry: false,
+    })
+}
+
+/// Returns the address of the frame of a coroutine (or generator) object, or None if
+/// the coroutine has already finished
+fn coroutine_frame_address<P: ProcessMemory>(
+    process: &P,
+    version: &Version,
+    addr: usize,
+) -> Result<Option<usize>, Error> {
+    let ptr_size = std::mem::size_of::<usize>();
+    let header_size = object_header_size(version);
+    match version {
+        Version {
+            major: 3,
+            minor: 11..=13,
+            ..
+        } => {
+            // python 3.11+ embeds the frame at the end of the generator object, after 6 pointer
+            // sized members (7 in python 3.11, which also has gi_code) and 4 chars. The last
+            // of those chars is gi_frame_state.
+            let members = if version.minor == 11 { 7 } else { 6 };
+            let frame_state_offset = header_size + members * ptr_size + 3;
+            let frame_offset = (frame_state_offset + ptr_size) & !(ptr_size - 1);
+
+            const FRAME_COMPLETED: i8 = 1;
+            let frame_state: i8 = process.copy_struct(addr + frame_state_offset)?;
+            if frame_state >= FRAME_COMPLETED {
+                return Ok(None);
+            }
+            Ok(Some(addr + frame_offset))
+        }
+        _ => {
+            // earlier versions store a pointer to the frame directly after the PyObject header
+            let frame: usize = process.copy_struct(addr + header_size)?;
+            Ok(if frame == 0 { None } else { Some(frame) })
+        }
+    }
+}
+
+/// Returns the object on top of the value stack for a suspended frame
+fn frame_stack_top<P: ProcessMemory>(
+    process: &P,
+    version: &Version,
+    frame_address: usize,
+) -> Result<Option<usize>, Error> {
+    let ptr_size = std::mem::size_of::<usize>();
+    let top = match version {
+        Version {
+            major: 3,
+            minor: 13,
+            ..
+        } => {
+            let frame: v3_13_0::_PyInterpreterFrame = process.copy_struct(frame_address)?;
+            if frame.stacktop <= 0 {
+                return Ok(None);
+            }
+            frame_address
+                + std::mem::offset_of!(v3_13_0::_PyInterpreterFrame, localsplus)
+                + (frame.stacktop as usize - 1) * ptr_size
+        }
+        Version {
+            major: 3,
+            minor: 12,
+            ..
+        } => {
+            let frame: v3_12_0::_PyInterpreterFrame = process.copy_struct(frame_address)?;
+            if frame.stacktop <= 0 {
+                return Ok(None);
+            }
+            frame_address
+                + std::mem::offset_of!(v3_12_0::_PyInterpreterFrame, localsplus)
+                + (frame.stacktop as usize - 1) * ptr_size
+        }
+        Version {
+            major: 3,
+            minor: 11,
+            ..
+        } => {
+            let frame: v3_11_0::_PyInterpreterFrame = process.copy_struct(frame_address)?;
+            if frame.stacktop <= 0 {
+                return Ok(None);
+            }
+            frame_address
+                + std::mem::offset_of!(v3_11_0::_PyInterpreterFrame, localsplus)
+                + (frame.stacktop as usize - 1) * ptr_size
+        }
+        Version {
+            major: 3,
+            minor: 10,
+            ..
+        } => {
+            let frame: v3_10_0::PyFrameObject = process.copy_struct(frame_address)?;
+            if frame.f_stackdepth <= 0 {
+                return Ok(None);
+            }
+            frame.f_valuestack as usize + (frame.f_stackdepth as usize - 1) * ptr_size
+        }
+        _ => {
+            // the PyFrameObject layout up to f_stacktop is the same for python 3.5-3.9.
+            // f_stacktop is only set when the frame is suspended
+            let frame: v3_7_0::PyFrameObject = process.copy_struct(frame_address)?;
+            if frame.f_stacktop.is_null() || frame.f_stacktop <= frame.f_valuestack {
+                return Ok(None);
+            }
+            frame.f_stacktop as usize - ptr_size
+        }
+    };
+
+    let value: usize = process.copy_struct(top)?;
+    Ok(if value == 0 { None } else { Some(value) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::stack_trace::tests::trace;
+
+    fn frame(name: &str) -> Frame {
+        crate::stack_trace::tests::frame(name, "test.py", 1)
+    }
+
+    #[test]
+    fn test_attribute_to_task() {
+        let mut trace = StackTrace {
+            owns_gil: true,
+            ..trace(vec![
+                frame("inner"),
+                frame("main"),
+                frame("_run"),
+                frame("run_forever"),
+                frame("<module>"),
+            ])
+        };
+
+        let task = AsyncTask {
+            name: String::from("Task-1"),
+            running: true,
+            thread_id: Some(1),
+            awaiting: None,
+            frames: vec![frame("main")],
+            address: 0,
+            fut_waiter: 0,
+        };
+
+        // tasks running on other threads shouldn't change the trace
+        let other = AsyncTask {
+            thread_id: Some(2),
+            ..task.clone()
+        };
+        attribute_to_task(&mut trace, &[other]);
+        assert_eq!(trace.frames.len(), 5);
+        assert!(trace.task_name.is_none());
+
+        attribute_to_task(&mut trace, &[task]);
+        assert_eq!(trace.task_name.as_deref(), Some("Task-1"));
+        let names: Vec<&str> = trace.frames.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["inner", "main"]);
+    }
+
+    #[test]
+    fn test_check_version() {
+        let version = |minor| Version {
+            major: 3,
+            minor,
+            patch: 0,
+            release_flags: String::new(),
+            build_metadata: None,
+            free_threaded: false,
+        };
+        assert!(check_version(&version(6)).is_err());
+        assert!(check_version(&version(7)).is_ok());
+        assert!(check_version(&version(13)).is_ok());
+        assert!(check_version(&version(14)).is_err());
+    }
+}
@@ -3,6 +3,7 @@ pub mod v3_10_0;
 pub mod v3_11_0;
 pub mod v3_12_0;
 pub mod v3_13_0;
+pub mod v3_13_0t;
 pub mod v3_3_7;
 pub mod v3_5_5;
 pub mod v3_6_6;
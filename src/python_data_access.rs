@@ -1,9 +1,11 @@
 #![allow(clippy::unnecessary_cast)]
 use anyhow::Error;
 
-use crate::python_bindings::v3_13_0;
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
+use crate::python_bindings::{v3_13_0, v3_13_0t};
 use crate::python_interpreters::{
-    BytesObject, InterpreterState, ListObject, Object, StringObject, TupleObject, TypeObject,
+    copy_pointer, BytesObject, InterpreterState, ListObject, Object, RemoteStruct, StringObject,
+    TupleObject, TypeObject,
 };
 use crate::utils::offset_of;
 use crate::version::Version;
@@ -13,8 +15,9 @@ use remoteprocess::ProcessMemory;
 pub fn copy_string<T: StringObject, P: ProcessMemory>(
     ptr: *const T,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
 ) -> Result<String, Error> {
-    let obj = process.copy_pointer(ptr)?;
+    let obj = copy_pointer(process, ptr, offsets)?;
     if obj.size() == 0 {
         return Ok(String::new());
     }
@@ -54,8 +57,9 @@ pub fn copy_string<T: StringObject, P: ProcessMemory>(
 pub fn copy_bytes<T: BytesObject, P: ProcessMemory>(
     ptr: *const T,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
 ) -> Result<Vec<u8>, Error> {
-    let obj = process.copy_pointer(ptr)?;
+    let obj = copy_pointer(process, ptr, offsets)?;
     let size = obj.size();
     if size >= 65536 {
         return Err(format_err!("Refusing to copy {} bytes", size));
@@ -63,6 +67,16 @@ pub fn copy_bytes<T: BytesObject, P: ProcessMemory>(
     Ok(process.copy(obj.address(ptr as usize), size as usize)?)
 }
 
+/// Returns the size of the PyObject header. Free-threaded builds store extra per-object
+/// state (the owning thread, a mutex and split reference counts) before the ob_type pointer
+pub fn object_header_size(version: &Version) -> usize {
+    if version.free_threaded {
+        std::mem::size_of::<v3_13_0t::PyObject>()
+    } else {
+        std::mem::size_of::<v3_13_0::PyObject>()
+    }
+}
+
 /// Copies a i64 from a PyLongObject. Returns the value + if it overflowed
 pub fn copy_long<P: ProcessMemory>(
     process: &P,
@@ -72,23 +86,20 @@ pub fn copy_long<P: ProcessMemory>(
     let (size, negative, digit, value_size) = match version {
         Version {
             major: 3,
-            minor: 12..=13,
+            minor: 12..,
             ..
         } => {
             // PyLongObject format changed in python 3.12
-            let value = process
-                .copy_pointer(addr as *const crate::python_bindings::v3_12_0::PyLongObject)?;
-            let size = value.long_value.lv_tag >> 3;
-            let negative: i64 = if (value.long_value.lv_tag & 3) == 2 {
-                -1
-            } else {
-                1
-            };
+            let header_size = object_header_size(version);
+            let long_value: crate::python_bindings::v3_12_0::_PyLongValue =
+                process.copy_struct(addr + header_size)?;
+            let size = long_value.lv_tag >> 3;
+            let negative: i64 = if (long_value.lv_tag & 3) == 2 { -1 } else { 1 };
             (
                 size,
                 negative,
-                value.long_value.ob_digit[0] as u32,
-                std::mem::size_of_val(&value),
+                long_value.ob_digit[0] as u32,
+                header_size + std::mem::size_of_val(&long_value),
             )
         }
         _ => {
@@ -159,13 +170,13 @@ impl<'a, P: ProcessMemory> DictIterator<'a, P> {
         tp_addr: usize,
         flags: usize,
     ) -> Result<DictIterator<'a, P>, Error> {
-        // Handles logic of _PyObject_ManagedDictPointer in python 3.11
+        // Handles logic of _PyObject_ManagedDictPointer in python 3.11. MANAGED_DICT_OFFSET is
+        // -3 pointers, except in free-threaded builds where it is -1
+        let dict_offset = if version.free_threaded { 1 } else { 3 };
         let mut values_addr: usize =
-            process.copy_struct(addr - 4 * std::mem::size_of::<usize>())?;
-
-        // TODO: MANAGED_DICT_OFFSET is -3 if GIL isn't disabled, -1 otherwise (in py3.13+)
-        // handle gil-less python branches
-        let mut dict_addr: usize = process.copy_struct(addr - 3 * std::mem::size_of::<usize>())?;
+            process.copy_struct(addr - (dict_offset + 1) * std::mem::size_of::<usize>())?;
+        let mut dict_addr: usize =
+            process.copy_struct(addr - dict_offset * std::mem::size_of::<usize>())?;
 
         // for python 3.12, the values/dict are combined into a single tagged pointer
         if version.major == 3 && version.minor == 12 {
@@ -178,7 +189,10 @@ impl<'a, P: ProcessMemory> DictIterator<'a, P> {
         }
 
         if values_addr != 0 {
-            let ht_cached_keys = if version.major == 3 && version.minor >= 12 {
+            let ht_cached_keys = if version.free_threaded {
+                let ht: v3_13_0t::PyHeapTypeObject = process.copy_struct(tp_addr)?;
+                ht.ht_cached_keys as usize
+            } else if version.major == 3 && version.minor >= 12 {
                 let ht: crate::python_bindings::v3_12_0::PyHeapTypeObject =
                     process.copy_struct(tp_addr)?;
                 ht.ht_cached_keys as usize
@@ -195,7 +209,7 @@ impl<'a, P: ProcessMemory> DictIterator<'a, P> {
                 let dict_values: v3_13_0::_dictvalues = Default::default();
                 let values_offset = offset_of(&dict_values, &dict_values.values);
 
-                values_addr = addr + std::mem::size_of::<v3_13_0::PyObject>() + values_offset;
+                values_addr = addr + object_header_size(version) + values_offset;
             }
 
             let keys: crate::python_bindings::v3_12_0::PyDictKeysObject =
@@ -227,23 +241,29 @@ impl<'a, P: ProcessMemory> DictIterator<'a, P> {
         match version {
             Version {
                 major: 3,
-                minor: 11..=13,
+                minor: 11..,
                 ..
             } => {
-                let dict: crate::python_bindings::v3_11_0::PyDictObject =
-                    process.copy_struct(addr)?;
-                let keys = process.copy_pointer(dict.ma_keys)?;
+                let (ma_keys, ma_values) = if version.free_threaded {
+                    let dict: v3_13_0t::PyDictObject = process.copy_struct(addr)?;
+                    (dict.ma_keys as usize, dict.ma_values as usize)
+                } else {
+                    let dict: crate::python_bindings::v3_11_0::PyDictObject =
+                        process.copy_struct(addr)?;
+                    (dict.ma_keys as usize, dict.ma_values as usize)
+                };
+                let keys: crate::python_bindings::v3_11_0::PyDictKeysObject =
+                    process.copy_struct(ma_keys)?;
 
-                let entries_addr = dict.ma_keys as usize
-                    + (1 << keys.dk_log2_index_bytes)
-                    + std::mem::size_of_val(&keys);
+                let entries_addr =
+                    ma_keys + (1 << keys.dk_log2_index_bytes) + std::mem::size_of_val(&keys);
                 Ok(DictIterator {
                     process,
                     entries_addr,
                     index: 0,
                     kind: keys.dk_kind,
                     entries: keys.dk_nentries as usize,
-                    values: dict.ma_values as usize,
+                    values: ma_values,
                 })
             }
             _ => {
@@ -342,6 +362,97 @@ impl<'a, P: ProcessMemory> Iterator for DictIterator<'a, P> {
     }
 }
 
+/// Returns an iterator over the __dict__ of a python object. Handles both objects with a
+/// tp_dictoffset and objects using managed dictionaries (python 3.11+)
+pub fn instance_dict_iterator<'a, I, P>(
+    process: &'a P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &'a Version,
+    addr: usize,
+) -> Result<DictIterator<'a, P>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let object = I::Object::copy_from(process, addr, offsets)?;
+    let object_type = copy_pointer(process, object.ob_type(), offsets)?;
+    let flags = object_type.flags();
+
+    if flags & PY_TPFLAGS_MANAGED_DICT != 0 {
+        DictIterator::from_managed_dict(process, version, addr, object.ob_type() as usize, flags)
+    } else {
+        let dict_offset = object_type.dictoffset();
+        if dict_offset == 0 {
+            return Err(format_err!(
+                "Object at 0x{:x} doesn't have a __dict__",
+                addr
+            ));
+        }
+        let dict_addr: usize = process.copy_struct((addr as isize + dict_offset) as usize)?;
+        DictIterator::from(process, version, dict_addr)
+    }
+}
+
+/// Copies the name of a python type object (truncating to 128 bytes if longer)
+pub fn copy_type_name<T: TypeObject, P: ProcessMemory>(
+    type_object: &T,
+    process: &P,
+) -> Result<String, Error> {
+    let max_type_len = 128;
+    let name = process.copy(type_object.name() as usize, max_type_len)?;
+    let length = name.iter().position(|&x| x == 0).unwrap_or(max_type_len);
+    Ok(std::str::from_utf8(&name[..length])?.to_owned())
+}
+
+/// Returns the type name of the python object at addr
+pub fn object_type_name<I: InterpreterState, P: ProcessMemory>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    addr: usize,
+) -> Result<String, Error> {
+    let object = I::Object::copy_from(process, addr, offsets)?;
+    let object_type = copy_pointer(process, object.ob_type(), offsets)?;
+    copy_type_name(&object_type, process)
+}
+
+/// Layout of PySetObject after the PyObject header, which hasn't changed in any of the
+/// python versions we support
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+struct PySetObject {
+    fill: isize,
+    used: isize,
+    mask: isize,
+    table: usize,
+}
+
+/// Returns the addresses of all the keys stored in a python set object. Note that deleted
+/// entries are marked with a dummy key, which callers need to filter out by type
+pub fn copy_set<P: ProcessMemory>(
+    process: &P,
+    version: &Version,
+    addr: usize,
+) -> Result<Vec<usize>, Error> {
+    let set: PySetObject = process.copy_struct(addr + object_header_size(version))?;
+    let size = set.mask as usize + 1;
+    if set.mask < 0 || size > 1 << 20 {
+        return Err(format_err!("Refusing to copy {} set entries", size));
+    }
+
+    // each setentry is a (PyObject *key, Py_hash_t hash) pair
+    let ptr_size = std::mem::size_of::<usize>();
+    let table = process.copy(set.table, size * 2 * ptr_size)?;
+    Ok(table
+        .chunks_exact(2 * ptr_size)
+        .map(|entry| {
+            let mut key = [0_u8; std::mem::size_of::<usize>()];
+            key.copy_from_slice(&entry[..ptr_size]);
+            usize::from_ne_bytes(key)
+        })
+        .filter(|&key| key != 0)
+        .collect())
+}
+
 pub const PY_TPFLAGS_INLINE_VALUES: usize = 1 << 2;
 pub const PY_TPFLAGS_MANAGED_DICT: usize = 1 << 4;
 const PY_TPFLAGS_INT_SUBCLASS: usize = 1 << 23;
@@ -355,6 +466,7 @@ const PY_TPFLAGS_DICT_SUBCLASS: usize = 1 << 29;
 /// Converts a python variable in the other process to a human readable string
 pub fn format_variable<I, P>(
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     version: &Version,
     addr: usize,
     max_length: isize,
@@ -369,17 +481,10 @@ where
         return Ok("...".to_owned());
     }
 
-    let value: I::Object = process.copy_struct(addr)?;
-    let value_type = process.copy_pointer(value.ob_type())?;
-
-    // get the typename (truncating to 128 bytes if longer)
-    let max_type_len = 128;
-    let value_type_name = process.copy(value_type.name() as usize, max_type_len)?;
-    let length = value_type_name
-        .iter()
-        .position(|&x| x == 0)
-        .unwrap_or(max_type_len);
-    let value_type_name = std::str::from_utf8(&value_type_name[..length])?;
+    let value = I::Object::copy_from(process, addr, offsets)?;
+    let value_type = copy_pointer(process, value.ob_type(), offsets)?;
+    let value_type_name = copy_type_name(&value_type, process)?;
+    let value_type_name = value_type_name.as_str();
 
     let format_int = |value: i64| {
         if value_type_name == "bool" {
@@ -408,7 +513,7 @@ where
     } else if flags & PY_TPFLAGS_STRING_SUBCLASS != 0
         || (version.major == 2 && (flags & PY_TPFLAGS_BYTES_SUBCLASS != 0))
     {
-        let value = copy_string(addr as *const I::StringObject, process)?
+        let value = copy_string(addr as *const I::StringObject, process, offsets)?
             .replace('\'', "\\\"")
             .replace('\n', "\\n");
         if let Some((offset, _)) = value.char_indices().nth((max_length - 5) as usize) {
@@ -422,8 +527,8 @@ where
             let mut remaining = max_length - 2;
             for entry in DictIterator::from(process, version, addr)? {
                 let (key, value) = entry?;
-                let key = format_variable::<I, P>(process, version, key, remaining)?;
-                let value = format_variable::<I, P>(process, version, value, remaining)?;
+                let key = format_variable::<I, P>(process, offsets, version, key, remaining)?;
+                let value = format_variable::<I, P>(process, offsets, version, value, remaining)?;
                 remaining -= (key.len() + value.len()) as isize + 4;
                 if remaining <= 5 {
                     values.push("...".to_owned());
@@ -437,14 +542,15 @@ where
             "dict".to_owned()
         }
     } else if flags & PY_TPFLAGS_LIST_SUBCLASS != 0 {
-        let object: I::ListObject = process.copy_struct(addr)?;
+        let object = I::ListObject::copy_from(process, addr, offsets)?;
         let addr = object.item() as usize;
         let mut values = Vec::new();
         let mut remaining = max_length - 2;
         for i in 0..object.size() {
             let valueptr: *mut I::Object =
                 process.copy_struct(addr + i * std::mem::size_of::<*mut I::Object>())?;
-            let value = format_variable::<I, P>(process, version, valueptr as usize, remaining)?;
+            let value =
+                format_variable::<I, P>(process, offsets, version, valueptr as usize, remaining)?;
             remaining -= value.len() as isize + 2;
             if remaining <= 5 {
                 values.push("...".to_owned());
@@ -454,12 +560,13 @@ where
         }
         format!("[{}]", values.join(", "))
     } else if flags & PY_TPFLAGS_TUPLE_SUBCLASS != 0 {
-        let object: I::TupleObject = process.copy_struct(addr)?;
+        let object = I::TupleObject::copy_from(process, addr, offsets)?;
         let mut values = Vec::new();
         let mut remaining = max_length - 2;
         for i in 0..object.size() {
             let value_addr: *mut I::Object = process.copy_struct(object.address(addr, i))?;
-            let value = format_variable::<I, P>(process, version, value_addr as usize, remaining)?;
+            let value =
+                format_variable::<I, P>(process, offsets, version, value_addr as usize, remaining)?;
             remaining -= value.len() as isize + 2;
             if remaining <= 5 {
                 values.push("...".to_owned());
@@ -469,24 +576,24 @@ where
         }
         format!("({})", values.join(", "))
     } else if value_type_name == "float" {
-        let value =
-            process.copy_pointer(addr as *const crate::python_bindings::v3_7_0::PyFloatObject)?;
-        format!("{}", value.ob_fval)
+        // PyFloatObject stores the value directly after the PyObject header
+        let value: f64 = process.copy_struct(addr + object_header_size(version))?;
+        format!("{value}")
     } else if value_type_name == "NoneType" {
         "None".to_owned()
     } else if value_type_name.starts_with("numpy.") {
         match value_type_name {
-            "numpy.bool" => format_obval::<bool, P>(addr, process)?,
-            "numpy.uint8" => format_obval::<u8, P>(addr, process)?,
-            "numpy.uint16" => format_obval::<u16, P>(addr, process)?,
-            "numpy.uint32" => format_obval::<u32, P>(addr, process)?,
-            "numpy.uint64" => format_obval::<u64, P>(addr, process)?,
-            "numpy.int8" => format_obval::<i8, P>(addr, process)?,
-            "numpy.int16" => format_obval::<i16, P>(addr, process)?,
-            "numpy.int32" => format_obval::<i32, P>(addr, process)?,
-            "numpy.int64" => format_obval::<i64, P>(addr, process)?,
-            "numpy.float32" => format_obval::<f32, P>(addr, process)?,
-            "numpy.float64" => format_obval::<f64, P>(addr, process)?,
+            "numpy.bool" => format_obval::<bool, P>(addr, version, process)?,
+            "numpy.uint8" => format_obval::<u8, P>(addr, version, process)?,
+            "numpy.uint16" => format_obval::<u16, P>(addr, version, process)?,
+            "numpy.uint32" => format_obval::<u32, P>(addr, version, process)?,
+            "numpy.uint64" => format_obval::<u64, P>(addr, version, process)?,
+            "numpy.int8" => format_obval::<i8, P>(addr, version, process)?,
+            "numpy.int16" => format_obval::<i16, P>(addr, version, process)?,
+            "numpy.int32" => format_obval::<i32, P>(addr, version, process)?,
+            "numpy.int64" => format_obval::<i64, P>(addr, version, process)?,
+            "numpy.float32" => format_obval::<f32, P>(addr, version, process)?,
+            "numpy.float64" => format_obval::<f64, P>(addr, version, process)?,
             _ => format!("<{value_type_name} at 0x{addr:x}>"),
         }
     } else {
@@ -509,15 +616,14 @@ where
 /// need to build bindings for the numpy C API.
 ///
 /// * `addr`: Address of the numpy scalar
+/// * `version`: Version of python, which determines the size of the PyObject header
 /// * `process`: Process memory in which the object resides
-fn format_obval<T, P>(addr: usize, process: &P) -> Result<String, Error>
+fn format_obval<T, P>(addr: usize, version: &Version, process: &P) -> Result<String, Error>
 where
     T: std::fmt::Display + Copy,
     P: ProcessMemory,
 {
-    let base_addr = addr as *mut u32;
-    let offset = std::mem::size_of::<crate::python_bindings::v3_7_0::PyObject>() as isize;
-    let result = unsafe { process.copy_pointer(base_addr.byte_offset(offset) as *const T)? };
+    let result: T = process.copy_struct(addr + object_header_size(version))?;
     Ok(format!("{result}"))
 }
 
@@ -598,7 +704,7 @@ pub mod tests {
         let obj = to_asciiobject(original);
 
         let unicode: &PyUnicodeObject = unsafe { std::mem::transmute(&obj.base) };
-        let copied = copy_string(unicode, &LocalProcess).unwrap();
+        let copied = copy_string(unicode, &LocalProcess, None).unwrap();
         assert_eq!(copied, original);
     }
 
@@ -606,7 +712,59 @@ pub mod tests {
     fn test_copy_bytes() {
         let original = [10_u8, 20, 30, 40, 50, 70, 80];
         let bytes = to_byteobject(&original);
-        let copied = copy_bytes(&bytes.base, &LocalProcess).unwrap();
+        let copied = copy_bytes(&bytes.base, &LocalProcess, None).unwrap();
         assert_eq!(copied, original);
     }
+
+    #[test]
+    fn test_free_threaded_objects() {
+        let version = Version {
+            major: 3,
+            minor: 13,
+            patch: 0,
+            release_flags: "".to_owned(),
+            build_metadata: None,
+            free_threaded: true,
+        };
+        assert_eq!(object_header_size(&version), 32);
+
+        // strings are read through the free-threaded bindings
+        #[repr(C)]
+        struct AllocatedString {
+            base: v3_13_0t::PyASCIIObject,
+            storage: [u8; 16],
+        }
+        let mut string = AllocatedString {
+            base: v3_13_0t::PyASCIIObject {
+                length: 5,
+                ..Default::default()
+            },
+            storage: [0; 16],
+        };
+        string.base.state.set_compact(1);
+        string.base.state.set_kind(1);
+        string.base.state.set_ascii(1);
+        string.storage[..5].copy_from_slice(b"hello");
+        let unicode = &string.base as *const _ as *const v3_13_0t::PyUnicodeObject;
+        assert_eq!(copy_string(unicode, &LocalProcess, None).unwrap(), "hello");
+
+        // and ints by skipping over the larger object header
+        #[repr(C)]
+        struct LongObject {
+            ob_base: v3_13_0t::PyObject,
+            long_value: crate::python_bindings::v3_12_0::_PyLongValue,
+        }
+        let long = LongObject {
+            ob_base: Default::default(),
+            long_value: crate::python_bindings::v3_12_0::_PyLongValue {
+                lv_tag: (1 << 3) | 2,
+                ob_digit: [42],
+            },
+        };
+        let addr = &long as *const LongObject as usize;
+        assert_eq!(
+            copy_long(&LocalProcess, &version, addr).unwrap(),
+            (-42, false)
+        );
+    }
 }
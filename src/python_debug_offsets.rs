@@ -0,0 +1,1138 @@
+/* Python 3.13+ stores a _Py_DebugOffsets table at the start of _PyRuntime, with the offsets of
+the fields that debuggers and profilers need in each of the interpreter structs. This module
+provides implementations of the python_interpreters traits that read those offsets at runtime
+instead of using bindgen generated structs, which lets us profile versions of python that we
+don't have bindings for yet.
+
+The layout of the _Py_DebugOffsets table itself changes between minor versions, so the table
+is read using the layout for the version stored in it and converted to the python 3.13 layout
+that the rest of this module uses. Versions newer than any layout we know about are read with
+the newest one, and refused if any of the offsets fall outside of the size of their struct,
+since every offset after a newly inserted field would be wrong.
+
+The traits copy whole structs out of the target process, so the structs here are fixed size
+byte buffers that are large enough to hold every field we read. Each PythonSpy keeps the offsets
+for its process and passes them in when copying the structs, which keep the offsets of their
+own fields next to the data.
+*/
+
+use std::os::raw::{c_char, c_ulong};
+
+use anyhow::Error;
+use remoteprocess::ProcessMemory;
+
+use crate::python_bindings::v3_13_0::{
+    self, _Py_DebugOffsets, _Py_DebugOffsets__bytes_object, _Py_DebugOffsets__code_object,
+    _Py_DebugOffsets__dict_object, _Py_DebugOffsets__float_object,
+    _Py_DebugOffsets__interpreter_frame, _Py_DebugOffsets__interpreter_state,
+    _Py_DebugOffsets__list_object, _Py_DebugOffsets__long_object, _Py_DebugOffsets__pyobject,
+    _Py_DebugOffsets__runtime_state, _Py_DebugOffsets__thread_state,
+    _Py_DebugOffsets__tuple_object, _Py_DebugOffsets__type_object,
+    _Py_DebugOffsets__unicode_object,
+};
+use crate::python_interpreters::{
+    get_compact_line_number, get_compact_location, BytesObject, CodeObject, FrameObject,
+    GilStateOffsets, InterpreterState, ListObject, Object, RemoteStruct, StringObject, ThreadState,
+    TupleObject, TypeObject,
+};
+
+const PTR_SIZE: usize = std::mem::size_of::<usize>();
+
+/// The start of the _Py_DebugOffsets, which has the same layout in every version
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct DebugOffsetsHeader {
+    cookie: [c_char; 8],
+    version: u64,
+}
+
+/// Copies the _Py_DebugOffsets from the start of the _PyRuntime in the target process,
+/// converting it to the python 3.13 layout
+pub fn read_debug_offsets<P: ProcessMemory>(
+    process: &P,
+    runtime_address: usize,
+) -> Result<_Py_DebugOffsets, Error> {
+    let header: DebugOffsetsHeader = process.copy_struct(runtime_address)?;
+    if !header.cookie.iter().map(|&c| c as u8).eq(*b"xdebugpy") {
+        return Err(format_err!(
+            "Invalid _Py_DebugOffsets cookie at 0x{:016x}",
+            runtime_address
+        ));
+    }
+
+    // the version is the PY_VERSION_HEX of the python that wrote the table
+    let (major, minor) = (header.version >> 24, (header.version >> 16) & 0xff);
+    match (major, minor) {
+        (3, 13) => Ok(process.copy_struct(runtime_address)?),
+        (3, 14) => {
+            let offsets: DebugOffsets314 = process.copy_struct(runtime_address)?;
+            Ok(offsets.into())
+        }
+        // newer versions will most likely keep the python 3.14 layout, and if they've inserted
+        // any fields then the size of each struct is unlikely to still be where we expect
+        (3, 15..) => {
+            warn!(
+                "Unknown _Py_DebugOffsets layout for python {}.{}, using the python 3.14 layout",
+                major, minor
+            );
+            let offsets: DebugOffsets314 = process.copy_struct(runtime_address)?;
+            let offsets = offsets.into();
+            check_struct_sizes(&offsets)?;
+            Ok(offsets)
+        }
+        _ => Err(format_err!(
+            "Unsupported _Py_DebugOffsets layout for python {}.{} (version 0x{:08x})",
+            major,
+            minor,
+            header.version
+        )),
+    }
+}
+
+// The python 3.14 _Py_DebugOffsets layout, from Include/internal/pycore_debug_offsets.h. Only
+// the start of the table up to the structs that we read is included here, and the structs
+// that didn't change from python 3.13 use the bindgen generated versions
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct DebugOffsets314 {
+    cookie: [c_char; 8],
+    version: u64,
+    free_threaded: u64,
+    runtime_state: _Py_DebugOffsets__runtime_state,
+    interpreter_state: InterpreterStateOffsets314,
+    thread_state: _Py_DebugOffsets__thread_state,
+    interpreter_frame: InterpreterFrameOffsets314,
+    code_object: CodeObjectOffsets314,
+    pyobject: _Py_DebugOffsets__pyobject,
+    type_object: _Py_DebugOffsets__type_object,
+    tuple_object: _Py_DebugOffsets__tuple_object,
+    list_object: _Py_DebugOffsets__list_object,
+    set_object: SetObjectOffsets314,
+    dict_object: _Py_DebugOffsets__dict_object,
+    float_object: _Py_DebugOffsets__float_object,
+    long_object: _Py_DebugOffsets__long_object,
+    bytes_object: _Py_DebugOffsets__bytes_object,
+    unicode_object: _Py_DebugOffsets__unicode_object,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct InterpreterStateOffsets314 {
+    size: u64,
+    id: u64,
+    next: u64,
+    threads_head: u64,
+    threads_main: u64,
+    gc: u64,
+    imports_modules: u64,
+    sysdict: u64,
+    builtins: u64,
+    ceval_gil: u64,
+    gil_runtime_state: u64,
+    gil_runtime_state_enabled: u64,
+    gil_runtime_state_locked: u64,
+    gil_runtime_state_holder: u64,
+    code_object_generation: u64,
+    tlbc_generation: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct InterpreterFrameOffsets314 {
+    size: u64,
+    previous: u64,
+    executable: u64,
+    instr_ptr: u64,
+    localsplus: u64,
+    owner: u64,
+    stackpointer: u64,
+    tlbc_index: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct CodeObjectOffsets314 {
+    size: u64,
+    filename: u64,
+    name: u64,
+    qualname: u64,
+    linetable: u64,
+    firstlineno: u64,
+    argcount: u64,
+    localsplusnames: u64,
+    localspluskinds: u64,
+    co_code_adaptive: u64,
+    co_tlbc: u64,
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Default)]
+struct SetObjectOffsets314 {
+    size: u64,
+    used: u64,
+    table: u64,
+    mask: u64,
+}
+
+impl From<DebugOffsets314> for _Py_DebugOffsets {
+    fn from(offsets: DebugOffsets314) -> _Py_DebugOffsets {
+        let state = &offsets.interpreter_state;
+        let frame = &offsets.interpreter_frame;
+        let code = &offsets.code_object;
+        let mut converted = _Py_DebugOffsets {
+            cookie: offsets.cookie,
+            version: offsets.version,
+            free_threaded: offsets.free_threaded,
+            runtime_state: offsets.runtime_state,
+            thread_state: offsets.thread_state,
+            pyobject: offsets.pyobject,
+            type_object: offsets.type_object,
+            tuple_object: offsets.tuple_object,
+            list_object: offsets.list_object,
+            dict_object: offsets.dict_object,
+            float_object: offsets.float_object,
+            long_object: offsets.long_object,
+            bytes_object: offsets.bytes_object,
+            unicode_object: offsets.unicode_object,
+            ..Default::default()
+        };
+
+        let converted_state = &mut converted.interpreter_state;
+        converted_state.size = state.size;
+        converted_state.id = state.id;
+        converted_state.next = state.next;
+        converted_state.threads_head = state.threads_head;
+        converted_state.gc = state.gc;
+        converted_state.imports_modules = state.imports_modules;
+        converted_state.sysdict = state.sysdict;
+        converted_state.builtins = state.builtins;
+        converted_state.ceval_gil = state.ceval_gil;
+        converted_state.gil_runtime_state = state.gil_runtime_state;
+        converted_state.gil_runtime_state_enabled = state.gil_runtime_state_enabled;
+        converted_state.gil_runtime_state_locked = state.gil_runtime_state_locked;
+        converted_state.gil_runtime_state_holder = state.gil_runtime_state_holder;
+
+        let converted_frame = &mut converted.interpreter_frame;
+        converted_frame.size = frame.size;
+        converted_frame.previous = frame.previous;
+        converted_frame.executable = frame.executable;
+        converted_frame.instr_ptr = frame.instr_ptr;
+        converted_frame.localsplus = frame.localsplus;
+        converted_frame.owner = frame.owner;
+
+        let converted_code = &mut converted.code_object;
+        converted_code.size = code.size;
+        converted_code.filename = code.filename;
+        converted_code.name = code.name;
+        converted_code.qualname = code.qualname;
+        converted_code.linetable = code.linetable;
+        converted_code.firstlineno = code.firstlineno;
+        converted_code.argcount = code.argcount;
+        converted_code.localsplusnames = code.localsplusnames;
+        converted_code.localspluskinds = code.localspluskinds;
+        converted_code.co_code_adaptive = code.co_code_adaptive;
+        converted
+    }
+}
+
+/// Checks that the offsets of the fields that we read are inside of the size given for each
+/// struct in the _Py_DebugOffsets, which catches tables that were read with the wrong layout
+fn check_struct_sizes(offsets: &_Py_DebugOffsets) -> Result<(), Error> {
+    let runtime = &offsets.runtime_state;
+    let state = &offsets.interpreter_state;
+    let ts = &offsets.thread_state;
+    let frame = &offsets.interpreter_frame;
+    let code = &offsets.code_object;
+    let tp = &offsets.type_object;
+    let tuple = &offsets.tuple_object;
+    let list = &offsets.list_object;
+    let bytes = &offsets.bytes_object;
+    let unicode = &offsets.unicode_object;
+    let structs = [
+        (
+            "_PyRuntimeState",
+            runtime.size,
+            vec![runtime.interpreters_head],
+        ),
+        (
+            "PyInterpreterState",
+            state.size,
+            vec![
+                state.id,
+                state.next,
+                state.threads_head,
+                state.imports_modules,
+                state.ceval_gil,
+            ],
+        ),
+        (
+            "PyThreadState",
+            ts.size,
+            vec![
+                ts.next,
+                ts.interp,
+                ts.current_frame,
+                ts.thread_id,
+                ts.native_thread_id,
+            ],
+        ),
+        (
+            "_PyInterpreterFrame",
+            frame.size,
+            vec![
+                frame.previous,
+                frame.executable,
+                frame.instr_ptr,
+                frame.localsplus,
+                frame.owner,
+            ],
+        ),
+        (
+            "PyCodeObject",
+            code.size,
+            vec![
+                code.filename,
+                code.name,
+                code.qualname,
+                code.linetable,
+                code.firstlineno,
+                code.argcount,
+                code.localsplusnames,
+                code.localspluskinds,
+                code.co_code_adaptive,
+            ],
+        ),
+        (
+            "PyObject",
+            offsets.pyobject.size,
+            vec![offsets.pyobject.ob_type],
+        ),
+        ("PyTypeObject", tp.size, vec![tp.tp_name, tp.tp_flags]),
+        (
+            "PyTupleObject",
+            tuple.size,
+            vec![tuple.ob_size, tuple.ob_item],
+        ),
+        ("PyListObject", list.size, vec![list.ob_size, list.ob_item]),
+        (
+            "PyBytesObject",
+            bytes.size,
+            vec![bytes.ob_size, bytes.ob_sval],
+        ),
+        (
+            "PyUnicodeObject",
+            unicode.size,
+            vec![unicode.state, unicode.length, unicode.asciiobject_size],
+        ),
+    ];
+    for (name, size, fields) in structs {
+        if let Some(offset) = fields.into_iter().find(|&offset| offset >= size) {
+            return Err(format_err!(
+                "_Py_DebugOffsets has a {} field at offset {}, past the end of the {} byte struct",
+                name,
+                offset,
+                size
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every field we read using the offsets fits inside of the Debug* structs
+pub fn check_debug_offsets(offsets: &_Py_DebugOffsets) -> Result<(), Error> {
+    let ulong_size = std::mem::size_of::<c_ulong>();
+    let tp_dictoffset = offsets.type_object.tp_name + TP_DICTOFFSET_FROM_TP_NAME as u64;
+    let unicode_data = offsets.unicode_object.asciiobject_size + 2 * PTR_SIZE as u64;
+    let ts = &offsets.thread_state;
+    let frame = &offsets.interpreter_frame;
+    let code = &offsets.code_object;
+    let tp = &offsets.type_object;
+    let unicode = &offsets.unicode_object;
+    let structs = [
+        (
+            "PyThreadState",
+            DEBUG_THREAD_STATE_SIZE,
+            vec![
+                (ts.current_frame, PTR_SIZE),
+                (ts.thread_id, ulong_size),
+                (ts.native_thread_id, ulong_size),
+                (ts.next, PTR_SIZE),
+                (ts.interp, PTR_SIZE),
+            ],
+        ),
+        (
+            "_PyInterpreterFrame",
+            DEBUG_FRAME_SIZE,
+            vec![
+                (frame.previous, PTR_SIZE),
+                (frame.executable, PTR_SIZE),
+                (frame.instr_ptr, PTR_SIZE),
+                (frame.owner, 1),
+            ],
+        ),
+        (
+            "PyCodeObject",
+            DEBUG_CODE_OBJECT_SIZE,
+            vec![
+                (code.filename, PTR_SIZE),
+                (code.name, PTR_SIZE),
+                (code.qualname, PTR_SIZE),
+                (code.linetable, PTR_SIZE),
+                (code.firstlineno, 4),
+                (code.argcount, 4),
+                (code.localsplusnames, PTR_SIZE),
+                (code.localspluskinds, PTR_SIZE),
+            ],
+        ),
+        (
+            "PyObject",
+            DEBUG_OBJECT_SIZE,
+            vec![(offsets.pyobject.ob_type, PTR_SIZE)],
+        ),
+        (
+            "PyTypeObject",
+            DEBUG_TYPE_OBJECT_SIZE,
+            vec![
+                (tp.tp_name, PTR_SIZE),
+                (tp_dictoffset, PTR_SIZE),
+                (tp.tp_flags, ulong_size),
+            ],
+        ),
+        (
+            "PyTupleObject",
+            DEBUG_TUPLE_OBJECT_SIZE,
+            vec![(offsets.tuple_object.ob_size, PTR_SIZE)],
+        ),
+        (
+            "PyListObject",
+            DEBUG_LIST_OBJECT_SIZE,
+            vec![
+                (offsets.list_object.ob_size, PTR_SIZE),
+                (offsets.list_object.ob_item, PTR_SIZE),
+            ],
+        ),
+        (
+            "PyBytesObject",
+            DEBUG_BYTES_OBJECT_SIZE,
+            vec![(offsets.bytes_object.ob_size, PTR_SIZE)],
+        ),
+        (
+            "PyUnicodeObject",
+            DEBUG_STRING_OBJECT_SIZE,
+            vec![
+                (unicode.state, 4),
+                (unicode.length, PTR_SIZE),
+                (unicode_data, PTR_SIZE),
+            ],
+        ),
+    ];
+    for (name, struct_size, fields) in structs {
+        for (offset, size) in fields {
+            if offset as usize + size > struct_size {
+                return Err(format_err!(
+                    "_Py_DebugOffsets has a {} field at offset {}, which is larger than we support",
+                    name,
+                    offset
+                ));
+            }
+        }
+    }
+
+    let state = &offsets.interpreter_state;
+    if state.gil_runtime_state_locked < state.gil_runtime_state
+        || state.gil_runtime_state_holder < state.gil_runtime_state
+        || (offsets.free_threaded != 0 && state.gil_runtime_state_enabled < state.gil_runtime_state)
+    {
+        return Err(format_err!(
+            "_Py_DebugOffsets has invalid offsets for the _gil_runtime_state"
+        ));
+    }
+    Ok(())
+}
+
+fn read_bytes<const N: usize>(data: &[u8], offset: usize) -> [u8; N] {
+    data.get(offset..offset + N)
+        .and_then(|bytes| bytes.try_into().ok())
+        .unwrap_or([0; N])
+}
+
+fn read_usize(data: &[u8], offset: usize) -> usize {
+    usize::from_ne_bytes(read_bytes(data, offset))
+}
+
+#[allow(clippy::unnecessary_cast)]
+fn read_ulong(data: &[u8], offset: usize) -> u64 {
+    c_ulong::from_ne_bytes(read_bytes(data, offset)) as u64
+}
+
+fn read_u32(data: &[u8], offset: usize) -> u32 {
+    u32::from_ne_bytes(read_bytes(data, offset))
+}
+
+fn read_i32(data: &[u8], offset: usize) -> i32 {
+    i32::from_ne_bytes(read_bytes(data, offset))
+}
+
+// The sizes of each of the structs below, which are larger than the python 3.13 structs to
+// leave room for new fields in later versions (but not so large that they are likely to read
+// past the end of the allocation in the target process)
+const DEBUG_THREAD_STATE_SIZE: usize = 512;
+const DEBUG_FRAME_SIZE: usize = 128;
+const DEBUG_CODE_OBJECT_SIZE: usize = 320;
+const DEBUG_OBJECT_SIZE: usize = 32;
+const DEBUG_TYPE_OBJECT_SIZE: usize = 512;
+const DEBUG_STRING_OBJECT_SIZE: usize = 80;
+const DEBUG_BYTES_OBJECT_SIZE: usize = 48;
+const DEBUG_TUPLE_OBJECT_SIZE: usize = 48;
+const DEBUG_LIST_OBJECT_SIZE: usize = 64;
+
+// tp_dictoffset isn't included in the debug offsets, but the PyTypeObject layout is part of the
+// public C-API and the slots between tp_name and tp_dictoffset have the same layout in every
+// python 3 release
+const TP_DICTOFFSET_FROM_TP_NAME: usize =
+    std::mem::offset_of!(v3_13_0::PyTypeObject, tp_dictoffset)
+        - std::mem::offset_of!(v3_13_0::PyTypeObject, tp_name);
+
+// Any extra fields given after the offsets are copied from the top level of the _Py_DebugOffsets
+macro_rules! DebugStruct {
+    ($name: ident, $size: ident, $field: ident, $offsets: ident $(, $extra: ident)*) => {
+        #[derive(Copy, Clone)]
+        pub struct $name {
+            data: [u8; $size],
+            offsets: $offsets,
+            $($extra: u64,)*
+        }
+
+        impl RemoteStruct for $name {
+            fn copy_from<P: ProcessMemory>(
+                process: &P,
+                addr: usize,
+                offsets: Option<&_Py_DebugOffsets>,
+            ) -> Result<Self, Error> {
+                let offsets = offsets.ok_or_else(|| {
+                    format_err!("Missing _Py_DebugOffsets to copy {}", stringify!($name))
+                })?;
+                Ok($name {
+                    data: process.copy_struct(addr)?,
+                    offsets: offsets.$field,
+                    $($extra: offsets.$extra,)*
+                })
+            }
+        }
+    };
+}
+
+/// The PyInterpreterState is never copied out of the target process, we only use
+/// the field offsets to get the addresses of the fields inside of it
+#[derive(Copy, Clone, Default)]
+pub struct DebugInterpreterState;
+
+DebugStruct!(
+    DebugThreadState,
+    DEBUG_THREAD_STATE_SIZE,
+    thread_state,
+    _Py_DebugOffsets__thread_state
+);
+DebugStruct!(
+    DebugFrame,
+    DEBUG_FRAME_SIZE,
+    interpreter_frame,
+    _Py_DebugOffsets__interpreter_frame,
+    version
+);
+DebugStruct!(
+    DebugCodeObject,
+    DEBUG_CODE_OBJECT_SIZE,
+    code_object,
+    _Py_DebugOffsets__code_object
+);
+DebugStruct!(
+    DebugObject,
+    DEBUG_OBJECT_SIZE,
+    pyobject,
+    _Py_DebugOffsets__pyobject
+);
+DebugStruct!(
+    DebugTypeObject,
+    DEBUG_TYPE_OBJECT_SIZE,
+    type_object,
+    _Py_DebugOffsets__type_object
+);
+DebugStruct!(
+    DebugStringObject,
+    DEBUG_STRING_OBJECT_SIZE,
+    unicode_object,
+    _Py_DebugOffsets__unicode_object
+);
+DebugStruct!(
+    DebugBytesObject,
+    DEBUG_BYTES_OBJECT_SIZE,
+    bytes_object,
+    _Py_DebugOffsets__bytes_object
+);
+DebugStruct!(
+    DebugTupleObject,
+    DEBUG_TUPLE_OBJECT_SIZE,
+    tuple_object,
+    _Py_DebugOffsets__tuple_object
+);
+DebugStruct!(
+    DebugListObject,
+    DEBUG_LIST_OBJECT_SIZE,
+    list_object,
+    _Py_DebugOffsets__list_object
+);
+
+/// Returns the PyInterpreterState field offsets. The offsets are always passed in for the
+/// versions of python that use the DebugInterpreterState
+fn interpreter_offsets(offsets: Option<&_Py_DebugOffsets>) -> &_Py_DebugOffsets__interpreter_state {
+    &offsets
+        .expect("DebugInterpreterState requires the _Py_DebugOffsets")
+        .interpreter_state
+}
+
+impl InterpreterState for DebugInterpreterState {
+    type ThreadState = DebugThreadState;
+    type Object = DebugObject;
+    type StringObject = DebugStringObject;
+    type ListObject = DebugListObject;
+    type TupleObject = DebugTupleObject;
+    const HAS_GIL_RUNTIME_STATE: bool = true;
+
+    fn threadstate_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::ThreadState {
+        (interpreter_address + interpreter_offsets(offsets).threads_head as usize)
+            as *const *const Self::ThreadState
+    }
+    fn modules_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::Object {
+        (interpreter_address + interpreter_offsets(offsets).imports_modules as usize)
+            as *const *const Self::Object
+    }
+    fn next_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self {
+        (interpreter_address + interpreter_offsets(offsets).next as usize) as *const *const Self
+    }
+    fn id_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const i64> {
+        Some((interpreter_address + interpreter_offsets(offsets).id as usize) as *const i64)
+    }
+    fn gil_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const usize> {
+        Some(
+            (interpreter_address + interpreter_offsets(offsets).ceval_gil as usize) as *const usize,
+        )
+    }
+    fn gil_state_offsets(offsets: Option<&_Py_DebugOffsets>) -> Option<GilStateOffsets> {
+        // the gil_runtime_state offsets are relative to the PyInterpreterState that the
+        // _gil_runtime_state is embedded in
+        let state = interpreter_offsets(offsets);
+        let base = state.gil_runtime_state as usize;
+        Some(GilStateOffsets {
+            enabled: if offsets.is_some_and(|o| o.free_threaded != 0) {
+                Some(state.gil_runtime_state_enabled as usize - base)
+            } else {
+                None
+            },
+            locked: state.gil_runtime_state_locked as usize - base,
+            last_holder: state.gil_runtime_state_holder as usize - base,
+        })
+    }
+}
+
+impl ThreadState for DebugThreadState {
+    type FrameObject = DebugFrame;
+    type InterpreterState = DebugInterpreterState;
+
+    fn interp(&self) -> *mut Self::InterpreterState {
+        read_usize(&self.data, self.offsets.interp as usize) as *mut Self::InterpreterState
+    }
+    fn frame_address(&self) -> Option<usize> {
+        None
+    }
+    fn frame(&self, _addr: Option<usize>) -> *mut Self::FrameObject {
+        read_usize(&self.data, self.offsets.current_frame as usize) as *mut Self::FrameObject
+    }
+    fn thread_id(&self) -> u64 {
+        read_ulong(&self.data, self.offsets.thread_id as usize)
+    }
+    fn native_thread_id(&self) -> Option<u64> {
+        Some(read_ulong(
+            &self.data,
+            self.offsets.native_thread_id as usize,
+        ))
+    }
+    fn next(&self) -> *mut Self {
+        read_usize(&self.data, self.offsets.next as usize) as *mut Self
+    }
+}
+
+impl FrameObject for DebugFrame {
+    type CodeObject = DebugCodeObject;
+
+    fn code(&self) -> *mut Self::CodeObject {
+        // f_executable is a _PyStackRef in python 3.14+, which uses the low bits as tags
+        const TAG_BITS: usize = 3;
+        (read_usize(&self.data, self.offsets.executable as usize) & !TAG_BITS)
+            as *mut Self::CodeObject
+    }
+    fn lasti(&self) -> i32 {
+        let instr_ptr = read_usize(&self.data, self.offsets.instr_ptr as usize);
+        instr_ptr.wrapping_sub(self.code() as usize) as i32
+    }
+    fn back(&self) -> *mut Self {
+        read_usize(&self.data, self.offsets.previous as usize) as *mut Self
+    }
+    fn is_entry(&self) -> bool {
+        // https://github.com/python/cpython/pull/108036#issuecomment-1684458828
+        // Python 3.14 added FRAME_OWNED_BY_INTERPRETER = 3 for the frame pushed when entering
+        // the interpreter, and moved FRAME_OWNED_BY_CSTACK to 4
+        const FRAME_OWNED_BY_CSTACK_313: u8 = 3;
+        const FRAME_OWNED_BY_INTERPRETER_314: u8 = 3;
+        const FRAME_OWNED_BY_CSTACK_314: u8 = 4;
+        let owner = read_bytes::<1>(&self.data, self.offsets.owner as usize)[0];
+        if self.version >> 16 >= 0x030e {
+            owner == FRAME_OWNED_BY_INTERPRETER_314 || owner == FRAME_OWNED_BY_CSTACK_314
+        } else {
+            owner == FRAME_OWNED_BY_CSTACK_313
+        }
+    }
+    fn locals_offset(&self) -> usize {
+        self.offsets.localsplus as usize
+    }
+}
+
+impl CodeObject for DebugCodeObject {
+    type StringObject = DebugStringObject;
+    type BytesObject = DebugBytesObject;
+    type TupleObject = DebugTupleObject;
+
+    fn name(&self) -> *mut Self::StringObject {
+        read_usize(&self.data, self.offsets.name as usize) as *mut Self::StringObject
+    }
+    fn qualname(&self) -> Option<*mut Self::StringObject> {
+        Some(read_usize(&self.data, self.offsets.qualname as usize) as *mut Self::StringObject)
+    }
+    fn filename(&self) -> *mut Self::StringObject {
+        read_usize(&self.data, self.offsets.filename as usize) as *mut Self::StringObject
+    }
+    fn line_table(&self) -> *mut Self::BytesObject {
+        read_usize(&self.data, self.offsets.linetable as usize) as *mut Self::BytesObject
+    }
+    fn first_lineno(&self) -> i32 {
+        read_i32(&self.data, self.offsets.firstlineno as usize)
+    }
+    fn nlocals(&self) -> i32 {
+        // co_nlocals isn't in the debug offsets, get_locals counts the locals from
+        // co_localspluskinds instead
+        0
+    }
+    fn argcount(&self) -> i32 {
+        read_i32(&self.data, self.offsets.argcount as usize)
+    }
+    fn varnames(&self) -> *mut Self::TupleObject {
+        read_usize(&self.data, self.offsets.localsplusnames as usize) as *mut Self::TupleObject
+    }
+    fn localspluskinds(&self) -> Option<*mut Self::BytesObject> {
+        Some(
+            read_usize(&self.data, self.offsets.localspluskinds as usize) as *mut Self::BytesObject,
+        )
+    }
+
+    fn get_line_number(&self, lasti: i32, table: &[u8]) -> i32 {
+        let lasti = lasti - self.offsets.co_code_adaptive as i32;
+        get_compact_line_number(self.first_lineno(), lasti, table)
+    }
+    fn get_location(&self, lasti: i32, table: &[u8]) -> (i32, Option<(u32, u32)>) {
+        let lasti = lasti - self.offsets.co_code_adaptive as i32;
+        get_compact_location(self.first_lineno(), lasti, table)
+    }
+    fn instruction_offset(&self, lasti: i32) -> i32 {
+        lasti - self.offsets.co_code_adaptive as i32
+    }
+}
+
+impl BytesObject for DebugBytesObject {
+    fn size(&self) -> usize {
+        read_usize(&self.data, self.offsets.ob_size as usize)
+    }
+    fn address(&self, base: usize) -> usize {
+        base + self.offsets.ob_sval as usize
+    }
+}
+
+impl StringObject for DebugStringObject {
+    // the state bitfield is 'interned:2, kind:3, compact:1, ascii:1, ...'
+    fn ascii(&self) -> bool {
+        (read_u32(&self.data, self.offsets.state as usize) >> 6) & 1 != 0
+    }
+    fn kind(&self) -> u32 {
+        (read_u32(&self.data, self.offsets.state as usize) >> 2) & 7
+    }
+    fn size(&self) -> usize {
+        read_usize(&self.data, self.offsets.length as usize)
+    }
+    fn address(&self, base: usize) -> usize {
+        // PyCompactUnicodeObject adds the utf8_length and utf8 members to the PyASCIIObject,
+        // and PyUnicodeObject adds a pointer to the data after that
+        let ascii_size = self.offsets.asciiobject_size as usize;
+        let compact_size = ascii_size + 2 * PTR_SIZE;
+        let compact = (read_u32(&self.data, self.offsets.state as usize) >> 5) & 1 != 0;
+        if !compact {
+            read_usize(&self.data, compact_size)
+        } else if self.ascii() {
+            base + ascii_size
+        } else {
+            base + compact_size
+        }
+    }
+}
+
+impl TupleObject for DebugTupleObject {
+    fn size(&self) -> usize {
+        read_usize(&self.data, self.offsets.ob_size as usize)
+    }
+    fn address(&self, base: usize, index: usize) -> usize {
+        base + self.offsets.ob_item as usize + index * PTR_SIZE
+    }
+}
+
+impl ListObject for DebugListObject {
+    type Object = DebugObject;
+    fn size(&self) -> usize {
+        read_usize(&self.data, self.offsets.ob_size as usize)
+    }
+    fn item(&self) -> *mut *mut Self::Object {
+        read_usize(&self.data, self.offsets.ob_item as usize) as *mut *mut Self::Object
+    }
+}
+
+impl Object for DebugObject {
+    type TypeObject = DebugTypeObject;
+    fn ob_type(&self) -> *mut Self::TypeObject {
+        read_usize(&self.data, self.offsets.ob_type as usize) as *mut Self::TypeObject
+    }
+}
+
+impl TypeObject for DebugTypeObject {
+    fn name(&self) -> *const ::std::os::raw::c_char {
+        read_usize(&self.data, self.offsets.tp_name as usize) as *const ::std::os::raw::c_char
+    }
+    fn dictoffset(&self) -> isize {
+        read_usize(
+            &self.data,
+            self.offsets.tp_name as usize + TP_DICTOFFSET_FROM_TP_NAME,
+        ) as isize
+    }
+    fn flags(&self) -> usize {
+        read_ulong(&self.data, self.offsets.tp_flags as usize) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::LineNo;
+    use crate::python_bindings::v3_7_0;
+    use crate::python_data_access::tests::{to_asciiobject, to_byteobject};
+    use crate::stack_trace::get_stack_trace;
+    use remoteprocess::LocalProcess;
+    use std::mem::{offset_of, size_of};
+
+    // the frames/threads/code objects use the python 3.13 layout, and the strings and bytes use
+    // the python 3.7 layout from the python_data_access test helpers. This works since we only
+    // care about the offsets here, and not which version of python they came from
+    fn debug_offsets() -> _Py_DebugOffsets {
+        let mut offsets = _Py_DebugOffsets::default();
+        offsets.thread_state.current_frame = offset_of!(v3_13_0::_ts, current_frame) as u64;
+        offsets.thread_state.thread_id = offset_of!(v3_13_0::_ts, thread_id) as u64;
+        offsets.thread_state.native_thread_id = offset_of!(v3_13_0::_ts, native_thread_id) as u64;
+        offsets.thread_state.next = offset_of!(v3_13_0::_ts, next) as u64;
+        offsets.thread_state.interp = offset_of!(v3_13_0::_ts, interp) as u64;
+
+        let frame = &mut offsets.interpreter_frame;
+        frame.previous = offset_of!(v3_13_0::_PyInterpreterFrame, previous) as u64;
+        frame.executable = offset_of!(v3_13_0::_PyInterpreterFrame, f_executable) as u64;
+        frame.instr_ptr = offset_of!(v3_13_0::_PyInterpreterFrame, instr_ptr) as u64;
+        frame.localsplus = offset_of!(v3_13_0::_PyInterpreterFrame, localsplus) as u64;
+        frame.owner = offset_of!(v3_13_0::_PyInterpreterFrame, owner) as u64;
+
+        let code = &mut offsets.code_object;
+        code.filename = offset_of!(v3_13_0::PyCodeObject, co_filename) as u64;
+        code.name = offset_of!(v3_13_0::PyCodeObject, co_name) as u64;
+        code.qualname = offset_of!(v3_13_0::PyCodeObject, co_qualname) as u64;
+        code.linetable = offset_of!(v3_13_0::PyCodeObject, co_linetable) as u64;
+        code.firstlineno = offset_of!(v3_13_0::PyCodeObject, co_firstlineno) as u64;
+        code.co_code_adaptive = offset_of!(v3_13_0::PyCodeObject, co_code_adaptive) as u64;
+
+        let unicode = &mut offsets.unicode_object;
+        unicode.state = offset_of!(v3_7_0::PyASCIIObject, state) as u64;
+        unicode.length = offset_of!(v3_7_0::PyASCIIObject, length) as u64;
+        unicode.asciiobject_size = size_of::<v3_7_0::PyASCIIObject>() as u64;
+
+        offsets.bytes_object.ob_size = offset_of!(v3_7_0::PyBytesObject, ob_base.ob_size) as u64;
+        offsets.bytes_object.ob_sval = offset_of!(v3_7_0::PyBytesObject, ob_sval) as u64;
+        offsets
+    }
+
+    #[test]
+    fn test_debug_offsets_stack_trace() {
+        let offsets = debug_offsets();
+        check_debug_offsets(&offsets).unwrap();
+        check_stack_trace(&offsets);
+    }
+
+    /// Gets a stack trace from python 3.13 structs, using the offsets passed in
+    fn check_stack_trace(offsets: &_Py_DebugOffsets) {
+        let mut filename = to_asciiobject("app.py");
+        let mut name = to_asciiobject("work");
+        let mut qualname = to_asciiobject("Worker.work");
+        // two instructions, with the second one on the line after co_firstlineno
+        let mut linetable = to_byteobject(&[0xd0, 0, 0, 0xd8, 0, 0]);
+        let code = v3_13_0::PyCodeObject {
+            co_filename: &mut filename as *mut _ as *mut _,
+            co_name: &mut name as *mut _ as *mut _,
+            co_qualname: &mut qualname as *mut _ as *mut _,
+            co_linetable: &mut linetable as *mut _ as *mut _,
+            co_firstlineno: 10,
+            ..Default::default()
+        };
+        let code_address = &code as *const _ as usize;
+
+        let mut entry = v3_13_0::_PyInterpreterFrame {
+            f_executable: code_address as *mut _,
+            instr_ptr: (code_address + offset_of!(v3_13_0::PyCodeObject, co_code_adaptive))
+                as *mut _,
+            owner: 3,
+            ..Default::default()
+        };
+        let mut leaf = v3_13_0::_PyInterpreterFrame {
+            f_executable: code_address as *mut _,
+            previous: &mut entry,
+            instr_ptr: (code_address + offset_of!(v3_13_0::PyCodeObject, co_code_adaptive) + 4)
+                as *mut _,
+            ..Default::default()
+        };
+        let thread = v3_13_0::_ts {
+            current_frame: &mut leaf,
+            thread_id: 0x1234,
+            native_thread_id: 42,
+            ..Default::default()
+        };
+
+        let thread =
+            DebugThreadState::copy_from(&LocalProcess, &thread as *const _ as usize, Some(offsets))
+                .unwrap();
+        assert_eq!(thread.thread_id(), 0x1234);
+        assert_eq!(thread.native_thread_id(), Some(42));
+        assert!(thread.next().is_null());
+
+        let trace = get_stack_trace(
+            &thread,
+            &LocalProcess,
+            Some(offsets),
+            false,
+            LineNo::LastInstruction,
+            true,
+            false,
+            &mut std::collections::HashMap::new(),
+        )
+        .unwrap();
+        let frames: Vec<(&str, &str, i32, bool)> = trace
+            .frames
+            .iter()
+            .map(|f| (f.name.as_str(), f.filename.as_str(), f.line, f.is_entry))
+            .collect();
+        assert_eq!(
+            frames,
+            vec![("work", "app.py", 11, false), ("work", "app.py", 10, true)]
+        );
+        assert_eq!(trace.frames[0].display_name(), "Worker.work");
+    }
+
+    fn cookie() -> [c_char; 8] {
+        b"xdebugpy".map(|c| c as c_char)
+    }
+
+    // python 3.14 inserted fields into several of the structs in _Py_DebugOffsets, give
+    // them values so that reading this with the 3.13 layout would get the wrong offsets
+    fn debug_offsets_314() -> DebugOffsets314 {
+        let expected = debug_offsets();
+        let frame = &expected.interpreter_frame;
+        let code = &expected.code_object;
+        DebugOffsets314 {
+            cookie: cookie(),
+            version: 0x030e00f0,
+            free_threaded: 0,
+            runtime_state: _Py_DebugOffsets__runtime_state {
+                size: 2048,
+                finalizing: 40,
+                interpreters_head: 48,
+            },
+            interpreter_state: InterpreterStateOffsets314 {
+                size: 4096,
+                id: 8,
+                next: 16,
+                threads_head: 24,
+                threads_main: 32,
+                gc: 40,
+                imports_modules: 48,
+                sysdict: 56,
+                builtins: 64,
+                ceval_gil: 72,
+                gil_runtime_state: 80,
+                gil_runtime_state_enabled: 88,
+                gil_runtime_state_locked: 96,
+                gil_runtime_state_holder: 104,
+                code_object_generation: 112,
+                tlbc_generation: 120,
+            },
+            thread_state: expected.thread_state,
+            interpreter_frame: InterpreterFrameOffsets314 {
+                size: 80,
+                previous: frame.previous,
+                executable: frame.executable,
+                instr_ptr: frame.instr_ptr,
+                localsplus: frame.localsplus,
+                owner: frame.owner,
+                stackpointer: 56,
+                tlbc_index: 64,
+            },
+            code_object: CodeObjectOffsets314 {
+                size: 208,
+                filename: code.filename,
+                name: code.name,
+                qualname: code.qualname,
+                linetable: code.linetable,
+                firstlineno: code.firstlineno,
+                argcount: code.argcount,
+                localsplusnames: code.localsplusnames,
+                localspluskinds: code.localspluskinds,
+                co_code_adaptive: code.co_code_adaptive,
+                co_tlbc: 200,
+            },
+            set_object: SetObjectOffsets314 {
+                size: 200,
+                used: 24,
+                table: 40,
+                mask: 32,
+            },
+            bytes_object: expected.bytes_object,
+            unicode_object: expected.unicode_object,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_read_debug_offsets_314() {
+        let expected = debug_offsets();
+        let table = debug_offsets_314();
+        let offsets = read_debug_offsets(&LocalProcess, &table as *const _ as usize).unwrap();
+        assert_eq!(offsets.version, 0x030e00f0);
+        assert_eq!(offsets.runtime_state.interpreters_head, 48);
+        let state = &offsets.interpreter_state;
+        assert_eq!((state.threads_head, state.gc), (24, 40));
+        assert_eq!(state.imports_modules, 48);
+        assert_eq!(
+            (state.gil_runtime_state, state.gil_runtime_state_holder),
+            (80, 104)
+        );
+        assert_eq!(
+            offsets.code_object.co_code_adaptive,
+            expected.code_object.co_code_adaptive
+        );
+        assert_eq!(offsets.bytes_object.ob_sval, expected.bytes_object.ob_sval);
+
+        check_debug_offsets(&offsets).unwrap();
+        check_stack_trace(&offsets);
+    }
+
+    #[test]
+    fn test_frame_is_entry() {
+        let mut offsets = debug_offsets();
+        let is_entry = |offsets: &_Py_DebugOffsets, owner| {
+            let frame = v3_13_0::_PyInterpreterFrame {
+                owner,
+                ..Default::default()
+            };
+            DebugFrame::copy_from(&LocalProcess, &frame as *const _ as usize, Some(offsets))
+                .unwrap()
+                .is_entry()
+        };
+        offsets.version = 0x030d05f0;
+        assert!(is_entry(&offsets, 3));
+        assert!(!is_entry(&offsets, 4));
+
+        // python 3.14 renumbered the frame owners
+        offsets.version = 0x030e00f0;
+        assert!(is_entry(&offsets, 3));
+        assert!(is_entry(&offsets, 4));
+        assert!(!is_entry(&offsets, 0));
+    }
+
+    #[test]
+    fn test_read_debug_offsets_newer() {
+        // newer versions are read with the python 3.14 layout, as long as every field is
+        // inside of the size given for its struct
+        let mut table = debug_offsets_314();
+        table.version = 0x030f00a1;
+        assert!(read_debug_offsets(&LocalProcess, &table as *const _ as usize).is_err());
+
+        table.thread_state.size = size_of::<v3_13_0::_ts>() as u64;
+        table.interpreter_frame.size = size_of::<v3_13_0::_PyInterpreterFrame>() as u64;
+        table.code_object.size = size_of::<v3_13_0::PyCodeObject>() as u64;
+        table.pyobject.size = size_of::<v3_13_0::PyObject>() as u64;
+        table.type_object.size = size_of::<v3_13_0::PyTypeObject>() as u64;
+        table.tuple_object.size = size_of::<v3_7_0::PyTupleObject>() as u64;
+        table.list_object.size = size_of::<v3_7_0::PyListObject>() as u64;
+        table.bytes_object.size = size_of::<v3_7_0::PyBytesObject>() as u64;
+        table.unicode_object.size = size_of::<v3_7_0::PyUnicodeObject>() as u64;
+        let offsets = read_debug_offsets(&LocalProcess, &table as *const _ as usize).unwrap();
+        assert_eq!(offsets.version, 0x030f00a1);
+        assert_eq!(offsets.interpreter_state.imports_modules, 48);
+        check_debug_offsets(&offsets).unwrap();
+        check_stack_trace(&offsets);
+    }
+
+    #[test]
+    fn test_read_debug_offsets_version() {
+        let mut table = debug_offsets();
+        table.cookie = cookie();
+        table.version = 0x030d05f0;
+        let offsets = read_debug_offsets(&LocalProcess, &table as *const _ as usize).unwrap();
+        assert_eq!(
+            offsets.thread_state.current_frame,
+            table.thread_state.current_frame
+        );
+
+        // older layouts we don't know about are refused instead of guessing at them
+        table.version = 0x030c00f0;
+        assert!(read_debug_offsets(&LocalProcess, &table as *const _ as usize).is_err());
+
+        table.cookie = [0; 8];
+        table.version = 0x030d05f0;
+        assert!(read_debug_offsets(&LocalProcess, &table as *const _ as usize).is_err());
+    }
+
+    #[test]
+    fn test_invalid_debug_offsets() {
+        let mut offsets = debug_offsets();
+        offsets.thread_state.current_frame = DEBUG_THREAD_STATE_SIZE as u64;
+        assert!(check_debug_offsets(&offsets).is_err());
+
+        let mut offsets = debug_offsets();
+        offsets.interpreter_state.gil_runtime_state = 64;
+        offsets.interpreter_state.gil_runtime_state_locked = 32;
+        assert!(check_debug_offsets(&offsets).is_err());
+    }
+}
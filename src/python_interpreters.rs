@@ -12,10 +12,38 @@ This means we can't dereference them directly.
 // these bindings are automatically generated by rust bindgen
 // using the generate_bindings.py script
 use crate::python_bindings::{
-    v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0, v3_9_5,
+    v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_13_0t, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0,
+    v3_9_5,
 };
 use crate::utils::offset_of;
 
+use anyhow::Error;
+use remoteprocess::ProcessMemory;
+
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
+
+/// A struct that gets copied out of the target process. The structs for versions of python that
+/// we don't have bindings for also need the field offsets from the _Py_DebugOffsets, which are
+/// passed in here and are None for every other version
+pub trait RemoteStruct: Copy {
+    fn copy_from<P: ProcessMemory>(
+        process: &P,
+        addr: usize,
+        _offsets: Option<&_Py_DebugOffsets>,
+    ) -> Result<Self, Error> {
+        Ok(process.copy_struct(addr)?)
+    }
+}
+
+/// Copies the struct pointed to by ptr out of the target process
+pub fn copy_pointer<T: RemoteStruct, P: ProcessMemory>(
+    process: &P,
+    ptr: *const T,
+    offsets: Option<&_Py_DebugOffsets>,
+) -> Result<T, Error> {
+    T::copy_from(process, ptr as usize, offsets)
+}
+
 pub trait InterpreterState: Copy {
     type ThreadState: ThreadState;
     type Object: Object;
@@ -23,14 +51,56 @@ pub trait InterpreterState: Copy {
     type ListObject: ListObject;
     type TupleObject: TupleObject;
     const HAS_GIL_RUNTIME_STATE: bool = false;
+    /// Whether this is a free-threaded build, where the GIL is usually disabled
+    const FREE_THREADED: bool = false;
 
     /// Get a remote pointer to a pointer to PyThreadState.
-    fn threadstate_ptr_ptr(interpreter_address: usize) -> *const *const Self::ThreadState;
+    fn threadstate_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::ThreadState;
     /// Get a remote pointer to a pointer to PyObject being the modules dict.
-    fn modules_ptr_ptr(interpreter_address: usize) -> *const *const Self::Object;
+    fn modules_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::Object;
+    /// Get a remote pointer to a pointer to the next PyInterpreterState in the interpreters list.
+    fn next_ptr_ptr(
+        interpreter_address: usize,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self;
+    /// Get a remote pointer to the interpreter id. Only available in python 3.7+.
+    fn id_ptr(
+        _interpreter_address: usize,
+        _offsets: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const i64> {
+        None
+    }
+    /// Get a remote pointer to a pointer to the _gil_runtime_state used by this interpreter.
+    /// Only available in python 3.12+, where each subinterpreter can have its own GIL.
+    fn gil_ptr_ptr(
+        _interpreter_address: usize,
+        _offsets: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const usize> {
+        None
+    }
+    /// Get the offsets of the fields in the _gil_runtime_state, when they are only known at
+    /// runtime. Otherwise the bindgen _gil_runtime_state structs are used
+    fn gil_state_offsets(_offsets: Option<&_Py_DebugOffsets>) -> Option<GilStateOffsets> {
+        None
+    }
 }
 
-pub trait ThreadState: Copy {
+/// Offsets of the fields we read from a _gil_runtime_state
+#[derive(Debug, Clone, Copy)]
+pub struct GilStateOffsets {
+    /// The offset of the enabled field, which only exists in free-threaded builds
+    pub enabled: Option<usize>,
+    pub locked: usize,
+    pub last_holder: usize,
+}
+
+pub trait ThreadState: RemoteStruct {
     type FrameObject: FrameObject;
     type InterpreterState: InterpreterState;
 
@@ -44,62 +114,105 @@ pub trait ThreadState: Copy {
     fn thread_id(&self) -> u64;
     fn native_thread_id(&self) -> Option<u64>;
     fn next(&self) -> *mut Self;
+    /// Get the address of the thread's per-thread state dict
+    fn dict(&self) -> Option<usize> {
+        None
+    }
+    /// Get the address of the contextvars.Context that is current in the thread. Only
+    /// available in python 3.7+
+    fn context(&self) -> Option<usize> {
+        None
+    }
+    /// Get the address of the key used to find the thread's threading.local values. Only
+    /// available in python 3.13+, older versions store these in the per-thread state dict
+    fn threading_local_key(&self) -> Option<usize> {
+        None
+    }
 }
 
-pub trait FrameObject: Copy {
+pub trait FrameObject: RemoteStruct {
     type CodeObject: CodeObject;
 
     fn code(&self) -> *mut Self::CodeObject;
     fn lasti(&self) -> i32;
     fn back(&self) -> *mut Self;
     fn is_entry(&self) -> bool;
+
+    /// Get the offset of the local variables, which are stored at the end of the frame
+    fn locals_offset(&self) -> usize {
+        std::mem::size_of::<Self>() - std::mem::size_of::<*const i32>()
+    }
 }
 
-pub trait CodeObject: Copy {
+pub trait CodeObject: RemoteStruct {
     type StringObject: StringObject;
     type BytesObject: BytesObject;
     type TupleObject: TupleObject;
 
     fn name(&self) -> *mut Self::StringObject;
+    /// Get the qualified name of the function (like 'Class.method'), only available in 3.11+
+    fn qualname(&self) -> Option<*mut Self::StringObject> {
+        None
+    }
     fn filename(&self) -> *mut Self::StringObject;
     fn line_table(&self) -> *mut Self::BytesObject;
     fn first_lineno(&self) -> i32;
     fn nlocals(&self) -> i32;
     fn argcount(&self) -> i32;
     fn varnames(&self) -> *mut Self::TupleObject;
+    /// Get the kind of each variable in varnames. Only used when the number of locals
+    /// isn't known, in which case nlocals is computed from the CO_FAST_LOCAL kinds
+    fn localspluskinds(&self) -> Option<*mut Self::BytesObject> {
+        None
+    }
 
     fn get_line_number(&self, lasti: i32, table: &[u8]) -> i32;
+    /// Get the line number along with the start and end columns of the instruction. Only
+    /// python 3.11+ stores the columns, older versions just return the line number
+    fn get_location(&self, lasti: i32, table: &[u8]) -> (i32, Option<(u32, u32)>) {
+        (self.get_line_number(lasti, table), None)
+    }
+
+    /// Get the bytes object holding the bytecode. Python 3.11+ stores the bytecode inline at
+    /// the end of the code object instead, and returns None here
+    fn bytecode(&self) -> Option<*mut Self::BytesObject> {
+        None
+    }
+    /// Get the byte offset of the instruction at lasti from the start of the bytecode
+    fn instruction_offset(&self, lasti: i32) -> i32 {
+        lasti
+    }
 }
 
-pub trait BytesObject: Copy {
+pub trait BytesObject: RemoteStruct {
     fn size(&self) -> usize;
     fn address(&self, base: usize) -> usize;
 }
 
-pub trait StringObject: Copy {
+pub trait StringObject: RemoteStruct {
     fn ascii(&self) -> bool;
     fn kind(&self) -> u32;
     fn size(&self) -> usize;
     fn address(&self, base: usize) -> usize;
 }
 
-pub trait TupleObject: Copy {
+pub trait TupleObject: RemoteStruct {
     fn size(&self) -> usize;
     fn address(&self, base: usize, index: usize) -> usize;
 }
 
-pub trait ListObject: Copy {
+pub trait ListObject: RemoteStruct {
     type Object: Object;
     fn size(&self) -> usize;
     fn item(&self) -> *mut *mut Self::Object;
 }
 
-pub trait Object: Copy {
+pub trait Object: RemoteStruct {
     type TypeObject: TypeObject;
     fn ob_type(&self) -> *mut Self::TypeObject;
 }
 
-pub trait TypeObject: Copy {
+pub trait TypeObject: RemoteStruct {
     fn name(&self) -> *const ::std::os::raw::c_char;
     fn dictoffset(&self) -> isize;
     fn flags(&self) -> usize;
@@ -108,8 +221,10 @@ pub trait TypeObject: Copy {
 /// This macro provides a common impl for PyThreadState/PyFrameObject/PyCodeObject traits
 /// (this code is identical across python versions, we are only abstracting the struct layouts here).
 /// String handling changes substantially between python versions, and is handled separately.
+/// The optional last arguments are the interpreter id and thread context fields, which only
+/// exist in python 3.7+.
 macro_rules! PythonCommonImpl {
-    ($py: ident, $stringobject: ident) => {
+    ($py: ident, $stringobject: ident $(, $id: ident, $context: ident)?) => {
         impl InterpreterState for $py::PyInterpreterState {
             type ThreadState = $py::PyThreadState;
             type Object = $py::PyObject;
@@ -117,16 +232,29 @@ macro_rules! PythonCommonImpl {
             type ListObject = $py::PyListObject;
             type TupleObject = $py::PyTupleObject;
 
-            fn threadstate_ptr_ptr(interpreter_address: usize) -> *const *const Self::ThreadState {
+            fn threadstate_ptr_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> *const *const Self::ThreadState {
                 (interpreter_address + std::mem::offset_of!(Self, tstate_head))
                     as *const *const Self::ThreadState
             }
-            fn modules_ptr_ptr(interpreter_address: usize) -> *const *const Self::Object {
+            fn modules_ptr_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> *const *const Self::Object {
                 (interpreter_address + std::mem::offset_of!(Self, modules))
                     as *const *const Self::Object
             }
+            fn next_ptr_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> *const *const Self {
+                (interpreter_address + std::mem::offset_of!(Self, next)) as *const *const Self
+            }
+            $(
+            fn id_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> Option<*const i64> {
+                Some((interpreter_address + std::mem::offset_of!(Self, $id)) as *const i64)
+            }
+            )?
         }
 
+        impl RemoteStruct for $py::PyThreadState {}
+        impl RemoteStruct for $py::PyFrameObject {}
+        impl RemoteStruct for $py::PyObject {}
+        impl RemoteStruct for $py::PyTypeObject {}
+
         impl ThreadState for $py::PyThreadState {
             type FrameObject = $py::PyFrameObject;
             type InterpreterState = $py::PyInterpreterState;
@@ -148,6 +276,14 @@ macro_rules! PythonCommonImpl {
             fn interp(&self) -> *mut Self::InterpreterState {
                 self.interp
             }
+            fn dict(&self) -> Option<usize> {
+                Some(self.dict as usize)
+            }
+            $(
+            fn context(&self) -> Option<usize> {
+                Some(self.$context as usize)
+            }
+            )?
         }
 
         impl FrameObject for $py::PyFrameObject {
@@ -191,6 +327,8 @@ macro_rules! PythonCommonImpl {
 // We can use this up until python3.10 - where code object lnotab attribute is deprecated
 macro_rules! PythonCodeObjectImpl {
     ($py: ident, $bytesobject: ident, $stringobject: ident) => {
+        impl RemoteStruct for $py::PyCodeObject {}
+
         impl CodeObject for $py::PyCodeObject {
             type BytesObject = $py::$bytesobject;
             type StringObject = $py::$stringobject;
@@ -217,6 +355,9 @@ macro_rules! PythonCodeObjectImpl {
             fn varnames(&self) -> *mut Self::TupleObject {
                 self.co_varnames as *mut Self::TupleObject
             }
+            fn bytecode(&self) -> Option<*mut Self::BytesObject> {
+                Some(self.co_code as *mut Self::BytesObject)
+            }
 
             fn get_line_number(&self, lasti: i32, table: &[u8]) -> i32 {
                 let lasti = lasti as i32;
@@ -282,6 +423,8 @@ fn read_signed_varint(index: &mut usize, table: &[u8]) -> Option<isize> {
 // Use for 3.11 and 3.12
 macro_rules! CompactCodeObjectImpl {
     ($py: ident, $bytesobject: ident, $stringobject: ident) => {
+        impl RemoteStruct for $py::PyCodeObject {}
+
         impl CodeObject for $py::PyCodeObject {
             type BytesObject = $py::$bytesobject;
             type StringObject = $py::$stringobject;
@@ -290,6 +433,9 @@ macro_rules! CompactCodeObjectImpl {
             fn name(&self) -> *mut Self::StringObject {
                 self.co_name as *mut Self::StringObject
             }
+            fn qualname(&self) -> Option<*mut Self::StringObject> {
+                Some(self.co_qualname as *mut Self::StringObject)
+            }
             fn filename(&self) -> *mut Self::StringObject {
                 self.co_filename as *mut Self::StringObject
             }
@@ -310,56 +456,109 @@ macro_rules! CompactCodeObjectImpl {
             }
 
             fn get_line_number(&self, lasti: i32, table: &[u8]) -> i32 {
-                // unpack compressed table format from python 3.11
-                // https://github.com/python/cpython/pull/91666/files
                 let lasti = lasti - offset_of(self, &self.co_code_adaptive) as i32;
-                let mut line_number: i32 = self.first_lineno();
-                let mut bytecode_address: i32 = 0;
-
-                let mut index: usize = 0;
-                loop {
-                    if index >= table.len() {
-                        break;
-                    }
-                    let byte = table[index];
-                    index += 1;
-
-                    let delta = ((byte & 7) as i32) + 1;
-                    bytecode_address += delta * 2;
-                    let code = (byte >> 3) & 15;
-                    let line_delta = match code {
-                        15 => 0,
-                        14 => {
-                            let delta = read_signed_varint(&mut index, table).unwrap_or(0);
-                            read_varint(&mut index, table); // end line
-                            read_varint(&mut index, table); // start column
-                            read_varint(&mut index, table); // end column
-                            delta
-                        }
-                        13 => read_signed_varint(&mut index, table).unwrap_or(0),
-                        10..=12 => {
-                            index += 2; // start column / end column
-                            (code - 10).into()
-                        }
-                        _ => {
-                            index += 1; // column
-                            0
-                        }
-                    };
-                    line_number += line_delta as i32;
-                    if bytecode_address >= lasti {
-                        break;
-                    }
-                }
-                line_number
+                get_compact_line_number(self.first_lineno(), lasti, table)
+            }
+            fn get_location(&self, lasti: i32, table: &[u8]) -> (i32, Option<(u32, u32)>) {
+                let lasti = lasti - offset_of(self, &self.co_code_adaptive) as i32;
+                get_compact_location(self.first_lineno(), lasti, table)
+            }
+            fn instruction_offset(&self, lasti: i32) -> i32 {
+                lasti - offset_of(self, &self.co_code_adaptive) as i32
             }
         }
     };
 }
 
+/// Returns the line number for a bytecode offset, from the compressed line table
+/// format used in python 3.11+
+pub(crate) fn get_compact_line_number(first_lineno: i32, lasti: i32, table: &[u8]) -> i32 {
+    get_compact_location(first_lineno, lasti, table).0
+}
+
+/// Returns the line number and the start/end columns for a bytecode offset, from the
+/// compressed line table format used in python 3.11+
+pub(crate) fn get_compact_location(
+    first_lineno: i32,
+    lasti: i32,
+    table: &[u8],
+) -> (i32, Option<(u32, u32)>) {
+    // unpack compressed table format from python 3.11
+    // https://github.com/python/cpython/pull/91666/files
+    let mut line_number: i32 = first_lineno;
+    let mut columns = None;
+    let mut bytecode_address: i32 = 0;
+
+    let mut index: usize = 0;
+    loop {
+        if index >= table.len() {
+            break;
+        }
+        let byte = table[index];
+        index += 1;
+
+        let delta = ((byte & 7) as i32) + 1;
+        bytecode_address += delta * 2;
+        let code = (byte >> 3) & 15;
+        let line_delta = match code {
+            15 => {
+                columns = None;
+                0
+            }
+            14 => {
+                let delta = read_signed_varint(&mut index, table).unwrap_or(0);
+                read_varint(&mut index, table); // end line
+
+                // columns are stored plus one here, with 0 meaning that they are unknown
+                let start = read_varint(&mut index, table).unwrap_or(0);
+                let end = read_varint(&mut index, table).unwrap_or(0);
+                columns = if start > 0 && end > 0 {
+                    Some((start as u32 - 1, end as u32 - 1))
+                } else {
+                    None
+                };
+                delta
+            }
+            13 => {
+                columns = None;
+                read_signed_varint(&mut index, table).unwrap_or(0)
+            }
+            10..=12 => {
+                // start column / end column
+                columns = match table.get(index..index + 2) {
+                    Some(&[start, end]) => Some((start as u32, end as u32)),
+                    _ => None,
+                };
+                index += 2;
+                (code - 10).into()
+            }
+            _ => {
+                // the start column is split between the code and the next byte, which also
+                // has the length of the range
+                columns = table.get(index).map(|&next| {
+                    let start = ((code as u32) << 3) | ((next as u32 >> 4) & 7);
+                    (start, start + (next as u32 & 15))
+                });
+                index += 1;
+                0
+            }
+        };
+        line_number += line_delta as i32;
+        if bytecode_address >= lasti {
+            break;
+        }
+    }
+    (line_number, columns)
+}
+
 // String/Byte/List/Tuple handling for Python 3.3+
 macro_rules! Python3Impl {
     ($py: ident) => {
+        impl RemoteStruct for $py::PyBytesObject {}
+        impl RemoteStruct for $py::PyUnicodeObject {}
+        impl RemoteStruct for $py::PyListObject {}
+        impl RemoteStruct for $py::PyTupleObject {}
+
         impl BytesObject for $py::PyBytesObject {
             fn size(&self) -> usize {
                 self.ob_base.ob_size as usize
@@ -426,16 +625,42 @@ impl InterpreterState for v3_13_0::PyInterpreterState {
     type TupleObject = v3_13_0::PyTupleObject;
     const HAS_GIL_RUNTIME_STATE: bool = true;
 
-    fn threadstate_ptr_ptr(interpreter_address: usize) -> *const *const Self::ThreadState {
+    fn threadstate_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::ThreadState {
         (interpreter_address + std::mem::offset_of!(Self, threads.head))
             as *const *const Self::ThreadState
     }
-    fn modules_ptr_ptr(interpreter_address: usize) -> *const *const Self::Object {
+    fn modules_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::Object {
         (interpreter_address + std::mem::offset_of!(Self, imports.modules))
             as *const *const Self::Object
     }
+    fn next_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self {
+        (interpreter_address + std::mem::offset_of!(Self, next)) as *const *const Self
+    }
+    fn id_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> Option<*const i64> {
+        Some((interpreter_address + std::mem::offset_of!(Self, id)) as *const i64)
+    }
+    fn gil_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const usize> {
+        Some((interpreter_address + std::mem::offset_of!(Self, ceval.gil)) as *const usize)
+    }
 }
 
+impl RemoteStruct for v3_13_0::PyThreadState {}
+impl RemoteStruct for v3_13_0::_PyInterpreterFrame {}
+impl RemoteStruct for v3_13_0::PyObject {}
+impl RemoteStruct for v3_13_0::PyTypeObject {}
+
 impl ThreadState for v3_13_0::PyThreadState {
     type FrameObject = v3_13_0::_PyInterpreterFrame;
     type InterpreterState = v3_13_0::PyInterpreterState;
@@ -457,6 +682,15 @@ impl ThreadState for v3_13_0::PyThreadState {
     fn interp(&self) -> *mut Self::InterpreterState {
         self.interp
     }
+    fn dict(&self) -> Option<usize> {
+        Some(self.dict as usize)
+    }
+    fn context(&self) -> Option<usize> {
+        Some(self.context as usize)
+    }
+    fn threading_local_key(&self) -> Option<usize> {
+        Some(self.threading_local_key as usize)
+    }
 }
 
 impl FrameObject for v3_13_0::_PyInterpreterFrame {
@@ -499,6 +733,126 @@ impl TypeObject for v3_13_0::PyTypeObject {
 
 CompactCodeObjectImpl!(v3_13_0, PyBytesObject, PyUnicodeObject);
 
+// Python 3.13 free-threaded builds, which only differ in the layout of their structs
+Python3Impl!(v3_13_0t);
+
+impl InterpreterState for v3_13_0t::PyInterpreterState {
+    type ThreadState = v3_13_0t::PyThreadState;
+    type Object = v3_13_0t::PyObject;
+    type StringObject = v3_13_0t::PyUnicodeObject;
+    type ListObject = v3_13_0t::PyListObject;
+    type TupleObject = v3_13_0t::PyTupleObject;
+    const HAS_GIL_RUNTIME_STATE: bool = true;
+    const FREE_THREADED: bool = true;
+
+    fn threadstate_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::ThreadState {
+        (interpreter_address + std::mem::offset_of!(Self, threads.head))
+            as *const *const Self::ThreadState
+    }
+    fn modules_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::Object {
+        (interpreter_address + std::mem::offset_of!(Self, imports.modules))
+            as *const *const Self::Object
+    }
+    fn next_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self {
+        (interpreter_address + std::mem::offset_of!(Self, next)) as *const *const Self
+    }
+    fn id_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> Option<*const i64> {
+        Some((interpreter_address + std::mem::offset_of!(Self, id)) as *const i64)
+    }
+    fn gil_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const usize> {
+        Some((interpreter_address + std::mem::offset_of!(Self, ceval.gil)) as *const usize)
+    }
+}
+
+impl RemoteStruct for v3_13_0t::PyThreadState {}
+impl RemoteStruct for v3_13_0t::_PyInterpreterFrame {}
+impl RemoteStruct for v3_13_0t::PyObject {}
+impl RemoteStruct for v3_13_0t::PyTypeObject {}
+
+impl ThreadState for v3_13_0t::PyThreadState {
+    type FrameObject = v3_13_0t::_PyInterpreterFrame;
+    type InterpreterState = v3_13_0t::PyInterpreterState;
+    fn frame_address(&self) -> Option<usize> {
+        None
+    }
+    fn frame(&self, _addr: Option<usize>) -> *mut Self::FrameObject {
+        self.current_frame
+    }
+    fn thread_id(&self) -> u64 {
+        self.thread_id as u64
+    }
+    fn native_thread_id(&self) -> Option<u64> {
+        Some(self.native_thread_id as u64)
+    }
+    fn next(&self) -> *mut Self {
+        self.next
+    }
+    fn interp(&self) -> *mut Self::InterpreterState {
+        self.interp
+    }
+    fn dict(&self) -> Option<usize> {
+        Some(self.dict as usize)
+    }
+    fn context(&self) -> Option<usize> {
+        Some(self.context as usize)
+    }
+    fn threading_local_key(&self) -> Option<usize> {
+        Some(self.threading_local_key as usize)
+    }
+}
+
+impl FrameObject for v3_13_0t::_PyInterpreterFrame {
+    type CodeObject = v3_13_0t::PyCodeObject;
+    fn code(&self) -> *mut Self::CodeObject {
+        self.f_executable as *mut v3_13_0t::PyCodeObject
+    }
+    fn lasti(&self) -> i32 {
+        let co_code = self.f_executable as *const _ as *const u8;
+        unsafe { (self.instr_ptr as *const u8).offset_from(co_code) as i32 }
+    }
+    fn back(&self) -> *mut Self {
+        self.previous
+    }
+    fn is_entry(&self) -> bool {
+        // https://github.com/python/cpython/pull/108036#issuecomment-1684458828
+        const FRAME_OWNED_BY_CSTACK: ::std::os::raw::c_char = 3;
+        self.owner == FRAME_OWNED_BY_CSTACK
+    }
+}
+
+impl Object for v3_13_0t::PyObject {
+    type TypeObject = v3_13_0t::PyTypeObject;
+    fn ob_type(&self) -> *mut Self::TypeObject {
+        self.ob_type as *mut Self::TypeObject
+    }
+}
+
+impl TypeObject for v3_13_0t::PyTypeObject {
+    fn name(&self) -> *const ::std::os::raw::c_char {
+        self.tp_name
+    }
+    fn dictoffset(&self) -> isize {
+        self.tp_dictoffset
+    }
+    fn flags(&self) -> usize {
+        self.tp_flags as usize
+    }
+}
+
+CompactCodeObjectImpl!(v3_13_0t, PyBytesObject, PyUnicodeObject);
+
 // Python 3.12
 // TODO: this shares some similarities with python 3.11, we should refactor to a common macro
 Python3Impl!(v3_12_0);
@@ -511,16 +865,42 @@ impl InterpreterState for v3_12_0::PyInterpreterState {
     type TupleObject = v3_12_0::PyTupleObject;
     const HAS_GIL_RUNTIME_STATE: bool = true;
 
-    fn threadstate_ptr_ptr(interpreter_address: usize) -> *const *const Self::ThreadState {
+    fn threadstate_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::ThreadState {
         (interpreter_address + std::mem::offset_of!(Self, threads.head))
             as *const *const Self::ThreadState
     }
-    fn modules_ptr_ptr(interpreter_address: usize) -> *const *const Self::Object {
+    fn modules_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::Object {
         (interpreter_address + std::mem::offset_of!(Self, imports.modules))
             as *const *const Self::Object
     }
+    fn next_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self {
+        (interpreter_address + std::mem::offset_of!(Self, next)) as *const *const Self
+    }
+    fn id_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> Option<*const i64> {
+        Some((interpreter_address + std::mem::offset_of!(Self, id)) as *const i64)
+    }
+    fn gil_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> Option<*const usize> {
+        Some((interpreter_address + std::mem::offset_of!(Self, ceval.gil)) as *const usize)
+    }
 }
 
+impl RemoteStruct for v3_12_0::PyThreadState {}
+impl RemoteStruct for v3_12_0::_PyInterpreterFrame {}
+impl RemoteStruct for v3_12_0::PyObject {}
+impl RemoteStruct for v3_12_0::PyTypeObject {}
+
 impl ThreadState for v3_12_0::PyThreadState {
     type FrameObject = v3_12_0::_PyInterpreterFrame;
     type InterpreterState = v3_12_0::PyInterpreterState;
@@ -545,6 +925,12 @@ impl ThreadState for v3_12_0::PyThreadState {
     fn interp(&self) -> *mut Self::InterpreterState {
         self.interp
     }
+    fn dict(&self) -> Option<usize> {
+        Some(self.dict as usize)
+    }
+    fn context(&self) -> Option<usize> {
+        Some(self.context as usize)
+    }
 }
 
 impl FrameObject for v3_12_0::_PyInterpreterFrame {
@@ -602,15 +988,35 @@ impl InterpreterState for v3_11_0::PyInterpreterState {
     type ListObject = v3_11_0::PyListObject;
     type TupleObject = v3_11_0::PyTupleObject;
 
-    fn threadstate_ptr_ptr(interpreter_address: usize) -> *const *const Self::ThreadState {
+    fn threadstate_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::ThreadState {
         (interpreter_address + std::mem::offset_of!(Self, threads.head))
             as *const *const Self::ThreadState
     }
-    fn modules_ptr_ptr(interpreter_address: usize) -> *const *const Self::Object {
+    fn modules_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self::Object {
         (interpreter_address + std::mem::offset_of!(Self, modules)) as *const *const Self::Object
     }
+    fn next_ptr_ptr(
+        interpreter_address: usize,
+        _: Option<&_Py_DebugOffsets>,
+    ) -> *const *const Self {
+        (interpreter_address + std::mem::offset_of!(Self, next)) as *const *const Self
+    }
+    fn id_ptr(interpreter_address: usize, _: Option<&_Py_DebugOffsets>) -> Option<*const i64> {
+        Some((interpreter_address + std::mem::offset_of!(Self, id)) as *const i64)
+    }
 }
 
+impl RemoteStruct for v3_11_0::PyThreadState {}
+impl RemoteStruct for v3_11_0::_PyInterpreterFrame {}
+impl RemoteStruct for v3_11_0::PyObject {}
+impl RemoteStruct for v3_11_0::PyTypeObject {}
+
 impl ThreadState for v3_11_0::PyThreadState {
     type FrameObject = v3_11_0::_PyInterpreterFrame;
     type InterpreterState = v3_11_0::PyInterpreterState;
@@ -635,6 +1041,12 @@ impl ThreadState for v3_11_0::PyThreadState {
     fn interp(&self) -> *mut Self::InterpreterState {
         self.interp
     }
+    fn dict(&self) -> Option<usize> {
+        Some(self.dict as usize)
+    }
+    fn context(&self) -> Option<usize> {
+        Some(self.context as usize)
+    }
 }
 
 impl FrameObject for v3_11_0::_PyInterpreterFrame {
@@ -680,7 +1092,9 @@ CompactCodeObjectImpl!(v3_11_0, PyBytesObject, PyUnicodeObject);
 
 // Python 3.10
 Python3Impl!(v3_10_0);
-PythonCommonImpl!(v3_10_0, PyUnicodeObject);
+PythonCommonImpl!(v3_10_0, PyUnicodeObject, id, context);
+
+impl RemoteStruct for v3_10_0::PyCodeObject {}
 
 impl CodeObject for v3_10_0::PyCodeObject {
     type BytesObject = v3_10_0::PyBytesObject;
@@ -708,6 +1122,13 @@ impl CodeObject for v3_10_0::PyCodeObject {
     fn varnames(&self) -> *mut Self::TupleObject {
         self.co_varnames as *mut Self::TupleObject
     }
+    fn bytecode(&self) -> Option<*mut Self::BytesObject> {
+        Some(self.co_code as *mut Self::BytesObject)
+    }
+    fn instruction_offset(&self, lasti: i32) -> i32 {
+        // lasti counts 2-byte code units in python 3.10, see get_line_number below
+        2 * lasti
+    }
     fn get_line_number(&self, lasti: i32, table: &[u8]) -> i32 {
         // in Python 3.10 we need to double the lasti instruction value here (and no I don't know why)
         // https://github.com/python/cpython/blob/7b88f63e1dd4006b1a08b9c9f087dd13449ecc76/Python/ceval.c#L5999
@@ -742,17 +1163,17 @@ impl CodeObject for v3_10_0::PyCodeObject {
 }
 
 // Python 3.9
-PythonCommonImpl!(v3_9_5, PyUnicodeObject);
+PythonCommonImpl!(v3_9_5, PyUnicodeObject, id, context);
 PythonCodeObjectImpl!(v3_9_5, PyBytesObject, PyUnicodeObject);
 Python3Impl!(v3_9_5);
 
 // Python 3.8
-PythonCommonImpl!(v3_8_0, PyUnicodeObject);
+PythonCommonImpl!(v3_8_0, PyUnicodeObject, id, context);
 PythonCodeObjectImpl!(v3_8_0, PyBytesObject, PyUnicodeObject);
 Python3Impl!(v3_8_0);
 
 // Python 3.7
-PythonCommonImpl!(v3_7_0, PyUnicodeObject);
+PythonCommonImpl!(v3_7_0, PyUnicodeObject, id, context);
 PythonCodeObjectImpl!(v3_7_0, PyBytesObject, PyUnicodeObject);
 Python3Impl!(v3_7_0);
 
@@ -774,6 +1195,10 @@ Python3Impl!(v3_3_7);
 // Python 2.7
 PythonCommonImpl!(v2_7_15, PyStringObject);
 PythonCodeObjectImpl!(v2_7_15, PyStringObject, PyStringObject);
+impl RemoteStruct for v2_7_15::PyStringObject {}
+impl RemoteStruct for v2_7_15::PyListObject {}
+impl RemoteStruct for v2_7_15::PyTupleObject {}
+
 impl BytesObject for v2_7_15::PyStringObject {
     fn size(&self) -> usize {
         self.ob_size as usize
@@ -836,4 +1261,21 @@ mod tests {
         ];
         assert_eq!(code.get_line_number(214, &table), 5);
     }
+
+    #[test]
+    fn test_py3_11_columns() {
+        // the line table for
+        // def f(x):
+        //     return g(h(x),
+        //              k(x))
+        let table = [
+            128_u8, 0, 221, 11, 12, 141, 81, 136, 113, 137, 84, 140, 84, 221, 13, 14, 136, 113,
+            137, 84, 140, 84, 241, 3, 1, 12, 19, 244, 0, 1, 12, 19, 240, 0, 1, 5, 19,
+        ];
+        // the calls to h, k and g
+        assert_eq!(get_compact_location(1, 34, &table), (2, Some((13, 17))));
+        assert_eq!(get_compact_location(1, 62, &table), (3, Some((13, 17))));
+        assert_eq!(get_compact_location(1, 76, &table), (2, Some((11, 18))));
+        assert_eq!(get_compact_location(1, 4, &table), (2, Some((11, 12))));
+    }
 }
@@ -0,0 +1,635 @@
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Error};
+use remoteprocess::ProcessMemory;
+
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
+use crate::python_data_access::{
+    copy_string, format_variable, instance_dict_iterator, object_header_size, object_type_name,
+    DictIterator,
+};
+use crate::python_interpreters::{InterpreterState, ThreadState};
+use crate::version::Version;
+
+/*
+ * This file contains code to read the values for the '--label name=expression' options, which
+ * tag each sample with something like the endpoint or tenant that the thread was working on.
+ *
+ * Each expression is a dotted path starting at a module, like 'app.context.tenant'. The module
+ * attributes are read through the instance dicts of each object, until we either reach the value
+ * or hit an object whose value depends on the thread being sampled:
+ *  - a contextvars.ContextVar, which is looked up in the context that is current in the thread
+ *  - a threading.local, where the next attribute is read from the thread's local dict
+ *
+ * Neither the context or threading.local objects are exposed in our bindings, so like the
+ * asyncio code the few fields we need here are hardcoded.
+ */
+
+// the maximum length of the formatted value for objects that aren't strings
+const MAX_VALUE_LENGTH: isize = 128;
+
+// how often to look for the modules of expressions that haven't been imported yet
+const RESOLVE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Reads the values for the --label options from the threads in a single interpreter
+#[derive(Debug, Clone)]
+pub struct LabelReader {
+    labels: Vec<Label>,
+    last_resolved: Option<Instant>,
+}
+
+#[derive(Debug, Clone)]
+struct Label {
+    name: String,
+    expression: String,
+    /// The address of the module that the expression starts from, along with the attributes to
+    /// read from it. None if the module hasn't been imported yet
+    module: Option<(usize, Vec<String>)>,
+}
+
+/// Where to read the value of a label from for each thread in a sample
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LabelSource {
+    /// The label has the same value in every thread
+    Value(usize),
+    /// A ContextVar, with the attributes to read from its value
+    ContextVar { var: usize, attributes: Vec<String> },
+    /// A threading.local, with the attributes to read from it
+    ThreadLocal {
+        local: usize,
+        attributes: Vec<String>,
+    },
+}
+
+/// Layout of PyContext after the PyObject header
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+struct PyContext {
+    ctx_prev: usize,
+    ctx_vars: usize,
+}
+
+/// Layout of PyContextVar after the PyObject header
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+struct PyContextVar {
+    var_name: usize,
+    var_default: usize,
+}
+
+/// Layout of the threading.local object after the PyObject header, before python 3.13. The
+/// thread's state dict maps 'key' to a dummy object holding the local dict
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+struct LocalObject {
+    key: usize,
+    args: usize,
+    kw: usize,
+    weakreflist: usize,
+    dummies: usize,
+}
+
+/// Layout of the threading.local object after the PyObject header, in python 3.13+. Local dicts
+/// are stored in 'localdicts', keyed by the threading_local_key of each thread
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Default)]
+struct LocalObject313 {
+    args: usize,
+    kw: usize,
+    weakreflist: usize,
+    localdicts: usize,
+}
+
+impl LabelReader {
+    pub fn new(labels: &[(String, String)]) -> LabelReader {
+        LabelReader {
+            labels: labels
+                .iter()
+                .map(|(name, expression)| Label {
+                    name: name.clone(),
+                    expression: expression.clone(),
+                    module: None,
+                })
+                .collect(),
+            last_resolved: None,
+        }
+    }
+
+    /// Finds the modules for any expressions whose module hasn't been found yet. Since this
+    /// means searching the modules dict, this is only done once every RESOLVE_INTERVAL
+    pub fn resolve<I, P>(
+        &mut self,
+        interpreter_address: usize,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+        version: &Version,
+    ) -> Result<(), Error>
+    where
+        I: InterpreterState,
+        P: ProcessMemory,
+    {
+        if self.labels.iter().all(|label| label.module.is_some())
+            || self
+                .last_resolved
+                .is_some_and(|last| last.elapsed() < RESOLVE_INTERVAL)
+        {
+            return Ok(());
+        }
+        self.last_resolved = Some(Instant::now());
+
+        let modules_ptr_ptr = I::modules_ptr_ptr(interpreter_address, offsets);
+        let modules: *const I::Object = process
+            .copy_pointer(modules_ptr_ptr)
+            .context("Failed to copy modules PyObject")?;
+
+        // use the longest module name that the expression starts with, so that 'app.db.name'
+        // reads 'name' from the 'app.db' module rather than the 'db' attribute of 'app'
+        let mut found: Vec<Option<(usize, usize)>> = vec![None; self.labels.len()];
+        for entry in DictIterator::from(process, version, modules as usize)? {
+            let (key, value) = entry?;
+            let module_name = copy_string(key as *const I::StringObject, process, offsets)?;
+            for (label, found) in self.labels.iter().zip(found.iter_mut()) {
+                let is_prefix = label
+                    .expression
+                    .strip_prefix(&module_name)
+                    .is_some_and(|rest| rest.starts_with('.'));
+                if is_prefix && found.is_none_or(|(len, _)| module_name.len() > len) {
+                    *found = Some((module_name.len(), value));
+                }
+            }
+        }
+
+        for (label, found) in self.labels.iter_mut().zip(found) {
+            if let (None, Some((len, module))) = (&label.module, found) {
+                let attributes = label.expression[len + 1..]
+                    .split('.')
+                    .map(|attribute| attribute.to_owned())
+                    .collect();
+                label.module = Some((module, attributes));
+            }
+        }
+        Ok(())
+    }
+
+    /// Reads the part of each expression that is the same for every thread, returning the name
+    /// of each label and where to read its value from. Labels that can't be read are skipped
+    pub fn sources<I, P>(
+        &self,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+        version: &Version,
+    ) -> Vec<(String, LabelSource)>
+    where
+        I: InterpreterState,
+        P: ProcessMemory,
+    {
+        let mut sources = Vec::new();
+        for label in &self.labels {
+            let (module, attributes) = match &label.module {
+                Some((module, attributes)) => (*module, attributes.as_slice()),
+                None => continue,
+            };
+            match get_source::<I, P>(process, offsets, version, module, attributes) {
+                Ok(Some(source)) => sources.push((label.name.clone(), source)),
+                Ok(None) => {}
+                Err(e) => info!("Failed to read label '{}': {}", label.expression, e),
+            }
+        }
+        sources
+    }
+}
+
+/// Reads the value of each label for a thread, formatted as a string
+pub fn read_labels<I, P>(
+    sources: &[(String, LabelSource)],
+    thread: &I::ThreadState,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+) -> Vec<(String, String)>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let mut labels = Vec::new();
+    for (name, source) in sources {
+        let value =
+            get_thread_value::<I, P>(process, offsets, version, thread, source).and_then(|value| {
+                match value {
+                    Some(value) => format_label::<I, P>(process, offsets, version, value).map(Some),
+                    None => Ok(None),
+                }
+            });
+        match value {
+            Ok(Some(value)) => labels.push((name.clone(), value)),
+            Ok(None) => {}
+            Err(e) => info!("Failed to read label '{}': {}", name, e),
+        }
+    }
+    labels
+}
+
+/// Follows the attributes from a module until reaching the value, or an object whose value
+/// depends on the thread
+fn get_source<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    module: usize,
+    attributes: &[String],
+) -> Result<Option<LabelSource>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let mut value = module;
+    for (i, attribute) in attributes.iter().enumerate() {
+        value = match get_attribute::<I, P>(process, offsets, version, value, attribute)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+
+        let remaining = &attributes[i + 1..];
+        match object_type_name::<I, P>(process, offsets, value)?.as_str() {
+            "ContextVar" | "_contextvars.ContextVar" => {
+                return Ok(Some(LabelSource::ContextVar {
+                    var: value,
+                    attributes: remaining.to_vec(),
+                }))
+            }
+            "_thread._local" if !remaining.is_empty() => {
+                return Ok(Some(LabelSource::ThreadLocal {
+                    local: value,
+                    attributes: remaining.to_vec(),
+                }))
+            }
+            _ => {}
+        }
+    }
+    Ok(Some(LabelSource::Value(value)))
+}
+
+/// Gets the value of a label for a thread, returning None if it isn't set
+fn get_thread_value<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    thread: &I::ThreadState,
+    source: &LabelSource,
+) -> Result<Option<usize>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let (value, attributes) = match source {
+        LabelSource::Value(value) => return Ok(Some(*value)),
+        LabelSource::ContextVar { var, attributes } => (
+            get_context_var::<I, P>(process, offsets, version, thread, *var)?,
+            attributes.as_slice(),
+        ),
+        LabelSource::ThreadLocal { local, attributes } => {
+            let dict = get_local_dict::<I, P>(process, version, thread, *local)?;
+            match dict {
+                Some(dict) => (
+                    get_dict_item::<I, P>(process, offsets, version, dict, &attributes[0])?,
+                    &attributes[1..],
+                ),
+                None => (None, attributes.as_slice()),
+            }
+        }
+    };
+
+    let mut value = match value {
+        Some(value) => value,
+        None => return Ok(None),
+    };
+    for attribute in attributes {
+        value = match get_attribute::<I, P>(process, offsets, version, value, attribute)? {
+            Some(value) => value,
+            None => return Ok(None),
+        };
+    }
+    Ok(Some(value))
+}
+
+/// Looks up a ContextVar in the thread's current context, falling back to the ContextVar's
+/// default value if it isn't set there
+fn get_context_var<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    thread: &I::ThreadState,
+    var: usize,
+) -> Result<Option<usize>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let context = thread
+        .context()
+        .ok_or_else(|| format_err!("contextvars aren't supported for python {}", version))?;
+    if context != 0 {
+        let context: PyContext = process
+            .copy_struct(context + object_header_size(version))
+            .context("Failed to copy PyContext")?;
+        if context.ctx_vars != 0 {
+            let hamt_root: usize = process
+                .copy_struct(context.ctx_vars + object_header_size(version))
+                .context("Failed to copy PyHamtObject")?;
+            if let Some(value) =
+                find_hamt_entry::<I, P>(process, offsets, version, hamt_root, var, 0)?
+            {
+                return Ok(Some(value));
+            }
+        }
+    }
+
+    let var: PyContextVar = process
+        .copy_struct(var + object_header_size(version))
+        .context("Failed to copy PyContextVar")?;
+    Ok(if var.var_default != 0 {
+        Some(var.var_default)
+    } else {
+        None
+    })
+}
+
+/// Finds the value for a key in the hash array mapped trie that stores the variables of a
+/// context. Rather than hashing the ContextVar, this just searches every node for the key
+fn find_hamt_entry<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    node: usize,
+    key: usize,
+    depth: usize,
+) -> Result<Option<usize>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    // keys are 32 bit hashes, split into 5 bits per level of the trie
+    if node == 0 || depth > 8 {
+        return Ok(None);
+    }
+
+    let ptr_size = std::mem::size_of::<usize>();
+    let header_size = object_header_size(version);
+    match object_type_name::<I, P>(process, offsets, node)?.as_str() {
+        // bitmap and collision nodes store (key, value) pairs after the ob_size and a 32 bit
+        // bitmap or hash. A bitmap node entry without a key has a child node as its value
+        name @ ("hamt_bitmap_node" | "hamt_collision_node") => {
+            let size: isize = process.copy_struct(node + header_size)?;
+            if !(0..=64).contains(&size) {
+                return Err(format_err!("Invalid hamt node size {}", size));
+            }
+            let entries = copy_pointers(process, node + header_size + 2 * ptr_size, size)?;
+            for entry in entries.chunks_exact(2) {
+                if entry[0] == key {
+                    return Ok(Some(entry[1]));
+                }
+                if entry[0] == 0 && name == "hamt_bitmap_node" {
+                    let value = find_hamt_entry::<I, P>(
+                        process,
+                        offsets,
+                        version,
+                        entry[1],
+                        key,
+                        depth + 1,
+                    )?;
+                    if value.is_some() {
+                        return Ok(value);
+                    }
+                }
+            }
+            Ok(None)
+        }
+        "hamt_array_node" => {
+            for child in copy_pointers(process, node + header_size, 32)? {
+                let value =
+                    find_hamt_entry::<I, P>(process, offsets, version, child, key, depth + 1)?;
+                if value.is_some() {
+                    return Ok(value);
+                }
+            }
+            Ok(None)
+        }
+        name => Err(format_err!("Unexpected hamt node type '{}'", name)),
+    }
+}
+
+/// Gets the address of the thread's dict for a threading.local object, or None if the
+/// threading.local hasn't been used in the thread
+fn get_local_dict<I, P>(
+    process: &P,
+    version: &Version,
+    thread: &I::ThreadState,
+    local: usize,
+) -> Result<Option<usize>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let header_size = object_header_size(version);
+    if version.major == 3 && version.minor >= 13 {
+        let key = match thread.threading_local_key() {
+            Some(key) if key != 0 => key,
+            _ => return Ok(None),
+        };
+        let local: LocalObject313 = process.copy_struct(local + header_size)?;
+        find_dict_value(process, version, local.localdicts, key)
+    } else {
+        let dict = match thread.dict() {
+            Some(dict) if dict != 0 => dict,
+            _ => return Ok(None),
+        };
+        let local: LocalObject = process.copy_struct(local + header_size)?;
+        // the dummy object stores the local dict directly after the PyObject header
+        match find_dict_value(process, version, dict, local.key)? {
+            Some(dummy) => Ok(Some(process.copy_struct(dummy + header_size)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Gets an attribute from the __dict__ of an object
+fn get_attribute<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    addr: usize,
+    name: &str,
+) -> Result<Option<usize>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    for entry in instance_dict_iterator::<I, P>(process, offsets, version, addr)? {
+        let (key, value) = entry?;
+        if copy_string(key as *const I::StringObject, process, offsets)? == name {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// Gets the value for a string key in a dict
+fn get_dict_item<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    dict: usize,
+    name: &str,
+) -> Result<Option<usize>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    for entry in DictIterator::from(process, version, dict)? {
+        let (key, value) = entry?;
+        if copy_string(key as *const I::StringObject, process, offsets)? == name {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+/// Gets the value for a key in a dict, comparing keys by identity
+fn find_dict_value<P: ProcessMemory>(
+    process: &P,
+    version: &Version,
+    dict: usize,
+    key: usize,
+) -> Result<Option<usize>, Error> {
+    for entry in DictIterator::from(process, version, dict)? {
+        let (entry_key, value) = entry?;
+        if entry_key == key {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+fn copy_pointers<P: ProcessMemory>(
+    process: &P,
+    addr: usize,
+    count: isize,
+) -> Result<Vec<usize>, Error> {
+    let ptr_size = std::mem::size_of::<usize>();
+    let bytes = process.copy(addr, count as usize * ptr_size)?;
+    Ok(bytes
+        .chunks_exact(ptr_size)
+        .map(|chunk| {
+            let mut ptr = [0_u8; std::mem::size_of::<usize>()];
+            ptr.copy_from_slice(chunk);
+            usize::from_ne_bytes(ptr)
+        })
+        .collect())
+}
+
+/// Formats the value of a label. Strings are used as is, rather than being quoted like
+/// format_variable does
+fn format_label<I, P>(
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+    addr: usize,
+) -> Result<String, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    if object_type_name::<I, P>(process, offsets, addr)? == "str" {
+        return copy_string(addr as *const I::StringObject, process, offsets);
+    }
+    format_variable::<I, P>(process, offsets, version, addr, MAX_VALUE_LENGTH)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::python_bindings::v3_7_0::{_is, PyObject, PyTypeObject};
+    use remoteprocess::LocalProcess;
+
+    /// A python object with some pointer sized fields after the header
+    #[repr(C)]
+    struct TestObject<const N: usize> {
+        base: PyObject,
+        fields: [usize; N],
+    }
+
+    fn object<const N: usize>(ob_type: &mut PyTypeObject, fields: [usize; N]) -> TestObject<N> {
+        TestObject {
+            base: PyObject {
+                ob_type,
+                ..Default::default()
+            },
+            fields,
+        }
+    }
+
+    #[test]
+    fn test_find_hamt_entry() {
+        let version = Version {
+            major: 3,
+            minor: 7,
+            patch: 0,
+            release_flags: "".to_owned(),
+            build_metadata: None,
+            free_threaded: false,
+        };
+        let names: Vec<std::ffi::CString> = ["hamt_bitmap_node", "hamt_array_node", "int"]
+            .iter()
+            .map(|name| std::ffi::CString::new(*name).unwrap())
+            .collect();
+        let mut types: Vec<PyTypeObject> = names
+            .iter()
+            .map(|name| PyTypeObject {
+                tp_name: name.as_ptr(),
+                ..Default::default()
+            })
+            .collect();
+        let (bitmap_type, rest) = types.split_at_mut(1);
+        let (array_type, int_type) = rest.split_at_mut(1);
+
+        let key = object(&mut int_type[0], []);
+        let value = object(&mut int_type[0], []);
+        let other = object(&mut int_type[0], []);
+        let addr = |o: &TestObject<0>| o as *const _ as usize;
+
+        // a bitmap node with ob_size 4, the bitmap and then two (key, value) pairs
+        let leaf = object(
+            &mut bitmap_type[0],
+            [
+                4,
+                0b11,
+                addr(&other),
+                addr(&other),
+                addr(&key),
+                addr(&value),
+            ],
+        );
+        let mut children = [0_usize; 32];
+        children[7] = &leaf as *const _ as usize;
+        let array = object(&mut array_type[0], children);
+        // a bitmap node with a single entry pointing at the array node
+        let root = object(
+            &mut bitmap_type[0],
+            [2, 0b1, 0, &array as *const _ as usize],
+        );
+
+        let find = |search: usize| {
+            find_hamt_entry::<_is, LocalProcess>(
+                &LocalProcess,
+                None,
+                &version,
+                &root as *const _ as usize,
+                search,
+                0,
+            )
+            .unwrap()
+        };
+        assert_eq!(find(addr(&key)), Some(addr(&value)));
+        assert_eq!(find(addr(&value)), None);
+    }
+}
@@ -0,0 +1,1745 @@
+// Opcode names for each version of python that we have bindings for, used to show which
+// bytecode instruction was running with 'record --instructions'. The tables here were
+// generated from each python version with:
+//     [(i, n) for i, n in enumerate(getattr(dis, '_all_opname', opcode.opname)[:256])
+//      if not n.startswith('<')]
+// dis._all_opname includes the specialized adaptive opcodes in python 3.11+.
+
+use anyhow::{format_err, Error};
+
+use crate::version::Version;
+
+/// Returns the name of an opcode for a version of python, or None if we don't know about it
+pub fn opcode_name(version: &Version, opcode: u8) -> Option<&'static str> {
+    let table = opcode_table(version)?;
+    table
+        .binary_search_by_key(&opcode, |&(opcode, _)| opcode)
+        .ok()
+        .map(|index| table[index].1)
+}
+
+/// Returns an error for versions of python that we don't have opcode names for
+pub fn check_version(version: &Version) -> Result<(), Error> {
+    match opcode_table(version) {
+        Some(_) => Ok(()),
+        None => Err(format_err!(
+            "--instructions isn't supported for Python {}",
+            version
+        )),
+    }
+}
+
+fn opcode_table(version: &Version) -> Option<&'static [(u8, &'static str)]> {
+    let table = match (version.major, version.minor) {
+        (2, 7) => PY2_7,
+        (3, 3) => PY3_3,
+        (3, 4) => PY3_4,
+        (3, 5) => PY3_5,
+        (3, 6) => PY3_6,
+        (3, 7) => PY3_7,
+        (3, 8) => PY3_8,
+        (3, 9) => PY3_9,
+        (3, 10) => PY3_10,
+        (3, 11) => PY3_11,
+        (3, 12) => PY3_12,
+        (3, 13) => PY3_13,
+        _ => return None,
+    };
+    Some(table)
+}
+
+const PY2_7: &[(u8, &str)] = &[
+    (0, "STOP_CODE"),
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "ROT_FOUR"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (13, "UNARY_CONVERT"),
+    (15, "UNARY_INVERT"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (21, "BINARY_DIVIDE"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (30, "SLICE+0"),
+    (31, "SLICE+1"),
+    (32, "SLICE+2"),
+    (33, "SLICE+3"),
+    (40, "STORE_SLICE+0"),
+    (41, "STORE_SLICE+1"),
+    (42, "STORE_SLICE+2"),
+    (43, "STORE_SLICE+3"),
+    (50, "DELETE_SLICE+0"),
+    (51, "DELETE_SLICE+1"),
+    (52, "DELETE_SLICE+2"),
+    (53, "DELETE_SLICE+3"),
+    (54, "STORE_MAP"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (58, "INPLACE_DIVIDE"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "PRINT_ITEM"),
+    (72, "PRINT_NEWLINE"),
+    (73, "PRINT_ITEM_TO"),
+    (74, "PRINT_NEWLINE_TO"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (80, "BREAK_LOOP"),
+    (81, "WITH_CLEANUP"),
+    (82, "LOAD_LOCALS"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "EXEC_STMT"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "BUILD_CLASS"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "LIST_APPEND"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (99, "DUP_TOPX"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (119, "CONTINUE_LOOP"),
+    (120, "SETUP_LOOP"),
+    (121, "SETUP_EXCEPT"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (134, "MAKE_CLOSURE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (140, "CALL_FUNCTION_VAR"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_VAR_KW"),
+    (143, "SETUP_WITH"),
+    (145, "EXTENDED_ARG"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+];
+
+const PY3_3: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (54, "STORE_MAP"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "STORE_LOCALS"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (80, "BREAK_LOOP"),
+    (81, "WITH_CLEANUP"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (119, "CONTINUE_LOOP"),
+    (120, "SETUP_LOOP"),
+    (121, "SETUP_EXCEPT"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (134, "MAKE_CLOSURE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (140, "CALL_FUNCTION_VAR"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_VAR_KW"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+];
+
+const PY3_4: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (54, "STORE_MAP"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (80, "BREAK_LOOP"),
+    (81, "WITH_CLEANUP"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (119, "CONTINUE_LOOP"),
+    (120, "SETUP_LOOP"),
+    (121, "SETUP_EXCEPT"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (134, "MAKE_CLOSURE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (140, "CALL_FUNCTION_VAR"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_VAR_KW"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+];
+
+const PY3_5: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_MATRIX_MULTIPLY"),
+    (17, "INPLACE_MATRIX_MULTIPLY"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (73, "GET_AWAITABLE"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (80, "BREAK_LOOP"),
+    (81, "WITH_CLEANUP_START"),
+    (82, "WITH_CLEANUP_FINISH"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (119, "CONTINUE_LOOP"),
+    (120, "SETUP_LOOP"),
+    (121, "SETUP_EXCEPT"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (134, "MAKE_CLOSURE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (140, "CALL_FUNCTION_VAR"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_VAR_KW"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (149, "BUILD_LIST_UNPACK"),
+    (150, "BUILD_MAP_UNPACK"),
+    (151, "BUILD_MAP_UNPACK_WITH_CALL"),
+    (152, "BUILD_TUPLE_UNPACK"),
+    (153, "BUILD_SET_UNPACK"),
+    (154, "SETUP_ASYNC_WITH"),
+];
+
+const PY3_6: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_MATRIX_MULTIPLY"),
+    (17, "INPLACE_MATRIX_MULTIPLY"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (73, "GET_AWAITABLE"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (80, "BREAK_LOOP"),
+    (81, "WITH_CLEANUP_START"),
+    (82, "WITH_CLEANUP_FINISH"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (119, "CONTINUE_LOOP"),
+    (120, "SETUP_LOOP"),
+    (121, "SETUP_EXCEPT"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (127, "STORE_ANNOTATION"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (149, "BUILD_LIST_UNPACK"),
+    (150, "BUILD_MAP_UNPACK"),
+    (151, "BUILD_MAP_UNPACK_WITH_CALL"),
+    (152, "BUILD_TUPLE_UNPACK"),
+    (153, "BUILD_SET_UNPACK"),
+    (154, "SETUP_ASYNC_WITH"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (158, "BUILD_TUPLE_UNPACK_WITH_CALL"),
+];
+
+const PY3_7: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_MATRIX_MULTIPLY"),
+    (17, "INPLACE_MATRIX_MULTIPLY"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (73, "GET_AWAITABLE"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (80, "BREAK_LOOP"),
+    (81, "WITH_CLEANUP_START"),
+    (82, "WITH_CLEANUP_FINISH"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (119, "CONTINUE_LOOP"),
+    (120, "SETUP_LOOP"),
+    (121, "SETUP_EXCEPT"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (149, "BUILD_LIST_UNPACK"),
+    (150, "BUILD_MAP_UNPACK"),
+    (151, "BUILD_MAP_UNPACK_WITH_CALL"),
+    (152, "BUILD_TUPLE_UNPACK"),
+    (153, "BUILD_SET_UNPACK"),
+    (154, "SETUP_ASYNC_WITH"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (158, "BUILD_TUPLE_UNPACK_WITH_CALL"),
+    (160, "LOAD_METHOD"),
+    (161, "CALL_METHOD"),
+];
+
+const PY3_8: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (6, "ROT_FOUR"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_MATRIX_MULTIPLY"),
+    (17, "INPLACE_MATRIX_MULTIPLY"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (53, "BEGIN_FINALLY"),
+    (54, "END_ASYNC_FOR"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (73, "GET_AWAITABLE"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (81, "WITH_CLEANUP_START"),
+    (82, "WITH_CLEANUP_FINISH"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (88, "END_FINALLY"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (149, "BUILD_LIST_UNPACK"),
+    (150, "BUILD_MAP_UNPACK"),
+    (151, "BUILD_MAP_UNPACK_WITH_CALL"),
+    (152, "BUILD_TUPLE_UNPACK"),
+    (153, "BUILD_SET_UNPACK"),
+    (154, "SETUP_ASYNC_WITH"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (158, "BUILD_TUPLE_UNPACK_WITH_CALL"),
+    (160, "LOAD_METHOD"),
+    (161, "CALL_METHOD"),
+    (162, "CALL_FINALLY"),
+    (163, "POP_FINALLY"),
+];
+
+const PY3_9: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (6, "ROT_FOUR"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_MATRIX_MULTIPLY"),
+    (17, "INPLACE_MATRIX_MULTIPLY"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (48, "RERAISE"),
+    (49, "WITH_EXCEPT_START"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (54, "END_ASYNC_FOR"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (73, "GET_AWAITABLE"),
+    (74, "LOAD_ASSERTION_ERROR"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (82, "LIST_TO_TUPLE"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (117, "IS_OP"),
+    (118, "CONTAINS_OP"),
+    (121, "JUMP_IF_NOT_EXC_MATCH"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (154, "SETUP_ASYNC_WITH"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (160, "LOAD_METHOD"),
+    (161, "CALL_METHOD"),
+    (162, "LIST_EXTEND"),
+    (163, "SET_UPDATE"),
+    (164, "DICT_MERGE"),
+    (165, "DICT_UPDATE"),
+];
+
+const PY3_10: &[(u8, &str)] = &[
+    (1, "POP_TOP"),
+    (2, "ROT_TWO"),
+    (3, "ROT_THREE"),
+    (4, "DUP_TOP"),
+    (5, "DUP_TOP_TWO"),
+    (6, "ROT_FOUR"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_MATRIX_MULTIPLY"),
+    (17, "INPLACE_MATRIX_MULTIPLY"),
+    (19, "BINARY_POWER"),
+    (20, "BINARY_MULTIPLY"),
+    (22, "BINARY_MODULO"),
+    (23, "BINARY_ADD"),
+    (24, "BINARY_SUBTRACT"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_FLOOR_DIVIDE"),
+    (27, "BINARY_TRUE_DIVIDE"),
+    (28, "INPLACE_FLOOR_DIVIDE"),
+    (29, "INPLACE_TRUE_DIVIDE"),
+    (30, "GET_LEN"),
+    (31, "MATCH_MAPPING"),
+    (32, "MATCH_SEQUENCE"),
+    (33, "MATCH_KEYS"),
+    (34, "COPY_DICT_WITHOUT_KEYS"),
+    (49, "WITH_EXCEPT_START"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (54, "END_ASYNC_FOR"),
+    (55, "INPLACE_ADD"),
+    (56, "INPLACE_SUBTRACT"),
+    (57, "INPLACE_MULTIPLY"),
+    (59, "INPLACE_MODULO"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "BINARY_LSHIFT"),
+    (63, "BINARY_RSHIFT"),
+    (64, "BINARY_AND"),
+    (65, "BINARY_XOR"),
+    (66, "BINARY_OR"),
+    (67, "INPLACE_POWER"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "YIELD_FROM"),
+    (73, "GET_AWAITABLE"),
+    (74, "LOAD_ASSERTION_ERROR"),
+    (75, "INPLACE_LSHIFT"),
+    (76, "INPLACE_RSHIFT"),
+    (77, "INPLACE_AND"),
+    (78, "INPLACE_XOR"),
+    (79, "INPLACE_OR"),
+    (82, "LIST_TO_TUPLE"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "YIELD_VALUE"),
+    (87, "POP_BLOCK"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (99, "ROT_N"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "JUMP_ABSOLUTE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (117, "IS_OP"),
+    (118, "CONTAINS_OP"),
+    (119, "RERAISE"),
+    (121, "JUMP_IF_NOT_EXC_MATCH"),
+    (122, "SETUP_FINALLY"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (129, "GEN_START"),
+    (130, "RAISE_VARARGS"),
+    (131, "CALL_FUNCTION"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (135, "LOAD_CLOSURE"),
+    (136, "LOAD_DEREF"),
+    (137, "STORE_DEREF"),
+    (138, "DELETE_DEREF"),
+    (141, "CALL_FUNCTION_KW"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "SETUP_WITH"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (152, "MATCH_CLASS"),
+    (154, "SETUP_ASYNC_WITH"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (160, "LOAD_METHOD"),
+    (161, "CALL_METHOD"),
+    (162, "LIST_EXTEND"),
+    (163, "SET_UPDATE"),
+    (164, "DICT_MERGE"),
+    (165, "DICT_UPDATE"),
+];
+
+const PY3_11: &[(u8, &str)] = &[
+    (0, "CACHE"),
+    (1, "POP_TOP"),
+    (2, "PUSH_NULL"),
+    (3, "BINARY_OP_ADAPTIVE"),
+    (4, "BINARY_OP_ADD_FLOAT"),
+    (5, "BINARY_OP_ADD_INT"),
+    (6, "BINARY_OP_ADD_UNICODE"),
+    (7, "BINARY_OP_INPLACE_ADD_UNICODE"),
+    (8, "BINARY_OP_MULTIPLY_FLOAT"),
+    (9, "NOP"),
+    (10, "UNARY_POSITIVE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (13, "BINARY_OP_MULTIPLY_INT"),
+    (14, "BINARY_OP_SUBTRACT_FLOAT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_OP_SUBTRACT_INT"),
+    (17, "BINARY_SUBSCR_ADAPTIVE"),
+    (18, "BINARY_SUBSCR_DICT"),
+    (19, "BINARY_SUBSCR_GETITEM"),
+    (20, "BINARY_SUBSCR_LIST_INT"),
+    (21, "BINARY_SUBSCR_TUPLE_INT"),
+    (22, "CALL_ADAPTIVE"),
+    (23, "CALL_PY_EXACT_ARGS"),
+    (24, "CALL_PY_WITH_DEFAULTS"),
+    (25, "BINARY_SUBSCR"),
+    (26, "COMPARE_OP_ADAPTIVE"),
+    (27, "COMPARE_OP_FLOAT_JUMP"),
+    (28, "COMPARE_OP_INT_JUMP"),
+    (29, "COMPARE_OP_STR_JUMP"),
+    (30, "GET_LEN"),
+    (31, "MATCH_MAPPING"),
+    (32, "MATCH_SEQUENCE"),
+    (33, "MATCH_KEYS"),
+    (34, "EXTENDED_ARG_QUICK"),
+    (35, "PUSH_EXC_INFO"),
+    (36, "CHECK_EXC_MATCH"),
+    (37, "CHECK_EG_MATCH"),
+    (38, "JUMP_BACKWARD_QUICK"),
+    (39, "LOAD_ATTR_ADAPTIVE"),
+    (40, "LOAD_ATTR_INSTANCE_VALUE"),
+    (41, "LOAD_ATTR_MODULE"),
+    (42, "LOAD_ATTR_SLOT"),
+    (43, "LOAD_ATTR_WITH_HINT"),
+    (44, "LOAD_CONST__LOAD_FAST"),
+    (45, "LOAD_FAST__LOAD_CONST"),
+    (46, "LOAD_FAST__LOAD_FAST"),
+    (47, "LOAD_GLOBAL_ADAPTIVE"),
+    (48, "LOAD_GLOBAL_BUILTIN"),
+    (49, "WITH_EXCEPT_START"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (53, "BEFORE_WITH"),
+    (54, "END_ASYNC_FOR"),
+    (55, "LOAD_GLOBAL_MODULE"),
+    (56, "LOAD_METHOD_ADAPTIVE"),
+    (57, "LOAD_METHOD_CLASS"),
+    (58, "LOAD_METHOD_MODULE"),
+    (59, "LOAD_METHOD_NO_DICT"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "LOAD_METHOD_WITH_DICT"),
+    (63, "LOAD_METHOD_WITH_VALUES"),
+    (64, "PRECALL_ADAPTIVE"),
+    (65, "PRECALL_BOUND_METHOD"),
+    (66, "PRECALL_BUILTIN_CLASS"),
+    (67, "PRECALL_BUILTIN_FAST_WITH_KEYWORDS"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "PRINT_EXPR"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "PRECALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS"),
+    (73, "PRECALL_NO_KW_BUILTIN_FAST"),
+    (74, "LOAD_ASSERTION_ERROR"),
+    (75, "RETURN_GENERATOR"),
+    (76, "PRECALL_NO_KW_BUILTIN_O"),
+    (77, "PRECALL_NO_KW_ISINSTANCE"),
+    (78, "PRECALL_NO_KW_LEN"),
+    (79, "PRECALL_NO_KW_LIST_APPEND"),
+    (80, "PRECALL_NO_KW_METHOD_DESCRIPTOR_FAST"),
+    (81, "PRECALL_NO_KW_METHOD_DESCRIPTOR_NOARGS"),
+    (82, "LIST_TO_TUPLE"),
+    (83, "RETURN_VALUE"),
+    (84, "IMPORT_STAR"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "YIELD_VALUE"),
+    (87, "ASYNC_GEN_WRAP"),
+    (88, "PREP_RERAISE_STAR"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (99, "SWAP"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "JUMP_IF_FALSE_OR_POP"),
+    (112, "JUMP_IF_TRUE_OR_POP"),
+    (113, "PRECALL_NO_KW_METHOD_DESCRIPTOR_O"),
+    (114, "POP_JUMP_FORWARD_IF_FALSE"),
+    (115, "POP_JUMP_FORWARD_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (117, "IS_OP"),
+    (118, "CONTAINS_OP"),
+    (119, "RERAISE"),
+    (120, "COPY"),
+    (121, "PRECALL_NO_KW_STR_1"),
+    (122, "BINARY_OP"),
+    (123, "SEND"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (127, "PRECALL_NO_KW_TUPLE_1"),
+    (128, "POP_JUMP_FORWARD_IF_NOT_NONE"),
+    (129, "POP_JUMP_FORWARD_IF_NONE"),
+    (130, "RAISE_VARARGS"),
+    (131, "GET_AWAITABLE"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (134, "JUMP_BACKWARD_NO_INTERRUPT"),
+    (135, "MAKE_CELL"),
+    (136, "LOAD_CLOSURE"),
+    (137, "LOAD_DEREF"),
+    (138, "STORE_DEREF"),
+    (139, "DELETE_DEREF"),
+    (140, "JUMP_BACKWARD"),
+    (141, "PRECALL_NO_KW_TYPE_1"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "PRECALL_PYFUNC"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "LOAD_CLASSDEREF"),
+    (149, "COPY_FREE_VARS"),
+    (150, "RESUME_QUICK"),
+    (151, "RESUME"),
+    (152, "MATCH_CLASS"),
+    (153, "STORE_ATTR_ADAPTIVE"),
+    (154, "STORE_ATTR_INSTANCE_VALUE"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (158, "STORE_ATTR_SLOT"),
+    (159, "STORE_ATTR_WITH_HINT"),
+    (160, "LOAD_METHOD"),
+    (161, "STORE_FAST__LOAD_FAST"),
+    (162, "LIST_EXTEND"),
+    (163, "SET_UPDATE"),
+    (164, "DICT_MERGE"),
+    (165, "DICT_UPDATE"),
+    (166, "PRECALL"),
+    (167, "STORE_FAST__STORE_FAST"),
+    (168, "STORE_SUBSCR_ADAPTIVE"),
+    (169, "STORE_SUBSCR_DICT"),
+    (170, "STORE_SUBSCR_LIST_INT"),
+    (171, "CALL"),
+    (172, "KW_NAMES"),
+    (173, "POP_JUMP_BACKWARD_IF_NOT_NONE"),
+    (174, "POP_JUMP_BACKWARD_IF_NONE"),
+    (175, "POP_JUMP_BACKWARD_IF_FALSE"),
+    (176, "POP_JUMP_BACKWARD_IF_TRUE"),
+    (177, "UNPACK_SEQUENCE_ADAPTIVE"),
+    (178, "UNPACK_SEQUENCE_LIST"),
+    (179, "UNPACK_SEQUENCE_TUPLE"),
+    (180, "UNPACK_SEQUENCE_TWO_TUPLE"),
+];
+
+const PY3_12: &[(u8, &str)] = &[
+    (0, "CACHE"),
+    (1, "POP_TOP"),
+    (2, "PUSH_NULL"),
+    (3, "INTERPRETER_EXIT"),
+    (4, "END_FOR"),
+    (5, "END_SEND"),
+    (6, "BINARY_OP_ADD_FLOAT"),
+    (7, "BINARY_OP_ADD_INT"),
+    (8, "BINARY_OP_ADD_UNICODE"),
+    (9, "NOP"),
+    (10, "BINARY_OP_INPLACE_ADD_UNICODE"),
+    (11, "UNARY_NEGATIVE"),
+    (12, "UNARY_NOT"),
+    (13, "BINARY_OP_MULTIPLY_FLOAT"),
+    (14, "BINARY_OP_MULTIPLY_INT"),
+    (15, "UNARY_INVERT"),
+    (16, "BINARY_OP_SUBTRACT_FLOAT"),
+    (17, "RESERVED"),
+    (18, "BINARY_OP_SUBTRACT_INT"),
+    (19, "BINARY_SUBSCR_DICT"),
+    (20, "BINARY_SUBSCR_GETITEM"),
+    (21, "BINARY_SUBSCR_LIST_INT"),
+    (22, "BINARY_SUBSCR_TUPLE_INT"),
+    (23, "CALL_PY_EXACT_ARGS"),
+    (24, "CALL_PY_WITH_DEFAULTS"),
+    (25, "BINARY_SUBSCR"),
+    (26, "BINARY_SLICE"),
+    (27, "STORE_SLICE"),
+    (28, "CALL_BOUND_METHOD_EXACT_ARGS"),
+    (29, "CALL_BUILTIN_CLASS"),
+    (30, "GET_LEN"),
+    (31, "MATCH_MAPPING"),
+    (32, "MATCH_SEQUENCE"),
+    (33, "MATCH_KEYS"),
+    (34, "CALL_BUILTIN_FAST_WITH_KEYWORDS"),
+    (35, "PUSH_EXC_INFO"),
+    (36, "CHECK_EXC_MATCH"),
+    (37, "CHECK_EG_MATCH"),
+    (38, "CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS"),
+    (39, "CALL_NO_KW_BUILTIN_FAST"),
+    (40, "CALL_NO_KW_BUILTIN_O"),
+    (41, "CALL_NO_KW_ISINSTANCE"),
+    (42, "CALL_NO_KW_LEN"),
+    (43, "CALL_NO_KW_LIST_APPEND"),
+    (44, "CALL_NO_KW_METHOD_DESCRIPTOR_FAST"),
+    (45, "CALL_NO_KW_METHOD_DESCRIPTOR_NOARGS"),
+    (46, "CALL_NO_KW_METHOD_DESCRIPTOR_O"),
+    (47, "CALL_NO_KW_STR_1"),
+    (48, "CALL_NO_KW_TUPLE_1"),
+    (49, "WITH_EXCEPT_START"),
+    (50, "GET_AITER"),
+    (51, "GET_ANEXT"),
+    (52, "BEFORE_ASYNC_WITH"),
+    (53, "BEFORE_WITH"),
+    (54, "END_ASYNC_FOR"),
+    (55, "CLEANUP_THROW"),
+    (56, "CALL_NO_KW_TYPE_1"),
+    (57, "COMPARE_OP_FLOAT"),
+    (58, "COMPARE_OP_INT"),
+    (59, "COMPARE_OP_STR"),
+    (60, "STORE_SUBSCR"),
+    (61, "DELETE_SUBSCR"),
+    (62, "FOR_ITER_LIST"),
+    (63, "FOR_ITER_TUPLE"),
+    (64, "FOR_ITER_RANGE"),
+    (65, "FOR_ITER_GEN"),
+    (66, "LOAD_SUPER_ATTR_ATTR"),
+    (67, "LOAD_SUPER_ATTR_METHOD"),
+    (68, "GET_ITER"),
+    (69, "GET_YIELD_FROM_ITER"),
+    (70, "LOAD_ATTR_CLASS"),
+    (71, "LOAD_BUILD_CLASS"),
+    (72, "LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN"),
+    (73, "LOAD_ATTR_INSTANCE_VALUE"),
+    (74, "LOAD_ASSERTION_ERROR"),
+    (75, "RETURN_GENERATOR"),
+    (76, "LOAD_ATTR_MODULE"),
+    (77, "LOAD_ATTR_PROPERTY"),
+    (78, "LOAD_ATTR_SLOT"),
+    (79, "LOAD_ATTR_WITH_HINT"),
+    (80, "LOAD_ATTR_METHOD_LAZY_DICT"),
+    (81, "LOAD_ATTR_METHOD_NO_DICT"),
+    (82, "LOAD_ATTR_METHOD_WITH_VALUES"),
+    (83, "RETURN_VALUE"),
+    (84, "LOAD_CONST__LOAD_FAST"),
+    (85, "SETUP_ANNOTATIONS"),
+    (86, "LOAD_FAST__LOAD_CONST"),
+    (87, "LOAD_LOCALS"),
+    (88, "LOAD_FAST__LOAD_FAST"),
+    (89, "POP_EXCEPT"),
+    (90, "STORE_NAME"),
+    (91, "DELETE_NAME"),
+    (92, "UNPACK_SEQUENCE"),
+    (93, "FOR_ITER"),
+    (94, "UNPACK_EX"),
+    (95, "STORE_ATTR"),
+    (96, "DELETE_ATTR"),
+    (97, "STORE_GLOBAL"),
+    (98, "DELETE_GLOBAL"),
+    (99, "SWAP"),
+    (100, "LOAD_CONST"),
+    (101, "LOAD_NAME"),
+    (102, "BUILD_TUPLE"),
+    (103, "BUILD_LIST"),
+    (104, "BUILD_SET"),
+    (105, "BUILD_MAP"),
+    (106, "LOAD_ATTR"),
+    (107, "COMPARE_OP"),
+    (108, "IMPORT_NAME"),
+    (109, "IMPORT_FROM"),
+    (110, "JUMP_FORWARD"),
+    (111, "LOAD_GLOBAL_BUILTIN"),
+    (112, "LOAD_GLOBAL_MODULE"),
+    (113, "STORE_ATTR_INSTANCE_VALUE"),
+    (114, "POP_JUMP_IF_FALSE"),
+    (115, "POP_JUMP_IF_TRUE"),
+    (116, "LOAD_GLOBAL"),
+    (117, "IS_OP"),
+    (118, "CONTAINS_OP"),
+    (119, "RERAISE"),
+    (120, "COPY"),
+    (121, "RETURN_CONST"),
+    (122, "BINARY_OP"),
+    (123, "SEND"),
+    (124, "LOAD_FAST"),
+    (125, "STORE_FAST"),
+    (126, "DELETE_FAST"),
+    (127, "LOAD_FAST_CHECK"),
+    (128, "POP_JUMP_IF_NOT_NONE"),
+    (129, "POP_JUMP_IF_NONE"),
+    (130, "RAISE_VARARGS"),
+    (131, "GET_AWAITABLE"),
+    (132, "MAKE_FUNCTION"),
+    (133, "BUILD_SLICE"),
+    (134, "JUMP_BACKWARD_NO_INTERRUPT"),
+    (135, "MAKE_CELL"),
+    (136, "LOAD_CLOSURE"),
+    (137, "LOAD_DEREF"),
+    (138, "STORE_DEREF"),
+    (139, "DELETE_DEREF"),
+    (140, "JUMP_BACKWARD"),
+    (141, "LOAD_SUPER_ATTR"),
+    (142, "CALL_FUNCTION_EX"),
+    (143, "LOAD_FAST_AND_CLEAR"),
+    (144, "EXTENDED_ARG"),
+    (145, "LIST_APPEND"),
+    (146, "SET_ADD"),
+    (147, "MAP_ADD"),
+    (148, "STORE_ATTR_SLOT"),
+    (149, "COPY_FREE_VARS"),
+    (150, "YIELD_VALUE"),
+    (151, "RESUME"),
+    (152, "MATCH_CLASS"),
+    (153, "STORE_ATTR_WITH_HINT"),
+    (154, "STORE_FAST__LOAD_FAST"),
+    (155, "FORMAT_VALUE"),
+    (156, "BUILD_CONST_KEY_MAP"),
+    (157, "BUILD_STRING"),
+    (158, "STORE_FAST__STORE_FAST"),
+    (159, "STORE_SUBSCR_DICT"),
+    (160, "STORE_SUBSCR_LIST_INT"),
+    (161, "UNPACK_SEQUENCE_LIST"),
+    (162, "LIST_EXTEND"),
+    (163, "SET_UPDATE"),
+    (164, "DICT_MERGE"),
+    (165, "DICT_UPDATE"),
+    (166, "UNPACK_SEQUENCE_TUPLE"),
+    (167, "UNPACK_SEQUENCE_TWO_TUPLE"),
+    (168, "SEND_GEN"),
+    (171, "CALL"),
+    (172, "KW_NAMES"),
+    (173, "CALL_INTRINSIC_1"),
+    (174, "CALL_INTRINSIC_2"),
+    (175, "LOAD_FROM_DICT_OR_GLOBALS"),
+    (176, "LOAD_FROM_DICT_OR_DEREF"),
+    (237, "INSTRUMENTED_LOAD_SUPER_ATTR"),
+    (238, "INSTRUMENTED_POP_JUMP_IF_NONE"),
+    (239, "INSTRUMENTED_POP_JUMP_IF_NOT_NONE"),
+    (240, "INSTRUMENTED_RESUME"),
+    (241, "INSTRUMENTED_CALL"),
+    (242, "INSTRUMENTED_RETURN_VALUE"),
+    (243, "INSTRUMENTED_YIELD_VALUE"),
+    (244, "INSTRUMENTED_CALL_FUNCTION_EX"),
+    (245, "INSTRUMENTED_JUMP_FORWARD"),
+    (246, "INSTRUMENTED_JUMP_BACKWARD"),
+    (247, "INSTRUMENTED_RETURN_CONST"),
+    (248, "INSTRUMENTED_FOR_ITER"),
+    (249, "INSTRUMENTED_POP_JUMP_IF_FALSE"),
+    (250, "INSTRUMENTED_POP_JUMP_IF_TRUE"),
+    (251, "INSTRUMENTED_END_FOR"),
+    (252, "INSTRUMENTED_END_SEND"),
+    (253, "INSTRUMENTED_INSTRUCTION"),
+    (254, "INSTRUMENTED_LINE"),
+];
+
+const PY3_13: &[(u8, &str)] = &[
+    (0, "CACHE"),
+    (1, "BEFORE_ASYNC_WITH"),
+    (2, "BEFORE_WITH"),
+    (3, "BINARY_OP_INPLACE_ADD_UNICODE"),
+    (4, "BINARY_SLICE"),
+    (5, "BINARY_SUBSCR"),
+    (6, "CHECK_EG_MATCH"),
+    (7, "CHECK_EXC_MATCH"),
+    (8, "CLEANUP_THROW"),
+    (9, "DELETE_SUBSCR"),
+    (10, "END_ASYNC_FOR"),
+    (11, "END_FOR"),
+    (12, "END_SEND"),
+    (13, "EXIT_INIT_CHECK"),
+    (14, "FORMAT_SIMPLE"),
+    (15, "FORMAT_WITH_SPEC"),
+    (16, "GET_AITER"),
+    (17, "RESERVED"),
+    (18, "GET_ANEXT"),
+    (19, "GET_ITER"),
+    (20, "GET_LEN"),
+    (21, "GET_YIELD_FROM_ITER"),
+    (22, "INTERPRETER_EXIT"),
+    (23, "LOAD_ASSERTION_ERROR"),
+    (24, "LOAD_BUILD_CLASS"),
+    (25, "LOAD_LOCALS"),
+    (26, "MAKE_FUNCTION"),
+    (27, "MATCH_KEYS"),
+    (28, "MATCH_MAPPING"),
+    (29, "MATCH_SEQUENCE"),
+    (30, "NOP"),
+    (31, "POP_EXCEPT"),
+    (32, "POP_TOP"),
+    (33, "PUSH_EXC_INFO"),
+    (34, "PUSH_NULL"),
+    (35, "RETURN_GENERATOR"),
+    (36, "RETURN_VALUE"),
+    (37, "SETUP_ANNOTATIONS"),
+    (38, "STORE_SLICE"),
+    (39, "STORE_SUBSCR"),
+    (40, "TO_BOOL"),
+    (41, "UNARY_INVERT"),
+    (42, "UNARY_NEGATIVE"),
+    (43, "UNARY_NOT"),
+    (44, "WITH_EXCEPT_START"),
+    (45, "BINARY_OP"),
+    (46, "BUILD_CONST_KEY_MAP"),
+    (47, "BUILD_LIST"),
+    (48, "BUILD_MAP"),
+    (49, "BUILD_SET"),
+    (50, "BUILD_SLICE"),
+    (51, "BUILD_STRING"),
+    (52, "BUILD_TUPLE"),
+    (53, "CALL"),
+    (54, "CALL_FUNCTION_EX"),
+    (55, "CALL_INTRINSIC_1"),
+    (56, "CALL_INTRINSIC_2"),
+    (57, "CALL_KW"),
+    (58, "COMPARE_OP"),
+    (59, "CONTAINS_OP"),
+    (60, "CONVERT_VALUE"),
+    (61, "COPY"),
+    (62, "COPY_FREE_VARS"),
+    (63, "DELETE_ATTR"),
+    (64, "DELETE_DEREF"),
+    (65, "DELETE_FAST"),
+    (66, "DELETE_GLOBAL"),
+    (67, "DELETE_NAME"),
+    (68, "DICT_MERGE"),
+    (69, "DICT_UPDATE"),
+    (70, "ENTER_EXECUTOR"),
+    (71, "EXTENDED_ARG"),
+    (72, "FOR_ITER"),
+    (73, "GET_AWAITABLE"),
+    (74, "IMPORT_FROM"),
+    (75, "IMPORT_NAME"),
+    (76, "IS_OP"),
+    (77, "JUMP_BACKWARD"),
+    (78, "JUMP_BACKWARD_NO_INTERRUPT"),
+    (79, "JUMP_FORWARD"),
+    (80, "LIST_APPEND"),
+    (81, "LIST_EXTEND"),
+    (82, "LOAD_ATTR"),
+    (83, "LOAD_CONST"),
+    (84, "LOAD_DEREF"),
+    (85, "LOAD_FAST"),
+    (86, "LOAD_FAST_AND_CLEAR"),
+    (87, "LOAD_FAST_CHECK"),
+    (88, "LOAD_FAST_LOAD_FAST"),
+    (89, "LOAD_FROM_DICT_OR_DEREF"),
+    (90, "LOAD_FROM_DICT_OR_GLOBALS"),
+    (91, "LOAD_GLOBAL"),
+    (92, "LOAD_NAME"),
+    (93, "LOAD_SUPER_ATTR"),
+    (94, "MAKE_CELL"),
+    (95, "MAP_ADD"),
+    (96, "MATCH_CLASS"),
+    (97, "POP_JUMP_IF_FALSE"),
+    (98, "POP_JUMP_IF_NONE"),
+    (99, "POP_JUMP_IF_NOT_NONE"),
+    (100, "POP_JUMP_IF_TRUE"),
+    (101, "RAISE_VARARGS"),
+    (102, "RERAISE"),
+    (103, "RETURN_CONST"),
+    (104, "SEND"),
+    (105, "SET_ADD"),
+    (106, "SET_FUNCTION_ATTRIBUTE"),
+    (107, "SET_UPDATE"),
+    (108, "STORE_ATTR"),
+    (109, "STORE_DEREF"),
+    (110, "STORE_FAST"),
+    (111, "STORE_FAST_LOAD_FAST"),
+    (112, "STORE_FAST_STORE_FAST"),
+    (113, "STORE_GLOBAL"),
+    (114, "STORE_NAME"),
+    (115, "SWAP"),
+    (116, "UNPACK_EX"),
+    (117, "UNPACK_SEQUENCE"),
+    (118, "YIELD_VALUE"),
+    (149, "RESUME"),
+    (150, "BINARY_OP_ADD_FLOAT"),
+    (151, "BINARY_OP_ADD_INT"),
+    (152, "BINARY_OP_ADD_UNICODE"),
+    (153, "BINARY_OP_MULTIPLY_FLOAT"),
+    (154, "BINARY_OP_MULTIPLY_INT"),
+    (155, "BINARY_OP_SUBTRACT_FLOAT"),
+    (156, "BINARY_OP_SUBTRACT_INT"),
+    (157, "BINARY_SUBSCR_DICT"),
+    (158, "BINARY_SUBSCR_GETITEM"),
+    (159, "BINARY_SUBSCR_LIST_INT"),
+    (160, "BINARY_SUBSCR_STR_INT"),
+    (161, "BINARY_SUBSCR_TUPLE_INT"),
+    (162, "CALL_ALLOC_AND_ENTER_INIT"),
+    (163, "CALL_BOUND_METHOD_EXACT_ARGS"),
+    (164, "CALL_BOUND_METHOD_GENERAL"),
+    (165, "CALL_BUILTIN_CLASS"),
+    (166, "CALL_BUILTIN_FAST"),
+    (167, "CALL_BUILTIN_FAST_WITH_KEYWORDS"),
+    (168, "CALL_BUILTIN_O"),
+    (169, "CALL_ISINSTANCE"),
+    (170, "CALL_LEN"),
+    (171, "CALL_LIST_APPEND"),
+    (172, "CALL_METHOD_DESCRIPTOR_FAST"),
+    (173, "CALL_METHOD_DESCRIPTOR_FAST_WITH_KEYWORDS"),
+    (174, "CALL_METHOD_DESCRIPTOR_NOARGS"),
+    (175, "CALL_METHOD_DESCRIPTOR_O"),
+    (176, "CALL_NON_PY_GENERAL"),
+    (177, "CALL_PY_EXACT_ARGS"),
+    (178, "CALL_PY_GENERAL"),
+    (179, "CALL_STR_1"),
+    (180, "CALL_TUPLE_1"),
+    (181, "CALL_TYPE_1"),
+    (182, "COMPARE_OP_FLOAT"),
+    (183, "COMPARE_OP_INT"),
+    (184, "COMPARE_OP_STR"),
+    (185, "CONTAINS_OP_DICT"),
+    (186, "CONTAINS_OP_SET"),
+    (187, "FOR_ITER_GEN"),
+    (188, "FOR_ITER_LIST"),
+    (189, "FOR_ITER_RANGE"),
+    (190, "FOR_ITER_TUPLE"),
+    (191, "LOAD_ATTR_CLASS"),
+    (192, "LOAD_ATTR_GETATTRIBUTE_OVERRIDDEN"),
+    (193, "LOAD_ATTR_INSTANCE_VALUE"),
+    (194, "LOAD_ATTR_METHOD_LAZY_DICT"),
+    (195, "LOAD_ATTR_METHOD_NO_DICT"),
+    (196, "LOAD_ATTR_METHOD_WITH_VALUES"),
+    (197, "LOAD_ATTR_MODULE"),
+    (198, "LOAD_ATTR_NONDESCRIPTOR_NO_DICT"),
+    (199, "LOAD_ATTR_NONDESCRIPTOR_WITH_VALUES"),
+    (200, "LOAD_ATTR_PROPERTY"),
+    (201, "LOAD_ATTR_SLOT"),
+    (202, "LOAD_ATTR_WITH_HINT"),
+    (203, "LOAD_GLOBAL_BUILTIN"),
+    (204, "LOAD_GLOBAL_MODULE"),
+    (205, "LOAD_SUPER_ATTR_ATTR"),
+    (206, "LOAD_SUPER_ATTR_METHOD"),
+    (207, "RESUME_CHECK"),
+    (208, "SEND_GEN"),
+    (209, "STORE_ATTR_INSTANCE_VALUE"),
+    (210, "STORE_ATTR_SLOT"),
+    (211, "STORE_ATTR_WITH_HINT"),
+    (212, "STORE_SUBSCR_DICT"),
+    (213, "STORE_SUBSCR_LIST_INT"),
+    (214, "TO_BOOL_ALWAYS_TRUE"),
+    (215, "TO_BOOL_BOOL"),
+    (216, "TO_BOOL_INT"),
+    (217, "TO_BOOL_LIST"),
+    (218, "TO_BOOL_NONE"),
+    (219, "TO_BOOL_STR"),
+    (220, "UNPACK_SEQUENCE_LIST"),
+    (221, "UNPACK_SEQUENCE_TUPLE"),
+    (222, "UNPACK_SEQUENCE_TWO_TUPLE"),
+    (236, "INSTRUMENTED_RESUME"),
+    (237, "INSTRUMENTED_END_FOR"),
+    (238, "INSTRUMENTED_END_SEND"),
+    (239, "INSTRUMENTED_RETURN_VALUE"),
+    (240, "INSTRUMENTED_RETURN_CONST"),
+    (241, "INSTRUMENTED_YIELD_VALUE"),
+    (242, "INSTRUMENTED_LOAD_SUPER_ATTR"),
+    (243, "INSTRUMENTED_FOR_ITER"),
+    (244, "INSTRUMENTED_CALL"),
+    (245, "INSTRUMENTED_CALL_KW"),
+    (246, "INSTRUMENTED_CALL_FUNCTION_EX"),
+    (247, "INSTRUMENTED_INSTRUCTION"),
+    (248, "INSTRUMENTED_JUMP_FORWARD"),
+    (249, "INSTRUMENTED_JUMP_BACKWARD"),
+    (250, "INSTRUMENTED_POP_JUMP_IF_TRUE"),
+    (251, "INSTRUMENTED_POP_JUMP_IF_FALSE"),
+    (252, "INSTRUMENTED_POP_JUMP_IF_NONE"),
+    (253, "INSTRUMENTED_POP_JUMP_IF_NOT_NONE"),
+    (254, "INSTRUMENTED_LINE"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(major: u64, minor: u64) -> Version {
+        Version {
+            major,
+            minor,
+            patch: 0,
+            release_flags: String::new(),
+            build_metadata: None,
+            free_threaded: false,
+        }
+    }
+
+    #[test]
+    fn test_opcode_name() {
+        assert_eq!(opcode_name(&version(3, 9), 23), Some("BINARY_ADD"));
+        assert_eq!(opcode_name(&version(3, 3), 69), Some("STORE_LOCALS"));
+        assert_eq!(opcode_name(&version(3, 5), 81), Some("WITH_CLEANUP_START"));
+        assert_eq!(opcode_name(&version(3, 11), 122), Some("BINARY_OP"));
+        // specialized adaptive opcodes
+        assert_eq!(opcode_name(&version(3, 11), 5), Some("BINARY_OP_ADD_INT"));
+        assert_eq!(opcode_name(&version(3, 12), 7), Some("BINARY_OP_ADD_INT"));
+        assert_eq!(opcode_name(&version(3, 9), 0), None);
+        assert_eq!(opcode_name(&version(3, 14), 1), None);
+    }
+
+    #[test]
+    fn test_check_version() {
+        assert!(check_version(&version(2, 7)).is_ok());
+        assert!(check_version(&version(3, 13)).is_ok());
+        assert!(check_version(&version(3, 14)).is_err());
+    }
+}
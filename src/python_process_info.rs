@@ -16,11 +16,13 @@ use remoteprocess::ProcessMemory;
 
 use crate::binary_parser::{parse_binary, BinaryInfo};
 use crate::config::Config;
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
 use crate::python_bindings::{
-    pyruntime, v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0,
-    v3_9_5,
+    pyruntime, v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_13_0t, v3_3_7, v3_5_5, v3_6_6,
+    v3_7_0, v3_8_0, v3_9_5,
 };
-use crate::python_interpreters::{InterpreterState, ThreadState};
+use crate::python_debug_offsets::{check_debug_offsets, read_debug_offsets, DebugInterpreterState};
+use crate::python_interpreters::{copy_pointer, InterpreterState, ThreadState};
 use crate::stack_trace::get_stack_traces;
 use crate::version::Version;
 
@@ -295,6 +297,74 @@ impl PythonProcessInfo {
 
 /// Returns the version of python running in the process.
 pub fn get_python_version<P>(python_info: &PythonProcessInfo, process: &P) -> Result<Version, Error>
+where
+    P: ProcessMemory,
+{
+    let mut version = scan_python_version(python_info, process)?;
+
+    // python 3.13+ records whether it's a free-threaded build in the _Py_DebugOffsets at the
+    // start of _PyRuntime, which works even if we only got the version from the filename
+    if version.major == 3 && version.minor >= 13 {
+        if let Some(&addr) = python_info.get_symbol("_PyRuntime") {
+            match read_debug_offsets(process, addr as usize) {
+                Ok(debug_offsets) => {
+                    version.free_threaded = debug_offsets.free_threaded != 0;
+                    if has_debug_offsets_only(&version) {
+                        // read_debug_offsets has already refused any _Py_DebugOffsets layouts
+                        // that we don't know about
+                        warn!(
+                            "Python {} isn't fully supported, reading struct offsets from _Py_DebugOffsets",
+                            version
+                        );
+                    }
+                }
+                Err(e) if has_debug_offsets_only(&version) => {
+                    return Err(e.context(format!("Unsupported version of Python: {}", version)));
+                }
+                Err(e) => info!("Failed to read _Py_DebugOffsets: {}", e),
+            }
+        } else if has_debug_offsets_only(&version) {
+            return Err(format_err!(
+                "Unsupported version of Python: {} (failed to find _PyRuntime symbol)",
+                version
+            ));
+        }
+    }
+    Ok(version)
+}
+
+/// Returns the _Py_DebugOffsets for versions of python that we can only profile using them,
+/// or None for versions that we have bindings for
+pub fn get_debug_offsets<P>(
+    python_info: &PythonProcessInfo,
+    process: &P,
+    version: &Version,
+) -> Result<Option<_Py_DebugOffsets>, Error>
+where
+    P: ProcessMemory,
+{
+    if !has_debug_offsets_only(version) {
+        return Ok(None);
+    }
+    let addr = python_info.get_symbol("_PyRuntime").ok_or_else(|| {
+        format_err!(
+            "Unsupported version of Python: {} (failed to find _PyRuntime symbol)",
+            version
+        )
+    })?;
+    let offsets = read_debug_offsets(process, *addr as usize)
+        .and_then(|offsets| check_debug_offsets(&offsets).map(|_| offsets))
+        .with_context(|| format!("Unsupported version of Python: {}", version))?;
+    Ok(Some(offsets))
+}
+
+/// Whether this version of python is newer than any we have bindings for, and can only be
+/// profiled using the offsets in _Py_DebugOffsets
+fn has_debug_offsets_only(version: &Version) -> bool {
+    version.major == 3 && version.minor > 13
+}
+
+fn scan_python_version<P>(python_info: &PythonProcessInfo, process: &P) -> Result<Version, Error>
 where
     P: ProcessMemory,
 {
@@ -341,7 +411,9 @@ where
     if let Some(python) = path.file_name() {
         if let Some(python) = python.to_str() {
             if let Some(stripped_python) = python.strip_prefix("python") {
-                let tokens: Vec<&str> = stripped_python.split('.').collect();
+                // free-threaded builds get installed as python3.13t
+                let free_threaded = stripped_python.ends_with('t');
+                let tokens: Vec<&str> = stripped_python.trim_end_matches('t').split('.').collect();
                 if tokens.len() >= 2 {
                     if let (Ok(major), Ok(minor)) =
                         (tokens[0].parse::<u64>(), tokens[1].parse::<u64>())
@@ -352,6 +424,7 @@ where
                             patch: 0,
                             release_flags: "".to_owned(),
                             build_metadata: None,
+                            free_threaded,
                         });
                     }
                 }
@@ -366,6 +439,7 @@ where
 pub fn get_interpreter_address<P>(
     python_info: &PythonProcessInfo,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     version: &Version,
 ) -> Result<usize, Error>
 where
@@ -376,19 +450,24 @@ where
     match version {
         Version {
             major: 3,
-            minor: 13,
+            minor: 13..,
             ..
         } => {
             if let Some(&addr) = python_info.get_symbol("_PyRuntime") {
                 // figure out the interpreters_head location using the debug_offsets
-                let debug_offsets: v3_13_0::_Py_DebugOffsets =
-                    process.copy_struct(addr as usize)?;
+                let debug_offsets = read_debug_offsets(process, addr as usize)?;
                 let addr = process.copy_struct(
                     addr as usize + debug_offsets.runtime_state.interpreters_head as usize,
                 )?;
 
                 // Make sure the interpreter addr is valid before returning
-                match check_interpreter_addresses(&[addr], &*python_info.maps, process, version) {
+                match check_interpreter_addresses(
+                    &[addr],
+                    &*python_info.maps,
+                    process,
+                    offsets,
+                    version,
+                ) {
                     Ok(addr) => return Ok(addr),
                     Err(_) => {
                         warn!(
@@ -409,7 +488,13 @@ where
                     .copy_struct(addr as usize + pyruntime::get_interp_head_offset(version))?;
 
                 // Make sure the interpreter addr is valid before returning
-                match check_interpreter_addresses(&[addr], &*python_info.maps, process, version) {
+                match check_interpreter_addresses(
+                    &[addr],
+                    &*python_info.maps,
+                    process,
+                    offsets,
+                    version,
+                ) {
                     Ok(addr) => return Ok(addr),
                     Err(_) => {
                         warn!(
@@ -423,7 +508,13 @@ where
         _ => {
             if let Some(&addr) = python_info.get_symbol("interp_head") {
                 let addr = process.copy_struct(addr as usize)?;
-                match check_interpreter_addresses(&[addr], &*python_info.maps, process, version) {
+                match check_interpreter_addresses(
+                    &[addr],
+                    &*python_info.maps,
+                    process,
+                    offsets,
+                    version,
+                ) {
                     Ok(addr) => return Ok(addr),
                     Err(_) => {
                         warn!(
@@ -439,7 +530,8 @@ where
 
     // try scanning the BSS section of the binary for things that might be the interpreterstate
     let err = if let Some(ref pb) = python_info.python_binary {
-        match get_interpreter_address_from_binary(pb, &*python_info.maps, process, version) {
+        match get_interpreter_address_from_binary(pb, &*python_info.maps, process, offsets, version)
+        {
             Ok(addr) => return Ok(addr),
             err => Some(err),
         }
@@ -449,7 +541,13 @@ where
     // Before giving up, try again if there is a libpython.so
     if let Some(ref lpb) = python_info.libpython_binary {
         info!("Failed to get interpreter from binary BSS, scanning libpython BSS");
-        match get_interpreter_address_from_binary(lpb, &*python_info.maps, process, version) {
+        match get_interpreter_address_from_binary(
+            lpb,
+            &*python_info.maps,
+            process,
+            offsets,
+            version,
+        ) {
             Ok(addr) => Ok(addr),
             lib_err => err.unwrap_or(lib_err),
         }
@@ -462,6 +560,7 @@ fn get_interpreter_address_from_binary<P>(
     binary: &BinaryInfo,
     maps: &dyn ContainsAddr,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     version: &Version,
 ) -> Result<usize, Error>
 where
@@ -477,7 +576,7 @@ where
         let addrs = unsafe {
             slice::from_raw_parts(bss.as_ptr() as *const usize, bss.len() / size_of::<usize>())
         };
-        if let Ok(addr) = check_interpreter_addresses(addrs, maps, process, version) {
+        if let Ok(addr) = check_interpreter_addresses(addrs, maps, process, offsets, version) {
             return Ok(addr);
         }
     }
@@ -490,7 +589,7 @@ where
     let addrs = unsafe {
         slice::from_raw_parts(bss.as_ptr() as *const usize, bss.len() / size_of::<usize>())
     };
-    check_interpreter_addresses(addrs, maps, process, version)
+    check_interpreter_addresses(addrs, maps, process, offsets, version)
 }
 
 // Checks whether a block of memory (from BSS/.data etc) contains pointers that are pointing
@@ -499,13 +598,19 @@ fn check_interpreter_addresses<P>(
     addrs: &[usize],
     maps: &dyn ContainsAddr,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     version: &Version,
 ) -> Result<usize, Error>
 where
     P: ProcessMemory,
 {
     // This function does all the work, but needs a type of the interpreter
-    fn check<I, P>(addrs: &[usize], maps: &dyn ContainsAddr, process: &P) -> Result<usize, Error>
+    fn check<I, P>(
+        addrs: &[usize],
+        maps: &dyn ContainsAddr,
+        process: &P,
+        offsets: Option<&_Py_DebugOffsets>,
+    ) -> Result<usize, Error>
     where
         I: InterpreterState,
         P: ProcessMemory,
@@ -514,7 +619,7 @@ where
             if maps.contains_addr(addr) {
                 // get the pythreadstate pointer from the interpreter object, and if it is also
                 // a valid pointer then load it up.
-                let threadstate_ptr_ptr = I::threadstate_ptr_ptr(addr);
+                let threadstate_ptr_ptr = I::threadstate_ptr_ptr(addr, offsets);
                 let maybe_threads = process
                     .copy_struct(threadstate_ptr_ptr as usize)
                     .context("Failed to copy PyThreadState head pointer");
@@ -527,14 +632,14 @@ where
                 if maps.contains_addr(threads as usize) {
                     // If the threadstate points back to the interpreter like we expect, then
                     // this is almost certainly the address of the intrepreter
-                    let thread = match process.copy_pointer(threads) {
+                    let thread = match copy_pointer(process, threads, offsets) {
                         Ok(thread) => thread,
                         Err(_) => continue,
                     };
 
                     // as a final sanity check, try getting the stack_traces, and only return if this works
                     if thread.interp() as usize == addr
-                        && get_stack_traces::<I, P>(addr, process, 0, None).is_ok()
+                        && get_stack_traces::<I, P>(addr, process, offsets, 0, None).is_ok()
                     {
                         return Ok(addr);
                     }
@@ -552,56 +657,67 @@ where
             major: 2,
             minor: 3..=7,
             ..
-        } => check::<v2_7_15::_is, P>(addrs, maps, process),
+        } => check::<v2_7_15::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3, minor: 3, ..
-        } => check::<v3_3_7::_is, P>(addrs, maps, process),
+        } => check::<v3_3_7::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3,
             minor: 4..=5,
             ..
-        } => check::<v3_5_5::_is, P>(addrs, maps, process),
+        } => check::<v3_5_5::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3, minor: 6, ..
-        } => check::<v3_6_6::_is, P>(addrs, maps, process),
+        } => check::<v3_6_6::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3, minor: 7, ..
-        } => check::<v3_7_0::_is, P>(addrs, maps, process),
+        } => check::<v3_7_0::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3,
             minor: 8,
             patch: 0,
             ..
         } => match version.release_flags.as_ref() {
-            "a1" | "a2" | "a3" => check::<v3_7_0::_is, P>(addrs, maps, process),
-            _ => check::<v3_8_0::_is, P>(addrs, maps, process),
+            "a1" | "a2" | "a3" => check::<v3_7_0::_is, P>(addrs, maps, process, offsets),
+            _ => check::<v3_8_0::_is, P>(addrs, maps, process, offsets),
         },
         Version {
             major: 3, minor: 8, ..
-        } => check::<v3_8_0::_is, P>(addrs, maps, process),
+        } => check::<v3_8_0::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3, minor: 9, ..
-        } => check::<v3_9_5::_is, P>(addrs, maps, process),
+        } => check::<v3_9_5::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3,
             minor: 10,
             ..
-        } => check::<v3_10_0::_is, P>(addrs, maps, process),
+        } => check::<v3_10_0::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3,
             minor: 11,
             ..
-        } => check::<v3_11_0::_is, P>(addrs, maps, process),
+        } => check::<v3_11_0::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3,
             minor: 12,
             ..
-        } => check::<v3_12_0::_is, P>(addrs, maps, process),
+        } => check::<v3_12_0::_is, P>(addrs, maps, process, offsets),
         Version {
             major: 3,
             minor: 13,
+            free_threaded: true,
+            ..
+        } => check::<v3_13_0t::_is, P>(addrs, maps, process, offsets),
+        Version {
+            major: 3,
+            minor: 13,
+            ..
+        } => check::<v3_13_0::_is, P>(addrs, maps, process, offsets),
+        Version {
+            major: 3,
+            minor: 14..,
             ..
-        } => check::<v3_13_0::_is, P>(addrs, maps, process),
+        } => check::<DebugInterpreterState, P>(addrs, maps, process, offsets),
         _ => Err(format_err!("Unsupported version of Python: {}", version)),
     }
 }
@@ -610,6 +726,7 @@ pub fn get_threadstate_address<P>(
     interpreter_address: usize,
     python_info: &PythonProcessInfo,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     version: &Version,
     config: &Config,
 ) -> Result<usize, Error>
@@ -617,6 +734,27 @@ where
     P: ProcessMemory,
 {
     let threadstate_address = match version {
+        Version {
+            major: 3,
+            minor: 14..,
+            ..
+        } => {
+            error_if_free_threaded(config, version)?;
+            match DebugInterpreterState::gil_ptr_ptr(interpreter_address, offsets) {
+                Some(gil_ptr) => process.copy_struct::<usize>(gil_ptr as usize)?,
+                None => 0,
+            }
+        }
+        Version {
+            major: 3,
+            minor: 13,
+            free_threaded: true,
+            ..
+        } => {
+            error_if_free_threaded(config, version)?;
+            let gil_ptr = interpreter_address + std::mem::offset_of!(v3_13_0t::_is, ceval.gil);
+            process.copy_struct::<usize>(gil_ptr)?
+        }
         Version {
             major: 3,
             minor: 13,
@@ -678,6 +816,18 @@ where
     Ok(threadstate_address)
 }
 
+/// The GIL is disabled by default in free-threaded builds, so there usually isn't a thread
+/// holding it to filter on
+fn error_if_free_threaded(config: &Config, version: &Version) -> Result<(), Error> {
+    if config.gil_only && version.free_threaded {
+        return Err(format_err!(
+            "Python {} is a free-threaded build, which doesn't need to hold the GIL to run. The --gil option isn't supported for free-threaded builds",
+            version
+        ));
+    }
+    Ok(())
+}
+
 fn error_if_gil(config: &Config, version: &Version, msg: &str) -> Result<(), Error> {
     lazy_static! {
         static ref WARNED: std::sync::atomic::AtomicBool =
@@ -767,7 +917,7 @@ pub fn get_windows_python_symbols(
 #[cfg(any(target_os = "linux", target_os = "freebsd"))]
 pub fn is_python_lib(pathname: &str) -> bool {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r"/libpython\d.\d\d?(m|d|u)?.so").unwrap();
+        static ref RE: Regex = Regex::new(r"/libpython\d.\d\d?(m|d|u|t|td)?.so").unwrap();
     }
     RE.is_match(pathname)
 }
@@ -775,7 +925,7 @@ pub fn is_python_lib(pathname: &str) -> bool {
 #[cfg(target_os = "macos")]
 pub fn is_python_lib(pathname: &str) -> bool {
     lazy_static! {
-        static ref RE: Regex = Regex::new(r"/libpython\d.\d\d?(m|d|u)?.(dylib|so)$").unwrap();
+        static ref RE: Regex = Regex::new(r"/libpython\d.\d\d?(m|d|u|t|td)?.(dylib|so)$").unwrap();
     }
     RE.is_match(pathname) || is_python_framework(pathname)
 }
@@ -783,7 +933,7 @@ pub fn is_python_lib(pathname: &str) -> bool {
 #[cfg(windows)]
 pub fn is_python_lib(pathname: &str) -> bool {
     lazy_static! {
-        static ref RE: Regex = RegexBuilder::new(r"\\python\d\d\d?(m|d|u)?.dll$")
+        static ref RE: Regex = RegexBuilder::new(r"\\python\d\d\d?(m|d|u|t|td)?.dll$")
             .case_insensitive(true)
             .build()
             .unwrap();
@@ -814,6 +964,9 @@ mod tests {
         // python2 configured with --with-wide-unicode (flag: u)
         assert!(is_python_lib("./libpython2.7u.dylib"));
 
+        // free-threaded builds configured with --disable-gil (flag: t)
+        assert!(is_python_lib("/usr/local/lib/libpython3.13t.dylib"));
+
         assert!(!is_python_lib("/libboost_python.dylib"));
         assert!(!is_python_lib("/lib/heapq.cpython-36m-darwin.dylib"));
     }
@@ -829,6 +982,8 @@ mod tests {
         assert!(is_python_lib("/usr/lib/libpython3.4d.so"));
         assert!(is_python_lib("/usr/local/lib/libpython3.8m.so"));
         assert!(is_python_lib("/usr/lib/libpython2.7u.so"));
+        assert!(is_python_lib("/usr/lib/libpython3.13t.so.1.0"));
+        assert!(is_python_lib("/usr/lib/libpython3.13td.so"));
 
         // don't blindly match libraries with python in the name (boost_python etc)
         assert!(!is_python_lib("/usr/lib/libboost_python.so"));
@@ -1,9 +1,10 @@
 use std::collections::HashMap;
-#[cfg(all(target_os = "linux", feature = "unwind"))]
+#[cfg(target_os = "linux")]
 use std::collections::HashSet;
 #[cfg(all(target_os = "linux", feature = "unwind"))]
 use std::iter::FromIterator;
 use std::path::Path;
+use std::time::Instant;
 
 use anyhow::{Context, Error, Result};
 use remoteprocess::{Pid, Process, ProcessMemory, Tid};
@@ -11,16 +12,25 @@ use remoteprocess::{Pid, Process, ProcessMemory, Tid};
 use crate::config::{Config, LockingStrategy};
 #[cfg(feature = "unwind")]
 use crate::native_stack_trace::NativeStack;
+use crate::python_asyncio::{self, attribute_to_task, AsyncTask, AsyncioTasks};
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
 use crate::python_bindings::{
-    v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0, v3_9_5,
+    v2_7_15, v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_13_0t, v3_3_7, v3_5_5, v3_6_6, v3_7_0, v3_8_0,
+    v3_9_5,
 };
 use crate::python_data_access::format_variable;
-use crate::python_interpreters::{InterpreterState, ThreadState};
+use crate::python_debug_offsets::DebugInterpreterState;
+use crate::python_interpreters::{copy_pointer, InterpreterState, ThreadState};
+use crate::python_labels::{read_labels, LabelReader};
+use crate::python_opcodes::{self, opcode_name};
 use crate::python_process_info::{
-    get_interpreter_address, get_python_version, get_threadstate_address, PythonProcessInfo,
+    get_debug_offsets, get_interpreter_address, get_python_version, get_threadstate_address,
+    PythonProcessInfo,
 };
 use crate::python_threading::thread_name_lookup;
-use crate::stack_trace::{get_gil_threadid, get_stack_trace, StackTrace};
+use crate::stack_trace::{
+    get_interpreter_gil_threadid, get_interpreters, get_stack_trace, Interpreter, StackTrace,
+};
 use crate::version::Version;
 
 /// Lets you retrieve stack traces of a running python program
@@ -28,6 +38,7 @@ pub struct PythonSpy {
     pub pid: Pid,
     pub process: Process,
     pub version: Version,
+    pub debug_offsets: Option<_Py_DebugOffsets>,
     pub interpreter_address: usize,
     pub threadstate_address: usize,
     pub config: Config,
@@ -36,8 +47,14 @@ pub struct PythonSpy {
     pub short_filenames: HashMap<String, Option<String>>,
     pub python_thread_ids: HashMap<u64, Tid>,
     pub python_thread_names: HashMap<u64, String>,
+    pub asyncio_tasks: HashMap<usize, AsyncioTasks>,
+    pub asyncio_not_found: HashMap<usize, Instant>,
+    pub label_readers: HashMap<usize, LabelReader>,
+    pub arg_names: HashMap<usize, String>,
     #[cfg(target_os = "linux")]
     pub dockerized: bool,
+    #[cfg(target_os = "linux")]
+    pub thread_cpu_ticks: HashMap<Tid, u64>,
 }
 
 impl PythonSpy {
@@ -58,7 +75,18 @@ impl PythonSpy {
         let version = get_python_version(&python_info, &process)?;
         info!("python version {} detected", version);
 
-        let interpreter_address = get_interpreter_address(&python_info, &process, &version)?;
+        if config.async_tasks {
+            python_asyncio::check_version(&version)?;
+        }
+
+        if config.instructions {
+            python_opcodes::check_version(&version)?;
+        }
+
+        let debug_offsets = get_debug_offsets(&python_info, &process, &version)?;
+
+        let interpreter_address =
+            get_interpreter_address(&python_info, &process, debug_offsets.as_ref(), &version)?;
         info!("Found interpreter at 0x{:016x}", interpreter_address);
 
         // lets us figure out which thread has the GIL
@@ -66,6 +94,7 @@ impl PythonSpy {
             interpreter_address,
             &python_info,
             &process,
+            debug_offsets.as_ref(),
             &version,
             config,
         )?;
@@ -85,16 +114,23 @@ impl PythonSpy {
             pid,
             process,
             version,
+            debug_offsets,
             interpreter_address,
             threadstate_address,
             #[cfg(feature = "unwind")]
             native,
             #[cfg(target_os = "linux")]
             dockerized: python_info.dockerized,
+            #[cfg(target_os = "linux")]
+            thread_cpu_ticks: HashMap::new(),
             config: config.clone(),
             short_filenames: HashMap::new(),
             python_thread_ids: HashMap::new(),
             python_thread_names: HashMap::new(),
+            asyncio_tasks: HashMap::new(),
+            asyncio_not_found: HashMap::new(),
+            label_readers: HashMap::new(),
+            arg_names: HashMap::new(),
         })
     }
 
@@ -181,11 +217,22 @@ impl PythonSpy {
                 minor: 12,
                 ..
             } => self._get_stack_traces::<v3_12_0::_is>(),
+            Version {
+                major: 3,
+                minor: 13,
+                free_threaded: true,
+                ..
+            } => self._get_stack_traces::<v3_13_0t::_is>(),
             Version {
                 major: 3,
                 minor: 13,
                 ..
             } => self._get_stack_traces::<v3_13_0::_is>(),
+            Version {
+                major: 3,
+                minor: 14..,
+                ..
+            } => self._get_stack_traces::<DebugInterpreterState>(),
             _ => Err(format_err!(
                 "Unsupported version of Python: {}",
                 self.version
@@ -222,134 +269,359 @@ impl PythonSpy {
             None
         };
 
-        // Find PyThreadState, and loop over all the python threads
-        let threadstate_ptr_ptr = I::threadstate_ptr_ptr(self.interpreter_address);
-        let threads_head = self
-            .process
-            .copy_pointer(threadstate_ptr_ptr)
-            .context("Failed to copy PyThreadState head pointer")?;
+        // Find all the interpreters, and the head of the PyThreadState list for each of them
+        let interpreters = get_interpreters::<I, Process>(
+            self.interpreter_address,
+            &self.process,
+            self.debug_offsets.as_ref(),
+        )
+        .context("Failed to get interpreters")?;
+        let mut threads_heads = Vec::with_capacity(interpreters.len());
+        for interpreter in &interpreters {
+            let threadstate_ptr_ptr =
+                I::threadstate_ptr_ptr(interpreter.address, self.debug_offsets.as_ref());
+            let threads_head = self
+                .process
+                .copy_pointer(threadstate_ptr_ptr)
+                .context("Failed to copy PyThreadState head pointer")?;
+            threads_heads.push(threads_head);
+        }
 
-        // get the threadid of the gil if appropriate
-        let gil_thread_id = get_gil_threadid::<I, Process>(self.threadstate_address, &self.process)
-            .context("failed to get gil_thread_id")?;
+        // get the asyncio tasks that are currently running, so that we can attribute each
+        // thread's stack trace to its task. Failing here shouldn't stop us from sampling
+        let running_tasks = if self.config.async_tasks {
+            self._get_async_tasks::<I>(&interpreters, true)
+                .unwrap_or_else(|e| {
+                    info!("Failed to get running asyncio tasks: {}", e);
+                    Vec::new()
+                })
+        } else {
+            Vec::new()
+        };
 
         let mut traces = Vec::new();
-        let mut threads = threads_head;
-        while !threads.is_null() {
-            // Get the stack trace of the python thread
-            let thread = self
-                .process
-                .copy_pointer(threads)
-                .context("Failed to copy PyThreadState")?;
-            threads = thread.next();
-
-            let python_thread_id = thread.thread_id();
-            let owns_gil = python_thread_id == gil_thread_id;
+        for (interpreter, &threads_head) in interpreters.iter().zip(threads_heads.iter()) {
+            // get the threadid of the gil if appropriate
+            let gil_thread_id = get_interpreter_gil_threadid::<I, Process>(
+                interpreter.address,
+                self.threadstate_address,
+                &self.process,
+                self.debug_offsets.as_ref(),
+            )
+            .context("failed to get gil_thread_id")?;
 
-            if self.config.gil_only && !owns_gil {
-                continue;
-            }
+            // find where to read each --label value from. Like the asyncio tasks, failing
+            // to read the labels shouldn't stop us from sampling
+            let label_reader = if self.config.labels.is_empty() {
+                None
+            } else {
+                let reader = self
+                    .label_readers
+                    .entry(interpreter.address)
+                    .or_insert_with(|| LabelReader::new(&self.config.labels));
+                if let Err(e) = reader.resolve::<I, Process>(
+                    interpreter.address,
+                    &self.process,
+                    self.debug_offsets.as_ref(),
+                    &self.version,
+                ) {
+                    info!("Failed to find modules for labels: {}", e);
+                }
+                Some(&*reader)
+            };
+            let label_sources = label_reader
+                .map(|reader| {
+                    reader.sources::<I, Process>(
+                        &self.process,
+                        self.debug_offsets.as_ref(),
+                        &self.version,
+                    )
+                })
+                .unwrap_or_default();
+
+            // loop over all the python threads in this interpreter
+            let mut threads = threads_head;
+            while !threads.is_null() {
+                // Get the stack trace of the python thread
+                let thread = copy_pointer(&self.process, threads, self.debug_offsets.as_ref())
+                    .context("Failed to copy PyThreadState")?;
+                threads = thread.next();
+
+                let python_thread_id = thread.thread_id();
+                let owns_gil = python_thread_id == gil_thread_id;
+
+                if self.config.gil_only && !owns_gil {
+                    continue;
+                }
 
-            let mut trace = get_stack_trace(
-                &thread,
-                &self.process,
-                self.config.dump_locals > 0,
-                self.config.lineno,
-            )?;
-
-            // Try getting the native thread id
-
-            // python 3.11+ has the native thread id directly on the PyThreadState object,
-            // for older versions of python, try using OS specific code to get the native
-            // thread id (doesn't work on freebsd, or on arm/i686 processors on linux)
-            if trace.os_thread_id.is_none() {
-                let mut os_thread_id =
-                    self._get_os_thread_id::<I>(python_thread_id, threads_head)?;
-
-                // linux can see issues where pthread_ids get recycled for new OS threads,
-                // which totally breaks the caching we were doing here. Detect this and retry
-                if let Some(tid) = os_thread_id {
-                    if !thread_activity.is_empty() && !thread_activity.contains_key(&tid) {
-                        info!("clearing away thread id caches, thread {} has exited", tid);
-                        self.python_thread_ids.clear();
-                        self.python_thread_names.clear();
-                        os_thread_id =
-                            self._get_os_thread_id::<I>(python_thread_id, threads_head)?;
-                    }
+                let mut trace = get_stack_trace(
+                    &thread,
+                    &self.process,
+                    self.debug_offsets.as_ref(),
+                    self.config.dump_locals > 0,
+                    self.config.lineno,
+                    self.config.qualified_names,
+                    self.config.instructions,
+                    &mut self.arg_names,
+                )?;
+
+                if !label_sources.is_empty() {
+                    trace.labels = read_labels::<I, Process>(
+                        &label_sources,
+                        &thread,
+                        &self.process,
+                        self.debug_offsets.as_ref(),
+                        &self.version,
+                    );
                 }
 
-                trace.os_thread_id = os_thread_id.map(|id| id as u64);
-            }
+                // Try getting the native thread id
+
+                // python 3.11+ has the native thread id directly on the PyThreadState object,
+                // for older versions of python, try using OS specific code to get the native
+                // thread id (doesn't work on freebsd, or on arm/i686 processors on linux)
+                if trace.os_thread_id.is_none() {
+                    let mut os_thread_id =
+                        self._get_os_thread_id::<I>(python_thread_id, &threads_heads)?;
+
+                    // linux can see issues where pthread_ids get recycled for new OS threads,
+                    // which totally breaks the caching we were doing here. Detect this and retry
+                    if let Some(tid) = os_thread_id {
+                        if !thread_activity.is_empty() && !thread_activity.contains_key(&tid) {
+                            info!("clearing away thread id caches, thread {} has exited", tid);
+                            self.python_thread_ids.clear();
+                            self.python_thread_names.clear();
+                            os_thread_id =
+                                self._get_os_thread_id::<I>(python_thread_id, &threads_heads)?;
+                        }
+                    }
 
-            trace.thread_name = self._get_python_thread_name(python_thread_id);
-            trace.owns_gil = owns_gil;
-            trace.pid = self.process.pid;
+                    trace.os_thread_id = os_thread_id.map(|id| id as u64);
+                }
 
-            // Figure out if the thread is sleeping from the OS if possible
-            trace.active = true;
-            if let Some(id) = trace.os_thread_id {
-                let id = id as Tid;
-                if let Some(active) = thread_activity.get(&id as _) {
-                    trace.active = *active;
+                trace.thread_name = self._get_python_thread_name(python_thread_id);
+                trace.owns_gil = owns_gil;
+                trace.interpreter_id = Some(interpreter.id);
+                trace.pid = self.process.pid;
+
+                // Figure out if the thread is sleeping from the OS if possible
+                trace.active = true;
+                if let Some(id) = trace.os_thread_id {
+                    let id = id as Tid;
+                    if let Some(active) = thread_activity.get(&id as _) {
+                        trace.active = *active;
+                    }
                 }
-            }
 
-            // fallback to using a heuristic if we think the thread is still active
-            // Note that on linux the OS thread activity can only be gotten on x86_64
-            // processors and even then seems to be wrong occasionally in thinking 'select'
-            // calls are active (which seems related to the thread locking code,
-            // this problem doesn't seem to happen with the --nonblocking option)
-            // Note: this should be done before the native merging for correct results
-            if trace.active {
-                trace.active = !self._heuristic_is_thread_idle(&trace);
-            }
+                // fallback to using a heuristic if we think the thread is still active
+                // Note that on linux the OS thread activity can only be gotten on x86_64
+                // processors and even then seems to be wrong occasionally in thinking 'select'
+                // calls are active (which seems related to the thread locking code,
+                // this problem doesn't seem to happen with the --nonblocking option)
+                // Note: this should be done before the native merging for correct results
+                if trace.active {
+                    trace.active = !self._heuristic_is_thread_idle(&trace);
+                }
 
-            // Merge in the native stack frames if necessary
-            #[cfg(feature = "unwind")]
-            {
-                if self.config.native {
-                    if let Some(native) = self.native.as_mut() {
-                        let thread_id = trace
+                #[cfg(target_os = "linux")]
+                {
+                    if self.config.cpu_time {
+                        trace.cpu_time = trace
                             .os_thread_id
-                            .ok_or_else(|| format_err!("failed to get os threadid"))?;
-                        let os_thread = remoteprocess::Thread::new(thread_id as Tid)?;
-                        trace.frames = native.merge_native_thread(&trace.frames, &os_thread)?
+                            .and_then(|id| self._get_thread_cpu_time(id as Tid));
                     }
                 }
-            }
 
-            for frame in &mut trace.frames {
-                frame.short_filename = self.shorten_filename(&frame.filename);
-                if let Some(locals) = frame.locals.as_mut() {
-                    let max_length = (128 * self.config.dump_locals) as isize;
-                    for local in locals {
-                        let repr = format_variable::<I, Process>(
-                            &self.process,
-                            &self.version,
-                            local.addr,
-                            max_length,
-                        );
-                        local.repr = Some(repr.unwrap_or_else(|_| "?".to_owned()));
+                // Merge in the native stack frames if necessary
+                #[cfg(feature = "unwind")]
+                {
+                    if self.config.native {
+                        if let Some(native) = self.native.as_mut() {
+                            let thread_id = trace
+                                .os_thread_id
+                                .ok_or_else(|| format_err!("failed to get os threadid"))?;
+                            let os_thread = remoteprocess::Thread::new(thread_id as Tid)?;
+                            trace.frames = native.merge_native_thread(&trace.frames, &os_thread)?
+                        }
                     }
                 }
-            }
 
-            traces.push(trace);
+                if !running_tasks.is_empty() {
+                    attribute_to_task(&mut trace, &running_tasks);
+                }
 
-            // This seems to happen occasionally when scanning BSS addresses for valid interpreters
-            if traces.len() > 4096 {
-                return Err(format_err!("Max thread recursion depth reached"));
+                for frame in &mut trace.frames {
+                    frame.short_filename = self.shorten_filename(&frame.filename);
+                    if let Some(instruction) = frame.instruction.as_mut() {
+                        instruction.name = opcode_name(&self.version, instruction.opcode);
+                    }
+                    if let Some(locals) = frame.locals.as_mut() {
+                        let max_length = (128 * self.config.dump_locals) as isize;
+                        for local in locals {
+                            let repr = format_variable::<I, Process>(
+                                &self.process,
+                                self.debug_offsets.as_ref(),
+                                &self.version,
+                                local.addr,
+                                max_length,
+                            );
+                            local.repr = Some(repr.unwrap_or_else(|_| "?".to_owned()));
+                        }
+                    }
+                }
+
+                traces.push(trace);
+
+                // This seems to happen occasionally when scanning BSS addresses for valid interpreters
+                if traces.len() > 4096 {
+                    return Err(format_err!("Max thread recursion depth reached"));
+                }
+
+                if self.config.gil_only {
+                    // There's only one GIL thread per interpreter and we've captured it,
+                    // so we can move on to the next interpreter
+                    break;
+                }
             }
+        }
 
-            if self.config.gil_only {
-                // There's only one GIL thread and we've captured it, so we can
-                // stop now
-                break;
+        // Only look for exited threads once we're tracking more threads than were sampled
+        #[cfg(target_os = "linux")]
+        if self.config.cpu_time {
+            let sampled: HashSet<u64> = traces.iter().filter_map(|t| t.os_thread_id).collect();
+            if self.thread_cpu_ticks.len() > sampled.len() {
+                self._prune_thread_cpu_ticks();
             }
         }
         Ok(traces)
     }
 
+    /// Gets all the asyncio tasks in the process, along with the logical stack of each task
+    pub fn get_async_tasks(&mut self) -> Result<Vec<AsyncTask>, Error> {
+        python_asyncio::check_version(&self.version)?;
+        match self.version {
+            Version {
+                major: 3, minor: 7, ..
+            } => self._get_all_async_tasks::<v3_7_0::_is>(),
+            Version {
+                major: 3, minor: 8, ..
+            } => self._get_all_async_tasks::<v3_8_0::_is>(),
+            Version {
+                major: 3, minor: 9, ..
+            } => self._get_all_async_tasks::<v3_9_5::_is>(),
+            Version {
+                major: 3,
+                minor: 10,
+                ..
+            } => self._get_all_async_tasks::<v3_10_0::_is>(),
+            Version {
+                major: 3,
+                minor: 11,
+                ..
+            } => self._get_all_async_tasks::<v3_11_0::_is>(),
+            Version {
+                major: 3,
+                minor: 12,
+                ..
+            } => self._get_all_async_tasks::<v3_12_0::_is>(),
+            Version {
+                major: 3,
+                minor: 13,
+                free_threaded: true,
+                ..
+            } => self._get_all_async_tasks::<v3_13_0t::_is>(),
+            Version {
+                major: 3,
+                minor: 13,
+                ..
+            } => self._get_all_async_tasks::<v3_13_0::_is>(),
+            _ => unreachable!("unsupported versions are refused by python_asyncio::check_version"),
+        }
+    }
+
+    fn _get_all_async_tasks<I: InterpreterState>(&mut self) -> Result<Vec<AsyncTask>, Error> {
+        let interpreters = get_interpreters::<I, Process>(
+            self.interpreter_address,
+            &self.process,
+            self.debug_offsets.as_ref(),
+        )
+        .context("Failed to get interpreters")?;
+        let _lock = if self.config.blocking == LockingStrategy::Lock {
+            Some(self.process.lock().context("Failed to suspend process")?)
+        } else {
+            None
+        };
+        self._get_async_tasks::<I>(&interpreters, false)
+    }
+
+    fn _get_async_tasks<I: InterpreterState>(
+        &mut self,
+        interpreters: &[Interpreter],
+        running_only: bool,
+    ) -> Result<Vec<AsyncTask>, Error> {
+        let mut tasks = Vec::new();
+        for interpreter in interpreters {
+            // the location of the asyncio task bookkeeping doesn't change once the module
+            // has been imported, so cache it rather than searching the modules dict each time.
+            // Interpreters that haven't imported asyncio are searched again every FIND_INTERVAL
+            let asyncio_tasks = match self.asyncio_tasks.get(&interpreter.address) {
+                Some(asyncio_tasks) => *asyncio_tasks,
+                None if self
+                    .asyncio_not_found
+                    .get(&interpreter.address)
+                    .is_some_and(|last| last.elapsed() < python_asyncio::FIND_INTERVAL) =>
+                {
+                    continue
+                }
+                None => match AsyncioTasks::find::<I, Process>(
+                    interpreter.address,
+                    &self.process,
+                    self.debug_offsets.as_ref(),
+                    &self.version,
+                )? {
+                    Some(asyncio_tasks) => {
+                        self.asyncio_tasks
+                            .insert(interpreter.address, asyncio_tasks);
+                        asyncio_tasks
+                    }
+                    // asyncio hasn't been imported in this interpreter
+                    None => {
+                        self.asyncio_not_found
+                            .insert(interpreter.address, Instant::now());
+                        continue;
+                    }
+                },
+            };
+
+            if running_only {
+                tasks.extend(asyncio_tasks.running_tasks::<I, Process>(
+                    &self.process,
+                    self.debug_offsets.as_ref(),
+                    &self.version,
+                    self.config.lineno,
+                    self.config.qualified_names,
+                    &mut self.arg_names,
+                )?);
+            } else {
+                tasks.extend(asyncio_tasks.all_tasks::<I, Process>(
+                    &self.process,
+                    self.debug_offsets.as_ref(),
+                    &self.version,
+                    self.config.lineno,
+                    self.config.qualified_names,
+                    &mut self.arg_names,
+                )?);
+            }
+        }
+
+        for task in &mut tasks {
+            for frame in &mut task.frames {
+                frame.short_filename = self.shorten_filename(&frame.filename);
+            }
+        }
+        Ok(tasks)
+    }
+
     // heuristic fallback for determining if a thread is active, used
     // when we don't have the ability to get the thread information from the OS
     fn _heuristic_is_thread_idle(&self, trace: &StackTrace) -> bool {
@@ -374,7 +646,7 @@ impl PythonSpy {
     fn _get_os_thread_id<I: InterpreterState>(
         &mut self,
         python_thread_id: u64,
-        _interp_head: *const I::ThreadState,
+        _threads_heads: &[*const I::ThreadState],
     ) -> Result<Option<Tid>, Error> {
         Ok(Some(python_thread_id as Tid))
     }
@@ -383,7 +655,7 @@ impl PythonSpy {
     fn _get_os_thread_id<I: InterpreterState>(
         &mut self,
         python_thread_id: u64,
-        _interp_head: *const I::ThreadState,
+        _threads_heads: &[*const I::ThreadState],
     ) -> Result<Option<Tid>, Error> {
         // If we've already know this threadid, we're good
         if let Some(thread_id) = self.python_thread_ids.get(&python_thread_id) {
@@ -407,7 +679,7 @@ impl PythonSpy {
     fn _get_os_thread_id<I: InterpreterState>(
         &mut self,
         _python_thread_id: u64,
-        _interp_head: *const I::ThreadState,
+        _threads_heads: &[*const I::ThreadState],
     ) -> Result<Option<Tid>, Error> {
         Ok(None)
     }
@@ -416,7 +688,7 @@ impl PythonSpy {
     fn _get_os_thread_id<I: InterpreterState>(
         &mut self,
         python_thread_id: u64,
-        interp_head: *const I::ThreadState,
+        threads_heads: &[*const I::ThreadState],
     ) -> Result<Option<Tid>, Error> {
         // in nonblocking mode, we can't get the threadid reliably (method here requires reading the RBX
         // register which requires a ptrace attach). fallback to heuristic thread activity here
@@ -434,17 +706,17 @@ impl PythonSpy {
             return Ok(Some(*thread_id));
         }
 
-        // Get a list of all the python thread ids
+        // Get a list of all the python thread ids, across all interpreters
         let mut all_python_threads = HashSet::new();
-        let mut threads = interp_head;
-        while !threads.is_null() {
-            let thread = self
-                .process
-                .copy_pointer(threads)
-                .context("Failed to copy PyThreadState")?;
-            let current = thread.thread_id();
-            all_python_threads.insert(current);
-            threads = thread.next();
+        for &threads_head in threads_heads {
+            let mut threads = threads_head;
+            while !threads.is_null() {
+                let thread = copy_pointer(&self.process, threads, self.debug_offsets.as_ref())
+                    .context("Failed to copy PyThreadState")?;
+                let current = thread.thread_id();
+                all_python_threads.insert(current);
+                threads = thread.next();
+            }
         }
 
         let processed_os_threads: HashSet<Tid> =
@@ -529,11 +801,36 @@ impl PythonSpy {
     fn _get_os_thread_id<I: InterpreterState>(
         &mut self,
         _python_thread_id: u64,
-        _interp_head: *const I::ThreadState,
+        _threads_heads: &[*const I::ThreadState],
     ) -> Result<Option<Tid>, Error> {
         Ok(None)
     }
 
+    /// Returns the CPU time in microseconds that an OS thread has used since the last time this
+    /// was called for it, or 0 the first time that a thread is seen
+    #[cfg(target_os = "linux")]
+    fn _get_thread_cpu_time(&mut self, tid: Tid) -> Option<u64> {
+        let stat = std::fs::read_to_string(format!("/proc/{}/task/{}/stat", self.pid, tid)).ok()?;
+        let ticks = parse_cpu_ticks(&stat)?;
+        let previous = self.thread_cpu_ticks.insert(tid, ticks).unwrap_or(ticks);
+        let ticks_per_second = unsafe { libc::sysconf(libc::_SC_CLK_TCK) }.max(1) as u64;
+        Some(ticks.saturating_sub(previous) * 1_000_000 / ticks_per_second)
+    }
+
+    /// Forgets the CPU time of OS threads that have exited, so that the map doesn't keep
+    /// growing in processes that start lots of short lived threads
+    #[cfg(target_os = "linux")]
+    fn _prune_thread_cpu_ticks(&mut self) {
+        let tasks = match std::fs::read_dir(format!("/proc/{}/task", self.pid)) {
+            Ok(tasks) => tasks,
+            Err(_) => return,
+        };
+        let live: HashSet<Tid> = tasks
+            .filter_map(|task| task.ok()?.file_name().to_str()?.parse().ok())
+            .collect();
+        self.thread_cpu_ticks.retain(|tid, _| live.contains(tid));
+    }
+
     fn _get_python_thread_name(&mut self, python_thread_id: u64) -> Option<String> {
         match self.python_thread_names.get(&python_thread_id) {
             Some(thread_name) => Some(thread_name.clone()),
@@ -594,3 +891,30 @@ impl PythonSpy {
         shortened
     }
 }
+
+/// Parses the total user and system CPU time of a thread in clock ticks, from the contents of
+/// its /proc/<pid>/task/<tid>/stat file
+#[cfg(target_os = "linux")]
+fn parse_cpu_ticks(stat: &str) -> Option<u64> {
+    // the thread name can contain spaces and parentheses, so skip past the last ')'
+    let (_, fields) = stat.rsplit_once(')')?;
+    let fields: Vec<&str> = fields.split_whitespace().collect();
+    // utime and stime are the 14th and 15th fields, and the fields here start at the 3rd
+    let utime: u64 = fields.get(11)?.parse().ok()?;
+    let stime: u64 = fields.get(12)?.parse().ok()?;
+    Some(utime + stime)
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cpu_ticks() {
+        let stat = "1234 (python (worker) 1) S 1 1234 1234 0 -1 4194560 2317 0 0 0 150 25 0 0 \
+                    20 0 3 0 3125 26365952 2567 18446744073709551615";
+        assert_eq!(parse_cpu_ticks(stat), Some(175));
+        assert_eq!(parse_cpu_ticks("1234 (python) S 1"), None);
+        assert_eq!(parse_cpu_ticks("garbage"), None);
+    }
+}
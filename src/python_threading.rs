@@ -2,10 +2,15 @@ use std::collections::HashMap;
 
 use anyhow::{Context, Error};
 
-use crate::python_bindings::{v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_6_6, v3_7_0, v3_8_0, v3_9_5};
-use crate::python_data_access::{copy_long, copy_string, DictIterator, PY_TPFLAGS_MANAGED_DICT};
-use crate::python_interpreters::{InterpreterState, Object, TypeObject};
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
+use crate::python_bindings::{
+    v3_10_0, v3_11_0, v3_12_0, v3_13_0, v3_13_0t, v3_6_6, v3_7_0, v3_8_0, v3_9_5,
+};
+use crate::python_data_access::{copy_long, copy_string, instance_dict_iterator, DictIterator};
+use crate::python_debug_offsets::DebugInterpreterState;
+use crate::python_interpreters::InterpreterState;
 use crate::python_spy::PythonSpy;
+use crate::stack_trace::get_interpreters;
 use remoteprocess::Process;
 
 use crate::version::Version;
@@ -17,9 +22,10 @@ use remoteprocess::ProcessMemory;
 pub fn thread_names_from_interpreter<I: InterpreterState, P: ProcessMemory>(
     interpreter_address: usize,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     version: &Version,
 ) -> Result<HashMap<u64, String>, Error> {
-    let modules_ptr_ptr = I::modules_ptr_ptr(interpreter_address);
+    let modules_ptr_ptr = I::modules_ptr_ptr(interpreter_address, offsets);
     let modules: *const I::Object = process
         .copy_pointer(modules_ptr_ptr)
         .context("Failed to copy modules PyObject")?;
@@ -27,44 +33,23 @@ pub fn thread_names_from_interpreter<I: InterpreterState, P: ProcessMemory>(
     let mut ret = HashMap::new();
     for entry in DictIterator::from(process, version, modules as usize)? {
         let (key, value) = entry?;
-        let module_name = copy_string(key as *const I::StringObject, process)?;
+        let module_name = copy_string(key as *const I::StringObject, process, offsets)?;
         if module_name == "threading" {
-            let module: I::Object = process.copy_struct(value)?;
-            let module_type = process.copy_pointer(module.ob_type())?;
-            let dictptr: usize = process.copy_struct(value + module_type.dictoffset() as usize)?;
-            for i in DictIterator::from(process, version, dictptr)? {
+            for i in instance_dict_iterator::<I, P>(process, offsets, version, value)? {
                 let (key, value) = i?;
-                let name = copy_string(key as *const I::StringObject, process)?;
+                let name = copy_string(key as *const I::StringObject, process, offsets)?;
                 if name == "_active" {
                     for i in DictIterator::from(process, version, value)? {
                         let (key, value) = i?;
                         let (threadid, _) = copy_long(process, version, key)?;
 
-                        let thread: I::Object = process.copy_struct(value)?;
-                        let thread_type = process.copy_pointer(thread.ob_type())?;
-                        let flags = thread_type.flags();
-
-                        let dict_iter = if flags & PY_TPFLAGS_MANAGED_DICT != 0 {
-                            DictIterator::from_managed_dict(
-                                process,
-                                version,
-                                value,
-                                thread.ob_type() as usize,
-                                flags,
-                            )?
-                        } else {
-                            let dict_offset = thread_type.dictoffset();
-                            let dict_addr = (value as isize + dict_offset) as usize;
-                            let thread_dict_addr: usize = process.copy_struct(dict_addr)?;
-                            DictIterator::from(process, version, thread_dict_addr)?
-                        };
-
-                        for i in dict_iter {
+                        for i in instance_dict_iterator::<I, P>(process, offsets, version, value)? {
                             let (key, value) = i?;
-                            let varname = copy_string(key as *const I::StringObject, process)?;
+                            let varname =
+                                copy_string(key as *const I::StringObject, process, offsets)?;
                             if varname == "_name" {
                                 let threadname =
-                                    copy_string(value as *const I::StringObject, process)?;
+                                    copy_string(value as *const I::StringObject, process, offsets)?;
                                 ret.insert(threadid as u64, threadname);
                                 break;
                             }
@@ -79,12 +64,43 @@ pub fn thread_names_from_interpreter<I: InterpreterState, P: ProcessMemory>(
     Ok(ret)
 }
 
+/// Returns a hashmap of threadid: threadname for all the interpreters in the process. Names from
+/// the main interpreter take precedence, since a thread can be running in several interpreters
+pub fn thread_names_from_interpreters<I: InterpreterState, P: ProcessMemory>(
+    interpreter_address: usize,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    version: &Version,
+) -> Result<HashMap<u64, String>, Error> {
+    let mut ret = HashMap::new();
+    // the main interpreter is last in the list, so inserting in order lets it win
+    for interpreter in get_interpreters::<I, P>(interpreter_address, process, offsets)? {
+        // subinterpreters don't necessarily import the threading module, so don't fail
+        // everything if we can't read the names from one of them
+        match thread_names_from_interpreter::<I, P>(interpreter.address, process, offsets, version)
+        {
+            Ok(names) => ret.extend(names),
+            Err(e) if interpreter.id == 0 => return Err(e),
+            Err(e) => info!(
+                "Failed to get thread names for interpreter {}: {}",
+                interpreter.id, e
+            ),
+        }
+    }
+    Ok(ret)
+}
+
 /// Returns a hashmap of threadid: threadname, by inspecting the '_active' variable in the
 /// 'threading' module.
 fn _thread_name_lookup<I: InterpreterState>(
     spy: &PythonSpy,
 ) -> Result<HashMap<u64, String>, Error> {
-    thread_names_from_interpreter::<I, Process>(spy.interpreter_address, &spy.process, &spy.version)
+    thread_names_from_interpreters::<I, Process>(
+        spy.interpreter_address,
+        &spy.process,
+        spy.debug_offsets.as_ref(),
+        &spy.version,
+    )
 }
 
 // try getting the threadnames, but don't sweat it if we can't. Since this relies on dictionary
@@ -119,11 +135,22 @@ pub fn thread_name_lookup(process: &PythonSpy) -> Option<HashMap<u64, String>> {
             minor: 12,
             ..
         } => _thread_name_lookup::<v3_12_0::_is>(process),
+        Version {
+            major: 3,
+            minor: 13,
+            free_threaded: true,
+            ..
+        } => _thread_name_lookup::<v3_13_0t::_is>(process),
         Version {
             major: 3,
             minor: 13,
             ..
         } => _thread_name_lookup::<v3_13_0::_is>(process),
+        Version {
+            major: 3,
+            minor: 14..,
+            ..
+        } => _thread_name_lookup::<DebugInterpreterState>(process),
         _ => return None,
     };
     err.ok()
@@ -0,0 +1,62 @@
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+use anyhow::{Context, Error};
+use chrono::{DateTime, Local};
+
+use crate::config::Config;
+use crate::sample_log;
+use crate::{file_extension, new_recorder};
+
+/// Renders a sample log recorded with '--format pyspy' to one of the other output formats.
+/// Since the log has every sample, idle threads and GIL filtering can be decided on here
+pub fn report(config: &Config) -> Result<(), Error> {
+    let input = config
+        .input_filenames
+        .first()
+        .ok_or_else(|| format_err!("A sample log is required"))?;
+    let format = config
+        .format
+        .ok_or_else(|| format_err!("A file format is required to render the sample log"))?;
+
+    let contents =
+        std::fs::read(input).with_context(|| format!("Failed to read sample log '{}'", input))?;
+    let log = sample_log::read(&contents)
+        .with_context(|| format!("Failed to parse sample log '{}'", input))?;
+
+    let start_time: DateTime<Local> = log.start_time.into();
+    println!(
+        "Sample log recorded by py-spy v{} at {}: {}",
+        log.pyspy_version,
+        start_time.format("%Y-%m-%d %H:%M:%S"),
+        log.command_line
+    );
+
+    // render with the sampling rate the log was recorded at, so that times are correct
+    let config = Config {
+        sampling_rate: log.sampling_rate,
+        ..config.clone()
+    };
+    let mut output = new_recorder(&config)?;
+    let mut samples = 0;
+    let mut errors = 0;
+    for mut sample in log.samples {
+        errors += sample.errors.len();
+        samples += output.increment_traces(&mut sample.traces, &config, Some(sample.timestamp))?;
+    }
+
+    let filename = match config.filename.as_ref() {
+        Some(filename) => filename.clone(),
+        None => Path::new(input)
+            .with_extension(file_extension(&format))
+            .display()
+            .to_string(),
+    };
+    let mut out = BufWriter::new(
+        File::create(&filename).with_context(|| format!("Failed to create file '{}'", filename))?,
+    );
+    output.write(&mut out)?;
+    println!("Wrote {format:?} output to '{filename}'. Samples: {samples} Errors: {errors}");
+    Ok(())
+}
@@ -0,0 +1,648 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::Error;
+use remoteprocess::Pid;
+
+use crate::config::Config;
+use crate::sampler::Sample;
+use crate::stack_trace::{ColumnRange, Frame, ProcessInfo, StackTrace};
+
+/*
+ * This file contains code to save every sample collected by py-spy into a compact binary log
+ * (with a .pyspy extension), which can be rendered into any of the other output formats later
+ * on with 'py-spy report'.
+ *
+ * The file starts with a header, describing the version of the format along with how the samples
+ * were recorded. This is followed by a stream of records, each of which is written as a record
+ * type and the length of the record, so that readers can skip over types they don't know about.
+ *
+ * Strings, frames and processes are interned: each is written out as a record the first time
+ * it is seen, and then referred to by its index afterwards. All integers are written as LEB128
+ * varints, with signed integers zigzag encoded.
+ */
+
+const MAGIC: &[u8] = b"PYSPYLOG";
+const FORMAT_VERSION: u64 = 1;
+
+const RECORD_STRING: u64 = 1;
+const RECORD_FRAME: u64 = 2;
+const RECORD_PROCESS: u64 = 3;
+const RECORD_SAMPLE: u64 = 4;
+
+// flags for the optional fields in each frame
+const FRAME_SHORT_FILENAME: u64 = 1;
+const FRAME_MODULE: u64 = 2;
+const FRAME_ENTRY: u64 = 4;
+const FRAME_SHIM_ENTRY: u64 = 8;
+const FRAME_QUALNAME: u64 = 16;
+const FRAME_COLUMNS: u64 = 32;
+
+// flags for the optional fields in each stack trace
+const TRACE_ACTIVE: u64 = 1;
+const TRACE_GIL: u64 = 2;
+const TRACE_OS_THREAD_ID: u64 = 4;
+const TRACE_THREAD_NAME: u64 = 8;
+const TRACE_INTERPRETER_ID: u64 = 16;
+const TRACE_TASK_NAME: u64 = 32;
+const TRACE_PROCESS_INFO: u64 = 64;
+const TRACE_CPU_TIME: u64 = 128;
+const TRACE_LABELS: u64 = 256;
+
+pub struct SampleLog {
+    records: Encoder,
+    strings: HashMap<String, u64>,
+    frames: HashMap<Frame, u64>,
+    processes: HashMap<Pid, u64>,
+    sampling_rate: u64,
+    start_time: SystemTime,
+    start_ts: Instant,
+}
+
+/// A single sample read back from a log, with the time in microseconds since the
+/// start of the recording that it was taken at
+pub struct LoggedSample {
+    pub timestamp: u64,
+    pub traces: Vec<StackTrace>,
+    pub errors: Vec<(Pid, String)>,
+}
+
+/// The contents of a sample log
+pub struct SampleLogFile {
+    pub pyspy_version: String,
+    pub command_line: String,
+    pub sampling_rate: u64,
+    pub start_time: SystemTime,
+    pub samples: Vec<LoggedSample>,
+}
+
+impl SampleLog {
+    pub fn new(config: &Config) -> SampleLog {
+        SampleLog {
+            records: Encoder::new(),
+            strings: HashMap::new(),
+            frames: HashMap::new(),
+            processes: HashMap::new(),
+            sampling_rate: config.sampling_rate,
+            start_time: SystemTime::now(),
+            start_ts: Instant::now(),
+        }
+    }
+
+    /// Records all of the stack traces in a sample, along with any errors from collecting it
+    pub fn increment_sample(&mut self, sample: &Sample) -> Result<(), Error> {
+        let errors: Vec<(Pid, String)> = sample
+            .sampling_errors
+            .iter()
+            .flatten()
+            .map(|(pid, e)| (*pid, format!("{e:#}")))
+            .collect();
+        let timestamp = self.start_ts.elapsed().as_micros() as u64;
+        self.add_sample(timestamp, &sample.traces, &errors);
+        Ok(())
+    }
+
+    /// Records a single stack trace, sampled `timestamp` microseconds after the start of the log
+    pub fn increment_at(&mut self, trace: &StackTrace, timestamp: u64) -> Result<(), Error> {
+        self.add_sample(timestamp, std::slice::from_ref(trace), &[]);
+        Ok(())
+    }
+
+    pub fn increment(&mut self, trace: &StackTrace) -> Result<(), Error> {
+        let timestamp = self.start_ts.elapsed().as_micros() as u64;
+        self.increment_at(trace, timestamp)
+    }
+
+    pub fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
+        let start_time = self
+            .start_time
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(0);
+
+        let mut header = Encoder::new();
+        header.varint(FORMAT_VERSION);
+        header.string(env!("CARGO_PKG_VERSION"));
+        header.string(&std::env::args().collect::<Vec<String>>().join(" "));
+        header.varint(self.sampling_rate);
+        header.varint(start_time);
+
+        w.write_all(MAGIC)?;
+        w.write_all(&header.buf)?;
+        w.write_all(&self.records.buf)?;
+        Ok(())
+    }
+
+    fn add_sample(&mut self, timestamp: u64, traces: &[StackTrace], errors: &[(Pid, String)]) {
+        let mut sample = Encoder::new();
+        sample.varint(timestamp);
+        sample.varint(traces.len() as u64);
+        for trace in traces {
+            self.encode_trace(&mut sample, trace);
+        }
+        sample.varint(errors.len() as u64);
+        for (pid, message) in errors {
+            sample.varint(*pid as u64);
+            let message = self.string(message);
+            sample.varint(message);
+        }
+        self.records.record(RECORD_SAMPLE, &sample.buf);
+    }
+
+    fn encode_trace(&mut self, e: &mut Encoder, trace: &StackTrace) {
+        let optional = [
+            (trace.active, TRACE_ACTIVE),
+            (trace.owns_gil, TRACE_GIL),
+            (trace.os_thread_id.is_some(), TRACE_OS_THREAD_ID),
+            (trace.thread_name.is_some(), TRACE_THREAD_NAME),
+            (trace.interpreter_id.is_some(), TRACE_INTERPRETER_ID),
+            (trace.task_name.is_some(), TRACE_TASK_NAME),
+            (trace.process_info.is_some(), TRACE_PROCESS_INFO),
+            (trace.cpu_time.is_some(), TRACE_CPU_TIME),
+            (!trace.labels.is_empty(), TRACE_LABELS),
+        ];
+        let flags = optional
+            .iter()
+            .filter(|(set, _)| *set)
+            .fold(0, |flags, (_, flag)| flags | flag);
+
+        e.varint(flags);
+        e.varint(trace.pid as u64);
+        e.varint(trace.thread_id);
+        if let Some(os_thread_id) = trace.os_thread_id {
+            e.varint(os_thread_id);
+        }
+        if let Some(thread_name) = trace.thread_name.as_ref() {
+            e.varint(self.string(thread_name));
+        }
+        if let Some(interpreter_id) = trace.interpreter_id {
+            e.zigzag(interpreter_id);
+        }
+        if let Some(task_name) = trace.task_name.as_ref() {
+            e.varint(self.string(task_name));
+        }
+        if let Some(process_info) = trace.process_info.as_ref() {
+            e.varint(self.process(process_info));
+        }
+        if let Some(cpu_time) = trace.cpu_time {
+            e.varint(cpu_time);
+        }
+        if !trace.labels.is_empty() {
+            e.varint(trace.labels.len() as u64);
+            for (name, value) in &trace.labels {
+                e.varint(self.string(name));
+                e.varint(self.string(value));
+            }
+        }
+        e.varint(trace.frames.len() as u64);
+        for frame in &trace.frames {
+            let frame = self.frame(frame);
+            e.varint(frame);
+        }
+    }
+
+    fn string(&mut self, s: &str) -> u64 {
+        if let Some(index) = self.strings.get(s) {
+            return *index;
+        }
+        let index = self.strings.len() as u64;
+        self.records.record(RECORD_STRING, s.as_bytes());
+        self.strings.insert(s.to_owned(), index);
+        index
+    }
+
+    fn frame(&mut self, frame: &Frame) -> u64 {
+        if let Some(index) = self.frames.get(frame) {
+            return *index;
+        }
+
+        let mut flags = 0;
+        if frame.short_filename.is_some() {
+            flags |= FRAME_SHORT_FILENAME;
+        }
+        if frame.module.is_some() {
+            flags |= FRAME_MODULE;
+        }
+        if frame.is_entry {
+            flags |= FRAME_ENTRY;
+        }
+        if frame.is_shim_entry {
+            flags |= FRAME_SHIM_ENTRY;
+        }
+        if frame.qualname.is_some() {
+            flags |= FRAME_QUALNAME;
+        }
+        if frame.columns.is_some() {
+            flags |= FRAME_COLUMNS;
+        }
+
+        let mut e = Encoder::new();
+        e.varint(flags);
+        e.varint(self.string(&frame.name));
+        e.varint(self.string(&frame.filename));
+        if let Some(short_filename) = frame.short_filename.as_ref() {
+            e.varint(self.string(short_filename));
+        }
+        if let Some(module) = frame.module.as_ref() {
+            e.varint(self.string(module));
+        }
+        if let Some(qualname) = frame.qualname.as_ref() {
+            e.varint(self.string(qualname));
+        }
+        e.zigzag(frame.line as i64);
+        if let Some(columns) = frame.columns {
+            e.varint(columns.start as u64);
+            e.varint(columns.end as u64);
+        }
+
+        let index = self.frames.len() as u64;
+        self.records.record(RECORD_FRAME, &e.buf);
+        self.frames.insert(frame.clone(), index);
+        index
+    }
+
+    fn process(&mut self, process: &ProcessInfo) -> u64 {
+        if let Some(index) = self.processes.get(&process.pid) {
+            return *index;
+        }
+
+        // parent processes have to be written out first, so that they can be referred to
+        let parent = process.parent.as_ref().map(|parent| self.process(parent));
+
+        let mut e = Encoder::new();
+        e.varint(process.pid as u64);
+        e.varint(self.string(&process.command_line));
+        match parent {
+            Some(parent) => {
+                e.varint(1);
+                e.varint(parent);
+            }
+            None => e.varint(0),
+        }
+
+        let index = self.processes.len() as u64;
+        self.records.record(RECORD_PROCESS, &e.buf);
+        self.processes.insert(process.pid, index);
+        index
+    }
+}
+
+/// Reads back all the samples from a sample log
+pub fn read(contents: &[u8]) -> Result<SampleLogFile, Error> {
+    let contents = contents
+        .strip_prefix(MAGIC)
+        .ok_or_else(|| format_err!("Not a py-spy sample log"))?;
+    let mut d = Decoder { buf: contents };
+
+    let version = d.varint()?;
+    if version != FORMAT_VERSION {
+        return Err(format_err!(
+            "Unsupported sample log version {} (expected {})",
+            version,
+            FORMAT_VERSION
+        ));
+    }
+    let pyspy_version = d.string()?;
+    let command_line = d.string()?;
+    let sampling_rate = d.varint()?;
+    let start_time = UNIX_EPOCH + std::time::Duration::from_micros(d.varint()?);
+
+    let mut strings: Vec<String> = Vec::new();
+    let mut frames: Vec<Frame> = Vec::new();
+    let mut processes: Vec<Arc<ProcessInfo>> = Vec::new();
+    let mut samples = Vec::new();
+
+    while !d.buf.is_empty() {
+        let record_type = d.varint()?;
+        let length = d.varint()? as usize;
+        let mut r = Decoder {
+            buf: d.bytes(length)?,
+        };
+
+        let string = |index: u64| -> Result<String, Error> {
+            strings
+                .get(index as usize)
+                .cloned()
+                .ok_or_else(|| format_err!("Invalid string index {}", index))
+        };
+
+        match record_type {
+            RECORD_STRING => {
+                strings.push(String::from_utf8_lossy(r.buf).into_owned());
+            }
+            RECORD_FRAME => {
+                let flags = r.varint()?;
+                let name = string(r.varint()?)?;
+                let filename = string(r.varint()?)?;
+                let short_filename = if flags & FRAME_SHORT_FILENAME != 0 {
+                    Some(string(r.varint()?)?)
+                } else {
+                    None
+                };
+                let module = if flags & FRAME_MODULE != 0 {
+                    Some(string(r.varint()?)?)
+                } else {
+                    None
+                };
+                let qualname = if flags & FRAME_QUALNAME != 0 {
+                    Some(string(r.varint()?)?)
+                } else {
+                    None
+                };
+                let line = r.zigzag()? as i32;
+                let columns = if flags & FRAME_COLUMNS != 0 {
+                    Some(ColumnRange {
+                        start: r.varint()? as u32,
+                        end: r.varint()? as u32,
+                    })
+                } else {
+                    None
+                };
+                frames.push(Frame {
+                    name,
+                    qualname,
+                    filename,
+                    module,
+                    short_filename,
+                    line,
+                    columns,
+                    instruction: None,
+                    locals: None,
+                    is_entry: flags & FRAME_ENTRY != 0,
+                    is_shim_entry: flags & FRAME_SHIM_ENTRY != 0,
+                });
+            }
+            RECORD_PROCESS => {
+                let pid = r.varint()? as Pid;
+                let command_line = string(r.varint()?)?;
+                let parent = if r.varint()? != 0 {
+                    let index = r.varint()?;
+                    let parent = processes
+                        .get(index as usize)
+                        .ok_or_else(|| format_err!("Invalid process index {}", index))?;
+                    Some(Box::new(parent.as_ref().clone()))
+                } else {
+                    None
+                };
+                processes.push(Arc::new(ProcessInfo {
+                    pid,
+                    command_line,
+                    parent,
+                }));
+            }
+            RECORD_SAMPLE => {
+                let timestamp = r.varint()?;
+                let trace_count = r.varint()?;
+                let mut traces = Vec::new();
+                for _ in 0..trace_count {
+                    let flags = r.varint()?;
+                    let pid = r.varint()? as Pid;
+                    let thread_id = r.varint()?;
+                    let os_thread_id = if flags & TRACE_OS_THREAD_ID != 0 {
+                        Some(r.varint()?)
+                    } else {
+                        None
+                    };
+                    let thread_name = if flags & TRACE_THREAD_NAME != 0 {
+                        Some(string(r.varint()?)?)
+                    } else {
+                        None
+                    };
+                    let interpreter_id = if flags & TRACE_INTERPRETER_ID != 0 {
+                        Some(r.zigzag()?)
+                    } else {
+                        None
+                    };
+                    let task_name = if flags & TRACE_TASK_NAME != 0 {
+                        Some(string(r.varint()?)?)
+                    } else {
+                        None
+                    };
+                    let process_info = if flags & TRACE_PROCESS_INFO != 0 {
+                        let index = r.varint()?;
+                        let process = processes
+                            .get(index as usize)
+                            .ok_or_else(|| format_err!("Invalid process index {}", index))?;
+                        Some(process.clone())
+                    } else {
+                        None
+                    };
+                    let cpu_time = if flags & TRACE_CPU_TIME != 0 {
+                        Some(r.varint()?)
+                    } else {
+                        None
+                    };
+                    let mut labels = Vec::new();
+                    if flags & TRACE_LABELS != 0 {
+                        for _ in 0..r.varint()? {
+                            labels.push((string(r.varint()?)?, string(r.varint()?)?));
+                        }
+                    }
+                    let frame_count = r.varint()?;
+                    let mut trace_frames = Vec::new();
+                    for _ in 0..frame_count {
+                        let index = r.varint()?;
+                        let frame = frames
+                            .get(index as usize)
+                            .ok_or_else(|| format_err!("Invalid frame index {}", index))?;
+                        trace_frames.push(frame.clone());
+                    }
+                    traces.push(StackTrace {
+                        pid,
+                        thread_id,
+                        thread_name,
+                        os_thread_id,
+                        interpreter_id,
+                        task_name,
+                        active: flags & TRACE_ACTIVE != 0,
+                        owns_gil: flags & TRACE_GIL != 0,
+                        cpu_time,
+                        labels,
+                        frames: trace_frames,
+                        process_info,
+                    });
+                }
+
+                let error_count = r.varint()?;
+                let mut errors = Vec::new();
+                for _ in 0..error_count {
+                    let pid = r.varint()? as Pid;
+                    errors.push((pid, string(r.varint()?)?));
+                }
+                samples.push(LoggedSample {
+                    timestamp,
+                    traces,
+                    errors,
+                });
+            }
+            // skip over records from newer versions of py-spy
+            _ => {}
+        }
+    }
+
+    Ok(SampleLogFile {
+        pyspy_version,
+        command_line,
+        sampling_rate,
+        start_time,
+        samples,
+    })
+}
+
+struct Encoder {
+    buf: Vec<u8>,
+}
+
+impl Encoder {
+    fn new() -> Encoder {
+        Encoder { buf: Vec::new() }
+    }
+
+    fn varint(&mut self, mut value: u64) {
+        while value >= 0x80 {
+            self.buf.push((value as u8) | 0x80);
+            value >>= 7;
+        }
+        self.buf.push(value as u8);
+    }
+
+    fn zigzag(&mut self, value: i64) {
+        self.varint(((value << 1) ^ (value >> 63)) as u64);
+    }
+
+    fn string(&mut self, s: &str) {
+        self.varint(s.len() as u64);
+        self.buf.extend_from_slice(s.as_bytes());
+    }
+
+    fn record(&mut self, record_type: u64, data: &[u8]) {
+        self.varint(record_type);
+        self.varint(data.len() as u64);
+        self.buf.extend_from_slice(data);
+    }
+}
+
+struct Decoder<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Decoder<'a> {
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut value = 0;
+        for (i, byte) in self.buf.iter().enumerate().take(10) {
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            if byte & 0x80 == 0 {
+                self.buf = &self.buf[i + 1..];
+                return Ok(value);
+            }
+        }
+        Err(format_err!("Truncated or invalid sample log"))
+    }
+
+    fn zigzag(&mut self) -> Result<i64, Error> {
+        let value = self.varint()?;
+        Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        if length > self.buf.len() {
+            return Err(format_err!("Truncated sample log"));
+        }
+        let (bytes, rest) = self.buf.split_at(length);
+        self.buf = rest;
+        Ok(bytes)
+    }
+
+    fn string(&mut self) -> Result<String, Error> {
+        let length = self.varint()? as usize;
+        Ok(String::from_utf8_lossy(self.bytes(length)?).into_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_log_round_trip() {
+        let frame = Frame {
+            name: String::from("work"),
+            qualname: Some(String::from("Worker.work")),
+            filename: String::from("/usr/lib/python3/app.py"),
+            module: None,
+            short_filename: Some(String::from("app.py")),
+            line: 12,
+            columns: Some(ColumnRange { start: 4, end: 10 }),
+            instruction: None,
+            locals: None,
+            is_entry: true,
+            is_shim_entry: false,
+        };
+        let parent = ProcessInfo {
+            pid: 1,
+            command_line: String::from("python launcher.py"),
+            parent: None,
+        };
+        let trace = StackTrace {
+            pid: 2,
+            thread_id: 0x7f00,
+            thread_name: Some(String::from("MainThread")),
+            os_thread_id: Some(42),
+            interpreter_id: Some(-1),
+            task_name: None,
+            active: false,
+            owns_gil: true,
+            cpu_time: Some(1500),
+            labels: vec![(String::from("tenant"), String::from("acme"))],
+            frames: vec![frame.clone(), frame],
+            process_info: Some(Arc::new(ProcessInfo {
+                pid: 2,
+                command_line: String::from("python app.py"),
+                parent: Some(Box::new(parent)),
+            })),
+        };
+
+        let mut log = SampleLog::new(&Config::default());
+        let sample = Sample {
+            traces: vec![trace.clone(), trace],
+            sampling_errors: Some(vec![(3, format_err!("process exited"))]),
+            late: None,
+        };
+        log.increment_sample(&sample).unwrap();
+        log.increment_at(&sample.traces[0], 10_000_000_000).unwrap();
+
+        let mut contents = Vec::new();
+        log.write(&mut contents).unwrap();
+        let file = read(&contents).unwrap();
+
+        assert_eq!(file.sampling_rate, 100);
+        assert_eq!(file.samples.len(), 2);
+        assert_eq!(
+            file.samples[0].errors,
+            vec![(3, String::from("process exited"))]
+        );
+        assert_eq!(file.samples[1].timestamp, 10_000_000_000);
+
+        let trace = &file.samples[1].traces[0];
+        assert_eq!(trace.pid, 2);
+        assert_eq!(trace.thread_id, 0x7f00);
+        assert_eq!(trace.os_thread_id, Some(42));
+        assert_eq!(trace.thread_name.as_deref(), Some("MainThread"));
+        assert_eq!(trace.interpreter_id, Some(-1));
+        assert!(!trace.active);
+        assert!(trace.owns_gil);
+        assert_eq!(trace.cpu_time, Some(1500));
+        assert_eq!(trace.labels, sample.traces[0].labels);
+        assert_eq!(trace.frames.len(), 2);
+        assert_eq!(trace.frames[0], sample.traces[0].frames[0]);
+
+        let process_info = trace.process_info.as_ref().unwrap();
+        assert_eq!(process_info.command_line, "python app.py");
+        assert_eq!(process_info.parent.as_ref().unwrap().pid, 1);
+
+        assert!(read(b"not a sample log").is_err());
+        assert!(read(&contents[..contents.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use anyhow::Error;
+use remoteprocess::Pid;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::config::{Config, FileFormat};
+use crate::new_recorder;
+use crate::python_spy::PythonSpy;
+use crate::sampler::Sampler;
+
+/// Longest profile that can be requested, to stop a single request from tying up the server
+const MAX_PROFILE_SECONDS: u64 = 3600;
+
+/// Runs a HTTP server that returns stack traces and profiles from the target process on demand:
+///
+/// * `GET /dump` returns the current stack traces, in the same json format as `dump --json`
+/// * `GET /profile?seconds=30&format=flamegraph` records a new profile and returns it. Only one
+///   profile is recorded at a time, other requests get a 429 response while it is running
+pub fn serve(pid: Pid, config: &Config) -> Result<(), Error> {
+    let listen = config.listen.as_deref().unwrap_or("127.0.0.1:8080");
+    let server =
+        Server::http(listen).map_err(|e| format_err!("Failed to listen on {}: {}", listen, e))?;
+
+    // keep the process attached, so that dumping stack traces doesn't have to find the
+    // python interpreter each time
+    let mut spy = PythonSpy::retry_new(pid, config, 3)?;
+    let profiling = Arc::new(AtomicBool::new(false));
+
+    println!("Serving stack traces for process {pid} on http://{listen}. Press Control-C to exit.");
+    for request in server.incoming_requests() {
+        info!("{} {}", request.method(), request.url());
+        if request.method() != &Method::Get {
+            respond_error(request, 405, "Only GET requests are supported");
+            continue;
+        }
+
+        let url = request.url().to_owned();
+        let (path, query) = parse_url(&url);
+        match path {
+            // dumping is quick, and has to happen on this thread since PythonSpy objects
+            // don't have the Send trait implemented on linux
+            "/dump" => respond(request, dump(&mut spy, config)),
+            // profiling takes a while though, so record each profile on its own thread
+            // to avoid blocking other requests while it runs
+            "/profile" => match parse_profile_args(&query) {
+                Ok((seconds, format)) => {
+                    // each profile attaches a new sampler to the process, and would pause it
+                    // again on every sample when running concurrently with another one
+                    if profiling.swap(true, Ordering::SeqCst) {
+                        respond_error(
+                            request,
+                            429,
+                            "A profile is already being recorded, try again once it has finished",
+                        );
+                        continue;
+                    }
+                    let guard = ProfilingGuard(profiling.clone());
+
+                    let config = Config {
+                        format: Some(format),
+                        ..config.clone()
+                    };
+                    std::thread::spawn(move || {
+                        let _guard = guard;
+                        respond(request, profile(pid, &config, seconds));
+                    });
+                }
+                Err(e) => respond_error(request, 400, &format!("{e:#}")),
+            },
+            _ => respond_error(request, 404, "Not found: try /dump or /profile"),
+        }
+    }
+    Ok(())
+}
+
+/// Clears the flag for a running profile when dropped, so that a profile that fails with a
+/// panic doesn't stop any more profiles from being recorded
+struct ProfilingGuard(Arc<AtomicBool>);
+
+impl Drop for ProfilingGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+fn respond(request: Request, result: Result<(&'static str, Vec<u8>), Error>) {
+    let (content_type, body) = match result {
+        Ok(response) => response,
+        Err(e) => return respond_error(request, 500, &format!("{e:#}")),
+    };
+    let header =
+        Header::from_bytes("Content-Type", content_type).expect("invalid content type header");
+    if let Err(e) = request.respond(Response::from_data(body).with_header(header)) {
+        warn!("Failed to send response: {}", e);
+    }
+}
+
+fn respond_error(request: Request, status: u16, message: &str) {
+    let response = Response::from_string(message).with_status_code(status);
+    if let Err(e) = request.respond(response) {
+        warn!("Failed to send response: {}", e);
+    }
+}
+
+fn dump(spy: &mut PythonSpy, config: &Config) -> Result<(&'static str, Vec<u8>), Error> {
+    let mut traces = spy.get_stack_traces()?;
+
+    // profiles sample the subprocesses themselves, but dumps need to attach to each of them.
+    // child_processes returns every python and non-python process in the tree below ours
+    if config.subprocesses {
+        for (childpid, _) in spy.process.child_processes()? {
+            match PythonSpy::new(childpid, config).and_then(|mut child| child.get_stack_traces()) {
+                Ok(child_traces) => traces.extend(child_traces),
+                Err(e) => info!("Failed to get stack traces from {}: {}", childpid, e),
+            }
+        }
+    }
+    Ok(("application/json", serde_json::to_vec_pretty(&traces)?))
+}
+
+/// Gets the number of seconds to sample for, and the format to return from the
+/// query parameters of a profile request
+fn parse_profile_args(query: &HashMap<&str, &str>) -> Result<(u64, FileFormat), Error> {
+    let seconds: u64 = match query.get("seconds") {
+        Some(seconds) => seconds
+            .parse()
+            .map_err(|_| format_err!("Invalid seconds '{}'", seconds))?,
+        None => 10,
+    };
+    if seconds == 0 || seconds > MAX_PROFILE_SECONDS {
+        return Err(format_err!(
+            "seconds must be between 1 and {}",
+            MAX_PROFILE_SECONDS
+        ));
+    }
+
+    let format = match query.get("format") {
+        Some(format) => format.parse().map_err(|e: String| format_err!(e))?,
+        None => FileFormat::flamegraph,
+    };
+    Ok((seconds, format))
+}
+
+fn profile(pid: Pid, config: &Config, seconds: u64) -> Result<(&'static str, Vec<u8>), Error> {
+    let format = config
+        .format
+        .ok_or_else(|| format_err!("A file format is required to record samples"))?;
+    let mut output = new_recorder(config)?;
+
+    let sampler = Sampler::new(pid, config)?;
+    let max_intervals = seconds * config.sampling_rate;
+    for (intervals, mut sample) in sampler.enumerate() {
+        if intervals as u64 >= max_intervals {
+            break;
+        }
+
+        output.increment_sample(&mut sample, config)?;
+
+        if let Some(sampling_errors) = sample.sampling_errors {
+            for (pid, e) in sampling_errors {
+                warn!("Failed to get stack trace from {}: {}", pid, e);
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    output.write(&mut body)?;
+
+    let content_type = match format {
+        FileFormat::flamegraph => "image/svg+xml",
+        FileFormat::speedscope | FileFormat::chrometrace => "application/json",
+        FileFormat::raw | FileFormat::annotate => "text/plain",
+        FileFormat::pprof | FileFormat::pyspy => "application/octet-stream",
+    };
+    Ok((content_type, body))
+}
+
+/// Splits a request url into the path and the query parameters
+fn parse_url(url: &str) -> (&str, HashMap<&str, &str>) {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let query = query
+        .split('&')
+        .filter(|param| !param.is_empty())
+        .map(|param| param.split_once('=').unwrap_or((param, "")))
+        .collect();
+    (path, query)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url() {
+        let (path, query) = parse_url("/profile?seconds=30&format=speedscope");
+        assert_eq!(path, "/profile");
+        assert_eq!(query.get("seconds"), Some(&"30"));
+        assert_eq!(query.get("format"), Some(&"speedscope"));
+
+        assert_eq!(
+            parse_profile_args(&query).unwrap(),
+            (30, FileFormat::speedscope)
+        );
+
+        let (path, query) = parse_url("/dump");
+        assert_eq!(path, "/dump");
+        assert!(query.is_empty());
+        assert_eq!(
+            parse_profile_args(&query).unwrap(),
+            (10, FileFormat::flamegraph)
+        );
+
+        let (_, query) = parse_url("/profile?seconds=0");
+        assert!(parse_profile_args(&query).is_err());
+    }
+
+    #[test]
+    fn test_profiling_guard() {
+        let profiling = Arc::new(AtomicBool::new(true));
+        let guard = ProfilingGuard(profiling.clone());
+        let result = std::thread::spawn(move || {
+            let _guard = guard;
+            panic!("profile failed");
+        })
+        .join();
+        assert!(result.is_err());
+        assert!(!profiling.load(Ordering::SeqCst));
+    }
+}
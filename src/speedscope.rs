@@ -126,23 +126,24 @@ enum ValueUnit {
     Seconds,
 }
 
+/// The name given to files recorded with --cpu-time, which lets us tell that the weights are
+/// CPU time when reading the file back in
+const CPU_TIME_FILE_NAME: &str = "py-spy CPU time profile";
+
 impl SpeedscopeFile {
     pub fn new(
-        samples: &HashMap<(Pid, Tid), Vec<Vec<usize>>>,
+        samples: &HashMap<ProfileKey, Vec<Vec<usize>>>,
+        weights: &HashMap<ProfileKey, Vec<f64>>,
         frames: &[Frame],
-        thread_name_map: &HashMap<(Pid, Tid), String>,
-        sample_rate: u64,
+        thread_name_map: &HashMap<ProfileKey, String>,
+        cpu_time: bool,
     ) -> SpeedscopeFile {
         let mut profiles: Vec<Profile> = samples
             .iter()
             .map(|(thread_id, samples)| {
-                let end_value = samples.len();
-                // we sample at 100 Hz, so scale the end value and weights to match the time unit
-                let scaled_end_value = end_value as f64 / sample_rate as f64;
-                let weights: Vec<f64> = samples
-                    .iter()
-                    .map(|_s| 1_f64 / sample_rate as f64)
-                    .collect();
+                // the weights are the time in seconds that each sample represents
+                let weights = weights.get(thread_id).cloned().unwrap_or_default();
+                let end_value = weights.iter().sum();
 
                 Profile {
                     profile_type: ProfileType::Sampled,
@@ -151,7 +152,7 @@ impl SpeedscopeFile {
                         .map_or_else(|| "py-spy".to_string(), |x| x.clone()),
                     unit: ValueUnit::Seconds,
                     start_value: 0.0,
-                    end_value: scaled_end_value,
+                    end_value,
                     samples: samples.clone(),
                     weights,
                 }
@@ -164,7 +165,11 @@ impl SpeedscopeFile {
             // This is always the same
             schema: "https://www.speedscope.app/file-format-schema.json".to_string(),
             active_profile_index: None,
-            name: Some("py-spy profile".to_string()),
+            name: Some(if cpu_time {
+                CPU_TIME_FILE_NAME.to_string()
+            } else {
+                "py-spy profile".to_string()
+            }),
             exporter: Some(format!("py-spy@{}", env!("CARGO_PKG_VERSION"))),
             profiles,
             shared: Shared {
@@ -177,7 +182,7 @@ impl SpeedscopeFile {
 impl Frame {
     pub fn new(stack_frame: &stack_trace::Frame, show_line_numbers: bool) -> Frame {
         Frame {
-            name: stack_frame.name.clone(),
+            name: stack_frame.display_name().to_owned(),
             // TODO: filename?
             file: Some(stack_frame.filename.clone()),
             line: if show_line_numbers {
@@ -185,16 +190,24 @@ impl Frame {
             } else {
                 None
             },
-            col: None,
+            col: stack_frame
+                .columns
+                .filter(|_| show_line_numbers)
+                .map(|columns| columns.start),
         }
     }
 }
 
+/// Samples are split into a profile for each thread, and for each set of --label values
+type ProfileKey = (Pid, Tid, Vec<(String, String)>);
+
 pub struct Stats {
-    samples: HashMap<(Pid, Tid), Vec<Vec<usize>>>,
+    samples: HashMap<ProfileKey, Vec<Vec<usize>>>,
+    weights: HashMap<ProfileKey, Vec<f64>>,
     frames: Vec<Frame>,
     frame_to_index: HashMap<stack_trace::Frame, usize>,
-    thread_name_map: HashMap<(Pid, Tid), String>,
+    thread_name_map: HashMap<ProfileKey, String>,
+    cpu_time: bool,
     config: Config,
 }
 
@@ -202,14 +215,37 @@ impl Stats {
     pub fn new(config: &Config) -> Stats {
         Stats {
             samples: HashMap::new(),
+            weights: HashMap::new(),
             frames: vec![],
             frame_to_index: HashMap::new(),
             thread_name_map: HashMap::new(),
+            cpu_time: false,
             config: config.clone(),
         }
     }
 
     pub fn record(&mut self, stack: &stack_trace::StackTrace) -> Result<(), io::Error> {
+        // we sample at 100 Hz by default, so weight each sample by the interval between samples
+        let weight = 1_f64 / self.config.sampling_rate as f64;
+        self.record_weighted(stack, weight)
+    }
+
+    /// Records a stack trace weighted by the CPU time in microseconds the thread used
+    /// since the previous sample, rather than by the sampling interval
+    pub fn record_cpu_time(
+        &mut self,
+        stack: &stack_trace::StackTrace,
+        cpu_time: u64,
+    ) -> Result<(), io::Error> {
+        self.cpu_time = true;
+        self.record_weighted(stack, cpu_time as f64 / 1_000_000.0)
+    }
+
+    fn record_weighted(
+        &mut self,
+        stack: &stack_trace::StackTrace,
+        weight: f64,
+    ) -> Result<(), io::Error> {
         let show_line_numbers = self.config.show_line_numbers;
         let mut frame_indices: Vec<usize> = stack
             .frames
@@ -229,16 +265,24 @@ impl Stats {
             .collect();
         frame_indices.reverse();
 
-        let key = (stack.pid as Pid, stack.thread_id as Tid);
-
-        self.samples.entry(key).or_default().push(frame_indices);
+        let key = (
+            stack.pid as Pid,
+            stack.thread_id as Tid,
+            stack.labels.clone(),
+        );
+
+        self.samples
+            .entry(key.clone())
+            .or_default()
+            .push(frame_indices);
+        self.weights.entry(key.clone()).or_default().push(weight);
         let subprocesses = self.config.subprocesses;
         self.thread_name_map.entry(key).or_insert_with(|| {
             let thread_name = stack
                 .thread_name
                 .as_ref()
                 .map_or_else(|| "".to_string(), |x| x.clone());
-            if subprocesses {
+            let name = if subprocesses {
                 format!(
                     "Process {} Thread {} \"{}\"",
                     stack.pid,
@@ -247,6 +291,16 @@ impl Stats {
                 )
             } else {
                 format!("Thread {} \"{}\"", stack.format_threadid(), thread_name)
+            };
+            if stack.labels.is_empty() {
+                name
+            } else {
+                let labels: Vec<String> = stack
+                    .labels
+                    .iter()
+                    .map(|(name, value)| format!("{name}={value}"))
+                    .collect();
+                format!("{} [{}]", name, labels.join(", "))
             }
         });
 
@@ -256,15 +310,204 @@ impl Stats {
     pub fn write(&self, w: &mut dyn Write) -> Result<(), Error> {
         let json = serde_json::to_string(&SpeedscopeFile::new(
             &self.samples,
+            &self.weights,
             &self.frames,
             &self.thread_name_map,
-            self.config.sampling_rate,
+            self.cpu_time,
         ))?;
         writeln!(w, "{json}")?;
         Ok(())
     }
 }
 
+/// Reads back the stack traces from a speedscope file, along with the time in microseconds that
+/// each was sampled at. The thread each sample came from is recovered from the profile names
+pub fn read_traces(
+    contents: &str,
+    sampling_rate: u64,
+) -> Result<Vec<(u64, stack_trace::StackTrace)>, Error> {
+    let file: SpeedscopeFile = serde_json::from_str(contents)?;
+    let frames: Vec<stack_trace::Frame> = file
+        .shared
+        .frames
+        .iter()
+        .map(|frame| stack_trace::Frame {
+            name: frame.name.clone(),
+            qualname: None,
+            filename: frame.file.clone().unwrap_or_default(),
+            module: None,
+            short_filename: None,
+            line: frame.line.unwrap_or(0) as i32,
+            columns: None,
+            instruction: None,
+            locals: None,
+            is_entry: true,
+            is_shim_entry: false,
+        })
+        .collect();
+
+    let interval = 1_000_000.0 / sampling_rate.max(1) as f64;
+    let cpu_time = weighted_by_cpu_time(&file);
+    let mut traces = Vec::new();
+    for profile in &file.profiles {
+        // if the weights aren't times, assume that each sample is from a single interval
+        let micros = unit_micros(&profile.unit);
+        let template = thread_trace(&profile.name);
+
+        let mut ts = profile.start_value * micros;
+        for (i, sample) in profile.samples.iter().enumerate() {
+            let mut trace = template.clone();
+            trace.frames = sample
+                .iter()
+                .rev()
+                .map(|&index| {
+                    frames.get(index).cloned().ok_or_else(|| {
+                        format_err!("Invalid frame index {} in speedscope file", index)
+                    })
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            if cpu_time {
+                trace.cpu_time = profile
+                    .weights
+                    .get(i)
+                    .map(|weight| (weight * micros).round() as u64);
+            }
+            traces.push((ts as u64, trace));
+
+            ts += match profile.weights.get(i) {
+                Some(weight) if micros > 0.0 => weight * micros,
+                _ => interval,
+            };
+        }
+    }
+    Ok(traces)
+}
+
+/// Creates an empty stack trace for the thread that a profile was recorded from, by parsing the
+/// profile name back into the process and thread ids (as formatted by `Stats::record`)
+fn thread_trace(profile_name: &str) -> stack_trace::StackTrace {
+    let mut trace = stack_trace::StackTrace {
+        pid: 0,
+        thread_id: 0,
+        thread_name: None,
+        os_thread_id: None,
+        interpreter_id: None,
+        task_name: None,
+        active: true,
+        owns_gil: false,
+        cpu_time: None,
+        labels: Vec::new(),
+        frames: Vec::new(),
+        process_info: None,
+    };
+
+    let name = match profile_name
+        .strip_prefix("Process ")
+        .and_then(|name| name.split_once(' '))
+    {
+        Some((pid, name)) => {
+            trace.pid = pid.parse().unwrap_or(0);
+            name
+        }
+        None => profile_name,
+    };
+
+    // profiles that were split by --label have the label values at the end of the name
+    let name = match name
+        .strip_suffix(']')
+        .and_then(|name| name.rsplit_once(" ["))
+    {
+        Some((name, labels)) => {
+            trace.labels = labels
+                .split(", ")
+                .filter_map(|label| label.split_once('='))
+                .map(|(name, value)| (name.to_owned(), value.to_owned()))
+                .collect();
+            name
+        }
+        None => name,
+    };
+
+    if let Some(name) = name.strip_prefix("Thread ") {
+        let (thread_id, thread_name) = name.split_once(' ').unwrap_or((name, ""));
+        match thread_id
+            .strip_prefix("0x")
+            .or_else(|| thread_id.strip_prefix("0X"))
+        {
+            Some(hex) => trace.thread_id = u64::from_str_radix(hex, 16).unwrap_or(0),
+            None => {
+                trace.thread_id = thread_id.parse().unwrap_or(0);
+                trace.os_thread_id = Some(trace.thread_id);
+            }
+        }
+        let thread_name = thread_name.trim_matches('"');
+        if !thread_name.is_empty() {
+            trace.thread_name = Some(thread_name.to_owned());
+        }
+    }
+    trace
+}
+
+/// The number of microseconds in each unit of a profile's weights, or 0 if they aren't times
+fn unit_micros(unit: &ValueUnit) -> f64 {
+    match unit {
+        ValueUnit::Seconds => 1_000_000.0,
+        ValueUnit::Milliseconds => 1_000.0,
+        ValueUnit::Microseconds => 1.0,
+        ValueUnit::Nanoseconds => 0.001,
+        ValueUnit::Bytes | ValueUnit::None => 0.0,
+    }
+}
+
+/// Profiles recorded with --cpu-time have the CPU time that each sample used as its weight,
+/// where other profiles give every sample the same weight of one sampling interval
+fn weighted_by_cpu_time(file: &SpeedscopeFile) -> bool {
+    file.name.as_deref() == Some(CPU_TIME_FILE_NAME)
+}
+
+/// Reads the counts for each stack from a speedscope file, with each stack formatted the same
+/// way as in the raw output (as written by `Flamegraph::write_raw`). Like the raw output, these
+/// are the number of samples, or the CPU time in microseconds for profiles recorded with --cpu-time
+pub fn read_collapsed(contents: &str) -> Result<HashMap<String, usize>, Error> {
+    let file: SpeedscopeFile = serde_json::from_str(contents)?;
+    let frames: Vec<String> = file
+        .shared
+        .frames
+        .iter()
+        .map(|frame| match (frame.file.as_deref(), frame.line) {
+            (Some(file), Some(line)) if line != 0 => format!("{} ({}:{})", frame.name, file, line),
+            (Some(file), _) if !file.is_empty() => format!("{} ({})", frame.name, file),
+            _ => frame.name.clone(),
+        })
+        .collect();
+
+    let cpu_time = weighted_by_cpu_time(&file);
+    let mut counts = HashMap::new();
+    for profile in &file.profiles {
+        let micros = unit_micros(&profile.unit);
+        for (i, sample) in profile.samples.iter().enumerate() {
+            let stack = sample
+                .iter()
+                .map(|&index| {
+                    frames
+                        .get(index)
+                        .map(|frame| frame.as_str())
+                        .ok_or_else(|| {
+                            format_err!("Invalid frame index {} in speedscope file", index)
+                        })
+                })
+                .collect::<Result<Vec<&str>, Error>>()?
+                .join(";");
+            let count = match profile.weights.get(i) {
+                Some(weight) if cpu_time => (weight * micros).round() as usize,
+                _ => 1,
+            };
+            *counts.entry(stack).or_insert(0) += count;
+        }
+    }
+    Ok(counts)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -283,10 +526,13 @@ mod tests {
 
         let frame = stack_trace::Frame {
             name: String::from("test"),
+            qualname: None,
             filename: String::from("test.py"),
             module: None,
             short_filename: None,
             line: 0,
+            columns: Some(stack_trace::ColumnRange { start: 4, end: 9 }),
+            instruction: None,
             locals: None,
             is_entry: true,
             is_shim_entry: false,
@@ -297,8 +543,12 @@ mod tests {
             thread_id: 1,
             thread_name: None,
             os_thread_id: None,
+            interpreter_id: None,
+            task_name: None,
             active: true,
             owns_gil: false,
+            cpu_time: None,
+            labels: Vec::new(),
             frames: vec![frame],
             process_info: None,
         };
@@ -314,5 +564,109 @@ mod tests {
 
         assert_eq!(trace.profiles[0].unit, ValueUnit::Seconds);
         assert_eq!(trace.profiles[0].end_value, 1.0 / sample_rate as f64);
+        assert_eq!(trace.shared.frames[0].col, Some(4));
+
+        let counts = read_collapsed(&s).unwrap();
+        assert_eq!(counts.get("test (test.py)"), Some(&1));
+    }
+
+    #[test]
+    fn test_speedscope_cpu_time() {
+        let mut stats = Stats::new(&Config::default());
+        let trace = |name: &str| stack_trace::StackTrace {
+            pid: 1,
+            thread_id: 1,
+            thread_name: None,
+            os_thread_id: None,
+            interpreter_id: None,
+            task_name: None,
+            active: true,
+            owns_gil: false,
+            cpu_time: None,
+            labels: Vec::new(),
+            frames: vec![stack_trace::Frame {
+                name: name.to_owned(),
+                qualname: None,
+                filename: String::from("test.py"),
+                module: None,
+                short_filename: None,
+                line: 0,
+                columns: None,
+                instruction: None,
+                locals: None,
+                is_entry: true,
+                is_shim_entry: false,
+            }],
+            process_info: None,
+        };
+        stats.record_cpu_time(&trace("busy"), 7500).unwrap();
+        stats.record_cpu_time(&trace("busy"), 2500).unwrap();
+        stats.record_cpu_time(&trace("idle"), 10).unwrap();
+
+        let mut output = Vec::new();
+        stats.write(&mut output).unwrap();
+        let s = String::from_utf8(output).unwrap();
+
+        // the counts are the CPU time in microseconds, like the raw output with --cpu-time
+        let counts = read_collapsed(&s).unwrap();
+        assert_eq!(counts.get("busy (test.py)"), Some(&10000));
+        assert_eq!(counts.get("idle (test.py)"), Some(&10));
+
+        let traces = read_traces(&s, 100).unwrap();
+        let cpu_times: Vec<Option<u64>> = traces.iter().map(|(_, t)| t.cpu_time).collect();
+        assert_eq!(cpu_times, vec![Some(7500), Some(2500), Some(10)]);
+
+        // CPU time is measured in whole clock ticks, so every sample can have the same weight
+        let mut stats = Stats::new(&Config::default());
+        stats.record_cpu_time(&trace("busy"), 10000).unwrap();
+        stats.record_cpu_time(&trace("idle"), 10000).unwrap();
+        let mut output = Vec::new();
+        stats.write(&mut output).unwrap();
+        let s = String::from_utf8(output).unwrap();
+        let counts = read_collapsed(&s).unwrap();
+        assert_eq!(counts.get("busy (test.py)"), Some(&10000));
+        assert_eq!(counts.get("idle (test.py)"), Some(&10000));
+    }
+
+    #[test]
+    fn test_speedscope_labels() {
+        let mut stats = Stats::new(&Config::default());
+        let trace = |tenant: &str| stack_trace::StackTrace {
+            pid: 1,
+            thread_id: 1,
+            thread_name: Some(String::from("MainThread")),
+            os_thread_id: Some(1),
+            interpreter_id: None,
+            task_name: None,
+            active: true,
+            owns_gil: false,
+            cpu_time: None,
+            labels: vec![(String::from("tenant"), tenant.to_owned())],
+            frames: Vec::new(),
+            process_info: None,
+        };
+        stats.record(&trace("acme")).unwrap();
+        stats.record(&trace("acme")).unwrap();
+        stats.record(&trace("initech")).unwrap();
+
+        let mut output = Vec::new();
+        stats.write(&mut output).unwrap();
+        let file: SpeedscopeFile = serde_json::from_slice(&output).unwrap();
+        let names: Vec<&str> = file.profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "Thread 1 \"MainThread\" [tenant=acme]",
+                "Thread 1 \"MainThread\" [tenant=initech]"
+            ]
+        );
+        assert_eq!(file.profiles[0].samples.len(), 2);
+
+        let trace = thread_trace(&file.profiles[1].name);
+        assert_eq!(trace.thread_name.as_deref(), Some("MainThread"));
+        assert_eq!(
+            trace.labels,
+            vec![(String::from("tenant"), String::from("initech"))]
+        );
     }
 }
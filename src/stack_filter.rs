@@ -0,0 +1,257 @@
+use anyhow::{Context, Error};
+use regex::Regex;
+
+use crate::config::{Aggregate, Config};
+use crate::sampler::Sample;
+use crate::stack_trace::{Frame, StackTrace};
+
+/// Drops samples and prunes frames from stack traces before they are recorded, based on the
+/// --include, --exclude, --focus and --aggregate options
+pub struct StackFilter {
+    include: Option<Regex>,
+    exclude: Option<Regex>,
+    focus: Option<Regex>,
+    aggregate: Aggregate,
+}
+
+impl StackFilter {
+    /// Creates a new filter from the config, returning None if there is nothing to filter
+    pub fn new(config: &Config) -> Result<Option<StackFilter>, Error> {
+        if config.include.is_none()
+            && config.exclude.is_none()
+            && config.focus.is_none()
+            && config.aggregate == Aggregate::function
+        {
+            return Ok(None);
+        }
+
+        let compile = |option: &str, pattern: &Option<String>| -> Result<Option<Regex>, Error> {
+            pattern
+                .as_ref()
+                .map(|pattern| {
+                    Regex::new(pattern).context(format!("Invalid --{option} regex '{pattern}'"))
+                })
+                .transpose()
+        };
+
+        Ok(Some(StackFilter {
+            include: compile("include", &config.include)?,
+            exclude: compile("exclude", &config.exclude)?,
+            focus: compile("focus", &config.focus)?,
+            aggregate: config.aggregate,
+        }))
+    }
+
+    /// Filters every trace in a sample, removing the traces that shouldn't be recorded
+    pub fn filter_sample(&self, sample: &mut Sample) {
+        sample.traces.retain_mut(|trace| self.filter_trace(trace));
+    }
+
+    /// Filters the frames of a single trace, returning false if the trace should be dropped
+    pub fn filter_trace(&self, trace: &mut StackTrace) -> bool {
+        if let Some(include) = &self.include {
+            if !trace
+                .frames
+                .iter()
+                .any(|frame| frame_matches(include, frame))
+            {
+                return false;
+            }
+        }
+
+        // frames are stored leaf first, so re-root the stack at the outermost matching call
+        if let Some(focus) = &self.focus {
+            match trace
+                .frames
+                .iter()
+                .rposition(|frame| focus.is_match(frame.display_name()))
+            {
+                Some(root) => trace.frames.truncate(root + 1),
+                None => return false,
+            }
+        }
+
+        if let Some(exclude) = &self.exclude {
+            trace.frames.retain(|frame| !frame_matches(exclude, frame));
+        }
+
+        if self.aggregate != Aggregate::function {
+            aggregate_frames(&mut trace.frames, self.aggregate);
+        }
+
+        !trace.frames.is_empty()
+    }
+}
+
+/// Replaces each frame with a frame named after its module or package, merging consecutive
+/// frames that end up with the same name
+fn aggregate_frames(frames: &mut Vec<Frame>, aggregate: Aggregate) {
+    for frame in frames.iter_mut() {
+        if let Some(label) = aggregate_label(frame, aggregate) {
+            *frame = Frame {
+                name: label,
+                qualname: None,
+                filename: String::new(),
+                module: None,
+                short_filename: None,
+                line: 0,
+                columns: None,
+                instruction: None,
+                locals: None,
+                is_entry: frame.is_entry,
+                is_shim_entry: frame.is_shim_entry,
+            };
+        }
+    }
+    frames.dedup_by(|a, b| a.name == b.name && a.filename.is_empty() && b.filename.is_empty());
+}
+
+/// Gets the module or package name for a frame. Python frames use the short filename, which
+/// only includes the directories with an __init__.py, and native frames use their library name
+fn aggregate_label(frame: &Frame, aggregate: Aggregate) -> Option<String> {
+    if frame.short_filename.is_none() {
+        if let Some(module) = &frame.module {
+            let library = std::path::Path::new(module).file_name()?;
+            return Some(library.to_string_lossy().into_owned());
+        }
+    }
+
+    let filename = frame.short_filename.as_ref().unwrap_or(&frame.filename);
+    let mut parts: Vec<&str> = filename
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty())
+        .collect();
+    let last = parts.pop()?;
+    parts.push(last.strip_suffix(".py").unwrap_or(last));
+
+    match aggregate {
+        Aggregate::function => None,
+        Aggregate::module => {
+            if parts.len() > 1 && parts.last() == Some(&"__init__") {
+                parts.pop();
+            }
+            Some(parts.join("."))
+        }
+        Aggregate::package => Some(parts[0].to_owned()),
+    }
+}
+
+fn frame_matches(regex: &Regex, frame: &Frame) -> bool {
+    regex.is_match(frame.display_name()) || regex.is_match(&frame.filename)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(name: &str, filename: &str) -> Frame {
+        Frame {
+            short_filename: Some(filename.trim_start_matches("/app/").to_owned()),
+            ..crate::stack_trace::tests::frame(name, filename, 1)
+        }
+    }
+
+    fn trace(frames: &[(&str, &str)]) -> StackTrace {
+        crate::stack_trace::tests::trace(
+            frames
+                .iter()
+                .map(|(name, filename)| frame(name, filename))
+                .collect(),
+        )
+    }
+
+    fn names(trace: &StackTrace) -> Vec<&str> {
+        trace.frames.iter().map(|f| f.name.as_str()).collect()
+    }
+
+    fn filter(include: Option<&str>, exclude: Option<&str>, focus: Option<&str>) -> StackFilter {
+        let config = Config {
+            include: include.map(|s| s.to_owned()),
+            exclude: exclude.map(|s| s.to_owned()),
+            focus: focus.map(|s| s.to_owned()),
+            ..Default::default()
+        };
+        StackFilter::new(&config).unwrap().unwrap()
+    }
+
+    fn filter_aggregate(aggregate: Aggregate) -> StackFilter {
+        let config = Config {
+            aggregate,
+            ..Default::default()
+        };
+        StackFilter::new(&config).unwrap().unwrap()
+    }
+
+    #[test]
+    fn test_filter_trace() {
+        assert!(StackFilter::new(&Config::default()).unwrap().is_none());
+
+        let stack = trace(&[
+            ("sleep", "/usr/lib/python3/time.py"),
+            ("handle", "/app/server.py"),
+            ("dispatch", "/app/server.py"),
+            ("handle", "/app/server.py"),
+            ("<module>", "/app/main.py"),
+        ]);
+
+        let include = filter(Some("main.py"), None, None);
+        assert!(include.filter_trace(&mut stack.clone()));
+        let include = filter(Some("missing"), None, None);
+        assert!(!include.filter_trace(&mut stack.clone()));
+
+        let mut focused = stack.clone();
+        assert!(filter(None, None, Some("^handle$")).filter_trace(&mut focused));
+        assert_eq!(
+            names(&focused),
+            vec!["sleep", "handle", "dispatch", "handle"]
+        );
+        assert!(!filter(None, None, Some("missing")).filter_trace(&mut stack.clone()));
+
+        let mut excluded = stack.clone();
+        assert!(filter(None, Some("python3|dispatch"), None).filter_trace(&mut excluded));
+        assert_eq!(names(&excluded), vec!["handle", "handle", "<module>"]);
+        assert!(!filter(None, Some(".*"), None).filter_trace(&mut stack.clone()));
+
+        let mut combined = stack.clone();
+        assert!(
+            filter(Some("server"), Some("^sleep$"), Some("dispatch")).filter_trace(&mut combined)
+        );
+        assert_eq!(names(&combined), vec!["handle", "dispatch"]);
+    }
+
+    #[test]
+    fn test_aggregate() {
+        let stack = trace(&[
+            ("execute", "/app/sqlalchemy/engine/base.py"),
+            ("query", "/app/sqlalchemy/orm/session.py"),
+            ("__init__", "/app/sqlalchemy/orm/__init__.py"),
+            ("handle", "/app/server.py"),
+            ("<module>", "/app/main.py"),
+        ]);
+
+        let mut packages = stack.clone();
+        assert!(filter_aggregate(Aggregate::package).filter_trace(&mut packages));
+        assert_eq!(names(&packages), vec!["sqlalchemy", "server", "main"]);
+
+        let mut modules = stack.clone();
+        assert!(filter_aggregate(Aggregate::module).filter_trace(&mut modules));
+        assert_eq!(
+            names(&modules),
+            vec![
+                "sqlalchemy.engine.base",
+                "sqlalchemy.orm.session",
+                "sqlalchemy.orm",
+                "server",
+                "main"
+            ]
+        );
+
+        let mut native = frame("PyEval_EvalFrame", "ceval.c");
+        native.module = Some(String::from("/usr/lib/libpython3.11.so.1.0"));
+        native.short_filename = None;
+        assert_eq!(
+            aggregate_label(&native, Aggregate::package),
+            Some(String::from("libpython3.11.so.1.0"))
+        );
+    }
+}
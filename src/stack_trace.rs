@@ -1,3 +1,5 @@
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use anyhow::{Context, Error, Result};
@@ -6,13 +8,16 @@ use remoteprocess::{Pid, ProcessMemory};
 use serde_derive::Serialize;
 
 use crate::config::{Config, LineNo};
+use crate::python_bindings::v3_13_0::_Py_DebugOffsets;
+use crate::python_data_access::copy_type_name;
 use crate::python_data_access::{copy_bytes, copy_string};
 use crate::python_interpreters::{
-    CodeObject, FrameObject, InterpreterState, ThreadState, TupleObject,
+    copy_pointer, BytesObject, CodeObject, FrameObject, InterpreterState, Object, RemoteStruct,
+    ThreadState, TupleObject,
 };
 
 /// Call stack for a single python thread
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Default, Serialize)]
 pub struct StackTrace {
     /// The process id than generated this stack trace
     pub pid: Pid,
@@ -22,10 +27,18 @@ pub struct StackTrace {
     pub thread_name: Option<String>,
     /// The OS thread id for this stack tracee
     pub os_thread_id: Option<u64>,
+    /// The id of the python interpreter this thread belongs to (0 is the main interpreter)
+    pub interpreter_id: Option<i64>,
+    /// The name of the asyncio task running in this thread (only set in async mode)
+    pub task_name: Option<String>,
     /// Whether or not the thread was active
     pub active: bool,
     /// Whether or not the thread held the GIL
     pub owns_gil: bool,
+    /// The CPU time in microseconds the thread used since the previous sample (only set with --cpu-time)
+    pub cpu_time: Option<u64>,
+    /// The values read from the process for each --label option, as (name, value) pairs
+    pub labels: Vec<(String, String)>,
     /// The frames
     pub frames: Vec<Frame>,
     /// process commandline / parent process info
@@ -33,10 +46,13 @@ pub struct StackTrace {
 }
 
 /// Information about a single function call in a stack trace
-#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Serialize)]
+#[derive(Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Serialize)]
 pub struct Frame {
     /// The function name
     pub name: String,
+    /// The qualified function name including the class (like 'Class.method'), if it differs
+    /// from the function name
+    pub qualname: Option<String>,
     /// The full filename of the file
     pub filename: String,
     /// The module/shared library the
@@ -45,6 +61,10 @@ pub struct Frame {
     pub short_filename: Option<String>,
     /// The line number inside the file (or 0 for native frames without line information)
     pub line: i32,
+    /// The range of columns on the line for the current instruction (only available in python 3.11+)
+    pub columns: Option<ColumnRange>,
+    /// The bytecode instruction being executed (only set in --instructions mode)
+    pub instruction: Option<Instruction>,
     /// Local Variables associated with the frame
     pub locals: Option<Vec<LocalVariable>>,
     /// If this is an entry frame. Each entry frame corresponds to one native frame (Python 3.11)
@@ -53,6 +73,24 @@ pub struct Frame {
     pub is_shim_entry: bool,
 }
 
+/// The start and end columns of an expression on a line, as 0-based utf8 byte offsets
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Serialize)]
+pub struct ColumnRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+/// A single bytecode instruction
+#[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Serialize)]
+pub struct Instruction {
+    /// The byte offset of the instruction in the bytecode
+    pub offset: u32,
+    /// The opcode of the instruction
+    pub opcode: u8,
+    /// The opcode name, if the opcode is known for the python version being profiled
+    pub name: Option<&'static str>,
+}
+
 #[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd, Clone, Serialize)]
 pub struct LocalVariable {
     pub name: String,
@@ -68,10 +106,62 @@ pub struct ProcessInfo {
     pub parent: Option<Box<ProcessInfo>>,
 }
 
+/// A python interpreter in the target process
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Interpreter {
+    /// The address of the PyInterpreterState
+    pub address: usize,
+    /// The interpreter id (0 for the main interpreter)
+    pub id: i64,
+}
+
+/// Returns all the interpreters in the process, by following the linked list of interpreters
+/// starting at interpreter_address. Python versions before 3.7 don't store an id on the
+/// interpreter, so there we use the position in the list instead (the main interpreter is always
+/// the last entry, since new interpreters get added to the head of the list)
+pub fn get_interpreters<I, P>(
+    interpreter_address: usize,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+) -> Result<Vec<Interpreter>, Error>
+where
+    I: InterpreterState,
+    P: ProcessMemory,
+{
+    let mut addresses = Vec::new();
+    let mut address = interpreter_address;
+    while address != 0 {
+        addresses.push(address);
+        if addresses.len() > 1024 {
+            return Err(format_err!("Max interpreter recursion depth reached"));
+        }
+        address = process
+            .copy_pointer(I::next_ptr_ptr(address, offsets))
+            .context("Failed to copy next PyInterpreterState pointer")? as usize;
+    }
+
+    let count = addresses.len();
+    addresses
+        .into_iter()
+        .enumerate()
+        .map(|(i, address)| {
+            let id = match I::id_ptr(address, offsets) {
+                Some(id_ptr) => process
+                    .copy_pointer(id_ptr)
+                    .context("Failed to copy interpreter id")?,
+                None => (count - i - 1) as i64,
+            };
+            Ok(Interpreter { address, id })
+        })
+        .collect()
+}
+
 /// Given an InterpreterState, this function returns a vector of stack traces for each thread
+/// in each interpreter
 pub fn get_stack_traces<I, P>(
     interpreter_address: usize,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     threadstate_address: usize,
     config: Option<&Config>,
 ) -> Result<Vec<StackTrace>, Error>
@@ -79,42 +169,66 @@ where
     I: InterpreterState,
     P: ProcessMemory,
 {
-    let gil_thread_id = get_gil_threadid::<I, P>(threadstate_address, process)?;
-
-    let threadstate_ptr_ptr = I::threadstate_ptr_ptr(interpreter_address);
-    let mut threads: *const I::ThreadState = process
-        .copy_struct(threadstate_ptr_ptr as usize)
-        .context("Failed to copy PyThreadState head pointer")?;
-
     let mut ret = Vec::new();
 
     let lineno = config.map(|c| c.lineno).unwrap_or(LineNo::NoLine);
     let dump_locals = config.map(|c| c.dump_locals).unwrap_or(0);
-
-    while !threads.is_null() {
-        let thread = process
-            .copy_pointer(threads)
-            .context("Failed to copy PyThreadState")?;
-
-        let mut trace = get_stack_trace(&thread, process, dump_locals > 0, lineno)?;
-        trace.owns_gil = trace.thread_id == gil_thread_id;
-
-        ret.push(trace);
-        // This seems to happen occasionally when scanning BSS addresses for valid interpreters
-        if ret.len() > 4096 {
-            return Err(format_err!("Max thread recursion depth reached"));
+    let qualified_names = config.is_some_and(|c| c.qualified_names);
+    let mut arg_names = HashMap::new();
+
+    for interpreter in get_interpreters::<I, P>(interpreter_address, process, offsets)? {
+        let gil_thread_id = get_interpreter_gil_threadid::<I, P>(
+            interpreter.address,
+            threadstate_address,
+            process,
+            offsets,
+        )?;
+
+        let threadstate_ptr_ptr = I::threadstate_ptr_ptr(interpreter.address, offsets);
+        let mut threads: *const I::ThreadState = process
+            .copy_struct(threadstate_ptr_ptr as usize)
+            .context("Failed to copy PyThreadState head pointer")?;
+
+        while !threads.is_null() {
+            let thread =
+                copy_pointer(process, threads, offsets).context("Failed to copy PyThreadState")?;
+
+            let mut trace = get_stack_trace(
+                &thread,
+                process,
+                offsets,
+                dump_locals > 0,
+                lineno,
+                qualified_names,
+                false,
+                &mut arg_names,
+            )?;
+            trace.owns_gil = trace.thread_id == gil_thread_id;
+            trace.interpreter_id = Some(interpreter.id);
+
+            ret.push(trace);
+            // This seems to happen occasionally when scanning BSS addresses for valid interpreters
+            if ret.len() > 4096 {
+                return Err(format_err!("Max thread recursion depth reached"));
+            }
+            threads = thread.next();
         }
-        threads = thread.next();
     }
     Ok(ret)
 }
 
-/// Gets a stack trace for an individual thread
+/// Gets a stack trace for an individual thread. `arg_names` caches the name of the first
+/// argument of each code object by its address, and can be kept between calls
+#[allow(clippy::too_many_arguments)]
 pub fn get_stack_trace<T, P>(
     thread: &T,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
     copy_locals: bool,
     lineno: LineNo,
+    qualified_names: bool,
+    instructions: bool,
+    arg_names: &mut HashMap<usize, String>,
 ) -> Result<StackTrace, Error>
 where
     T: ThreadState,
@@ -141,16 +255,16 @@ where
     };
 
     while !frame_ptr.is_null() {
-        let frame = process
-            .copy_pointer(frame_ptr)
-            .context("Failed to copy PyFrameObject")?;
+        let frame =
+            copy_pointer(process, frame_ptr, offsets).context("Failed to copy PyFrameObject")?;
 
-        let code = process
-            .copy_pointer(frame.code())
-            .context("Failed to copy PyCodeObject")?;
+        let code =
+            copy_pointer(process, frame.code(), offsets).context("Failed to copy PyCodeObject")?;
 
-        let filename = copy_string(code.filename(), process).context("Failed to copy filename");
-        let name = copy_string(code.name(), process).context("Failed to copy function name");
+        let filename =
+            copy_string(code.filename(), process, offsets).context("Failed to copy filename");
+        let name =
+            copy_string(code.name(), process, offsets).context("Failed to copy function name");
 
         // just skip processing the current frame if we can't load the filename or function name.
         // this can happen in python 3.13+ since the f_executable isn't guaranteed to be
@@ -175,11 +289,11 @@ where
             continue;
         }
 
-        let line = match lineno {
-            LineNo::NoLine => 0,
-            LineNo::First => code.first_lineno(),
-            LineNo::LastInstruction => match get_line_number(&code, frame.lasti(), process) {
-                Ok(line) => line,
+        let (line, columns) = match lineno {
+            LineNo::NoLine => (0, None),
+            LineNo::First => (code.first_lineno(), None),
+            LineNo::LastInstruction => match get_location(&code, frame.lasti(), process, offsets) {
+                Ok(location) => location,
                 Err(e) => {
                     // Failling to get the line number really shouldn't be fatal here, but
                     // can happen in extreme cases (https://github.com/benfred/py-spy/issues/164)
@@ -189,13 +303,38 @@ where
                         "Failed to get line number from {}.{}: {}",
                         filename, name, e
                     );
-                    0
+                    (0, None)
                 }
             },
         };
 
         let locals = if copy_locals {
-            Some(get_locals(&code, frame_ptr, &frame, process)?)
+            Some(get_locals(&code, frame_ptr, &frame, process, offsets)?)
+        } else {
+            None
+        };
+
+        let qualname = if qualified_names {
+            get_qualname::<T, P>(&code, frame_ptr, &frame, process, offsets, &name, arg_names)
+        } else {
+            None
+        };
+
+        let instruction = if instructions {
+            get_instruction(
+                &code,
+                frame.code() as usize,
+                frame.lasti(),
+                process,
+                offsets,
+            )
+            .unwrap_or_else(|e| {
+                warn!(
+                    "Failed to get instruction from {}.{}: {}",
+                    filename, name, e
+                );
+                None
+            })
         } else {
             None
         };
@@ -204,8 +343,11 @@ where
 
         frames.push(Frame {
             name,
+            qualname,
             filename,
             line,
+            columns,
+            instruction,
             short_filename: None,
             module: None,
             locals,
@@ -228,12 +370,34 @@ where
         thread_id: thread.thread_id(),
         thread_name: None,
         owns_gil: false,
+        cpu_time: None,
+        labels: Vec::new(),
         active: true,
         os_thread_id: thread.native_thread_id(),
+        interpreter_id: None,
+        task_name: None,
         process_info: None,
     })
 }
 
+impl Frame {
+    /// Creates a frame that isn't from python code, like the ones added to the root of a stack
+    /// trace to show the thread or process that it came from
+    pub fn shim(name: String) -> Frame {
+        Frame {
+            name,
+            is_entry: true,
+            is_shim_entry: true,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the name to show for this frame, which is the qualified name when we have it
+    pub fn display_name(&self) -> &str {
+        self.qualname.as_deref().unwrap_or(&self.name)
+    }
+}
+
 impl StackTrace {
     pub fn status_str(&self) -> &str {
         match (self.owns_gil, self.active) {
@@ -257,15 +421,138 @@ impl StackTrace {
     }
 }
 
-/// Returns the line number from a PyCodeObject (given the lasti index from a PyFrameObject)
-fn get_line_number<C: CodeObject, P: ProcessMemory>(
+/// Returns the line number and columns from a PyCodeObject (given the lasti index from a
+/// PyFrameObject)
+pub(crate) fn get_location<C: CodeObject, P: ProcessMemory>(
+    code: &C,
+    lasti: i32,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+) -> Result<(i32, Option<ColumnRange>), Error> {
+    let table = copy_bytes(code.line_table(), process, offsets)
+        .context("Failed to copy line number table")?;
+    let (line, columns) = code.get_location(lasti, &table);
+    Ok((line, columns.map(|(start, end)| ColumnRange { start, end })))
+}
+
+/// Reads the bytecode instruction at lasti from a PyCodeObject. The opcode name depends on the
+/// python version, and is left for the caller to fill in
+pub(crate) fn get_instruction<C: CodeObject, P: ProcessMemory>(
     code: &C,
+    code_address: usize,
     lasti: i32,
     process: &P,
-) -> Result<i32, Error> {
-    let table =
-        copy_bytes(code.line_table(), process).context("Failed to copy line number table")?;
-    Ok(code.get_line_number(lasti, &table))
+    offsets: Option<&_Py_DebugOffsets>,
+) -> Result<Option<Instruction>, Error> {
+    let offset = code.instruction_offset(lasti);
+    // frames that haven't started executing don't have a current instruction
+    if offset < 0 {
+        return Ok(None);
+    }
+
+    let address = match code.bytecode() {
+        Some(bytecode) => {
+            let bytes =
+                copy_pointer(process, bytecode, offsets).context("Failed to copy co_code")?;
+            if offset as usize >= bytes.size() {
+                return Err(format_err!("Instruction offset {} is out of range", offset));
+            }
+            bytes.address(bytecode as usize) + offset as usize
+        }
+        // python 3.11+ stores the bytecode inline in the code object, with lasti being
+        // relative to the start of the code object
+        None => code_address + lasti as usize,
+    };
+
+    let opcode: u8 = process
+        .copy_struct(address)
+        .context("Failed to copy opcode")?;
+    Ok(Some(Instruction {
+        offset: offset as u32,
+        opcode,
+        name: None,
+    }))
+}
+
+/// Gets the qualified name of a function. Python 3.11+ stores this on the code object, for older
+/// versions we guess the class from the type of the 'self' or 'cls' argument - which will be the
+/// subclass rather than the class defining the method for inherited methods
+pub(crate) fn get_qualname<T: ThreadState, P: ProcessMemory>(
+    code: &<T::FrameObject as FrameObject>::CodeObject,
+    frameptr: *const T::FrameObject,
+    frame: &T::FrameObject,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    name: &str,
+    arg_names: &mut HashMap<usize, String>,
+) -> Option<String> {
+    let qualname = match code.qualname() {
+        Some(qualname) => copy_string(qualname, process, offsets).ok()?,
+        None => {
+            let class = get_class_name::<T, P>(code, frameptr, frame, process, offsets, arg_names)
+                .ok()??;
+            format!("{class}.{name}")
+        }
+    };
+    if qualname == name {
+        None
+    } else {
+        Some(qualname)
+    }
+}
+
+/// Returns the class name of the first argument of a function, if it is named 'self' or 'cls'.
+/// The argument name is cached by code object, so that other functions only cost a lookup
+fn get_class_name<T: ThreadState, P: ProcessMemory>(
+    code: &<T::FrameObject as FrameObject>::CodeObject,
+    frameptr: *const T::FrameObject,
+    frame: &T::FrameObject,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+    arg_names: &mut HashMap<usize, String>,
+) -> Result<Option<String>, Error> {
+    type PyObject<T> = <<T as ThreadState>::InterpreterState as InterpreterState>::Object;
+    type PyTypeObject<T> = <PyObject<T> as Object>::TypeObject;
+    type StringObject<T> =
+        <<<T as ThreadState>::FrameObject as FrameObject>::CodeObject as CodeObject>::StringObject;
+
+    let argname = match arg_names.entry(frame.code() as usize) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            // functions without arguments are cached with an empty name
+            let argname = if code.argcount() == 0 {
+                String::new()
+            } else {
+                let varnames = copy_pointer(process, code.varnames(), offsets)?;
+                let nameptr: *const StringObject<T> =
+                    process.copy_struct(varnames.address(code.varnames() as usize, 0))?;
+                copy_string(nameptr, process, offsets)?
+            };
+            entry.insert(argname)
+        }
+    };
+    if argname != "self" && argname != "cls" {
+        return Ok(None);
+    }
+
+    let addr: usize = process.copy_struct(frameptr as usize + frame.locals_offset())?;
+    if addr == 0 {
+        return Ok(None);
+    }
+
+    let class = if argname == "self" {
+        let object = PyObject::<T>::copy_from(process, addr, offsets)?;
+        copy_pointer(process, object.ob_type(), offsets)?
+    } else {
+        PyTypeObject::<T>::copy_from(process, addr, offsets)?
+    };
+
+    // builtin types include the module in tp_name (like 'collections.OrderedDict')
+    let class = copy_type_name(&class, process)?;
+    Ok(Some(match class.rsplit_once('.') {
+        Some((_, class)) => class.to_owned(),
+        None => class,
+    }))
 }
 
 fn get_locals<C: CodeObject, F: FrameObject, P: ProcessMemory>(
@@ -273,20 +560,30 @@ fn get_locals<C: CodeObject, F: FrameObject, P: ProcessMemory>(
     frameptr: *const F,
     frame: &F,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
 ) -> Result<Vec<LocalVariable>, Error> {
-    let local_count = code.nlocals() as usize;
+    let local_count = match code.localspluskinds() {
+        Some(kinds) => {
+            const CO_FAST_LOCAL: u8 = 0x20;
+            copy_bytes(kinds, process, offsets)?
+                .iter()
+                .filter(|&&kind| kind & CO_FAST_LOCAL != 0)
+                .count()
+        }
+        None => code.nlocals() as usize,
+    };
     let argcount = code.argcount() as usize;
-    let varnames = process.copy_pointer(code.varnames())?;
+    let varnames = copy_pointer(process, code.varnames(), offsets)?;
 
     let ptr_size = std::mem::size_of::<*const i32>();
-    let locals_addr = frameptr as usize + std::mem::size_of_val(frame) - ptr_size;
+    let locals_addr = frameptr as usize + frame.locals_offset();
 
     let mut ret = Vec::new();
 
     for i in 0..local_count {
         let nameptr: *const C::StringObject =
             process.copy_struct(varnames.address(code.varnames() as usize, i))?;
-        let name = copy_string(nameptr, process)?;
+        let name = copy_string(nameptr, process, offsets)?;
         let addr: usize = process.copy_struct(locals_addr + i * ptr_size)?;
         if addr == 0 {
             continue;
@@ -304,13 +601,35 @@ fn get_locals<C: CodeObject, F: FrameObject, P: ProcessMemory>(
 pub fn get_gil_threadid<I: InterpreterState, P: ProcessMemory>(
     threadstate_address: usize,
     process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
 ) -> Result<u64, Error> {
     // happens during initialization when checking to see if we have a valid interpreter (before we've figured out the threadstate_address)
     if threadstate_address == 0 {
         return Ok(0);
     }
 
-    let addr = if I::HAS_GIL_RUNTIME_STATE {
+    let addr = if let Some(gil_offsets) = I::gil_state_offsets(offsets) {
+        let enabled = match gil_offsets.enabled {
+            Some(enabled) => process.copy_struct::<i32>(threadstate_address + enabled)? != 0,
+            None => true,
+        };
+        let locked: i32 = process.copy_struct(threadstate_address + gil_offsets.locked)?;
+        if enabled && locked != 0 {
+            process.copy_struct::<usize>(threadstate_address + gil_offsets.last_holder)?
+        } else {
+            0
+        }
+    } else if I::FREE_THREADED {
+        // free-threaded builds only have a GIL if it has been re-enabled at runtime (for
+        // instance by importing an extension that doesn't support running without it)
+        let gil_state: crate::python_bindings::v3_13_0t::_gil_runtime_state =
+            process.copy_struct(threadstate_address)?;
+        if gil_state.enabled != 0 && gil_state.locked != 0 {
+            gil_state.last_holder as usize
+        } else {
+            0
+        }
+    } else if I::HAS_GIL_RUNTIME_STATE {
         // get the gilruntimestate - note that this struct is identical between 3.12/3.13
         let gil_state: crate::python_bindings::v3_13_0::_gil_runtime_state =
             process.copy_struct(threadstate_address)?;
@@ -326,7 +645,7 @@ pub fn get_gil_threadid<I: InterpreterState, P: ProcessMemory>(
 
     // if the addr is 0, no thread is currently holding the GIL
     let threadid = if addr != 0 {
-        let threadstate: I::ThreadState = process.copy_struct(addr)?;
+        let threadstate = I::ThreadState::copy_from(process, addr, offsets)?;
         threadstate.thread_id()
     } else {
         0
@@ -335,37 +654,172 @@ pub fn get_gil_threadid<I: InterpreterState, P: ProcessMemory>(
     Ok(threadid)
 }
 
+/// Returns the python thread id holding the GIL for an interpreter. Python 3.12+ can have a
+/// separate GIL per subinterpreter, earlier versions share the GIL at threadstate_address
+pub fn get_interpreter_gil_threadid<I: InterpreterState, P: ProcessMemory>(
+    interpreter_address: usize,
+    threadstate_address: usize,
+    process: &P,
+    offsets: Option<&_Py_DebugOffsets>,
+) -> Result<u64, Error> {
+    // a threadstate_address of 0 means we aren't tracking the GIL at all
+    let gil_address = match I::gil_ptr_ptr(interpreter_address, offsets) {
+        Some(gil_ptr_ptr) if threadstate_address != 0 => process.copy_pointer(gil_ptr_ptr)?,
+        _ => threadstate_address,
+    };
+    get_gil_threadid::<I, P>(gil_address, process, offsets)
+}
+
 impl ProcessInfo {
     pub fn to_frame(&self) -> Frame {
-        Frame {
-            name: format!("process {}:\"{}\"", self.pid, self.command_line),
-            filename: String::from(""),
-            module: None,
-            short_filename: None,
-            line: 0,
-            locals: None,
-            is_entry: true,
-            is_shim_entry: true,
-        }
+        Frame::shim(format!("process {}:\"{}\"", self.pid, self.command_line))
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub mod tests {
     use super::*;
-    use crate::python_bindings::v3_7_0::PyCodeObject;
-    use crate::python_data_access::tests::to_byteobject;
+    use crate::python_bindings::v3_7_0::{
+        _frame, PyCodeObject, PyObject, PyThreadState, PyTupleObject, PyTypeObject,
+    };
+    use crate::python_data_access::tests::{to_asciiobject, to_byteobject};
     use remoteprocess::LocalProcess;
 
+    /// Creates a python frame for tests of the code that handles the sampled stack traces
+    pub fn frame(name: &str, filename: &str, line: i32) -> Frame {
+        Frame {
+            name: name.to_owned(),
+            filename: filename.to_owned(),
+            line,
+            is_entry: true,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a stack trace of an active thread, with the innermost frame first
+    pub fn trace(frames: Vec<Frame>) -> StackTrace {
+        StackTrace {
+            pid: 1,
+            thread_id: 1,
+            active: true,
+            frames,
+            ..Default::default()
+        }
+    }
+
     #[test]
-    fn test_get_line_number() {
+    fn test_get_location() {
         let mut lnotab = to_byteobject(&[0u8, 1, 10, 1, 8, 1, 4, 1]);
         let code = PyCodeObject {
             co_firstlineno: 3,
             co_lnotab: &mut lnotab.base.ob_base.ob_base,
             ..Default::default()
         };
-        let lineno = get_line_number(&code, 30, &LocalProcess).unwrap();
-        assert_eq!(lineno, 7);
+        let location = get_location(&code, 30, &LocalProcess, None).unwrap();
+        assert_eq!(location, (7, None));
+    }
+
+    #[test]
+    fn test_get_instruction() {
+        // LOAD_CONST 1, BINARY_ADD, RETURN_VALUE
+        let mut bytecode = to_byteobject(&[100u8, 1, 23, 0, 83, 0]);
+        let code = PyCodeObject {
+            co_code: &mut bytecode.base.ob_base.ob_base,
+            ..Default::default()
+        };
+        let instruction = get_instruction(&code, 0, 2, &LocalProcess, None).unwrap();
+        assert_eq!(
+            instruction,
+            Some(Instruction {
+                offset: 2,
+                opcode: 23,
+                name: None
+            })
+        );
+
+        // frames that haven't started yet have a lasti of -1
+        assert_eq!(
+            get_instruction(&code, 0, -1, &LocalProcess, None).unwrap(),
+            None
+        );
+        assert!(get_instruction(&code, 0, 6, &LocalProcess, None).is_err());
+    }
+
+    #[test]
+    fn test_get_qualname() {
+        let tp_name = std::ffi::CString::new("app.Worker").unwrap();
+        let mut class = PyTypeObject {
+            tp_name: tp_name.as_ptr(),
+            ..Default::default()
+        };
+        let mut object = PyObject {
+            ob_type: &mut class,
+            ..Default::default()
+        };
+
+        let qualname = |argname: &str, local: *mut PyObject| {
+            let mut argname = to_asciiobject(argname);
+            let mut varnames = PyTupleObject {
+                ob_item: [&mut argname as *mut _ as *mut _],
+                ..Default::default()
+            };
+            let code = PyCodeObject {
+                co_argcount: 1,
+                co_varnames: &mut varnames as *mut _ as *mut _,
+                ..Default::default()
+            };
+            let frame = _frame {
+                f_localsplus: [local],
+                ..Default::default()
+            };
+            let mut arg_names = HashMap::new();
+            get_qualname::<PyThreadState, _>(
+                &code,
+                &frame,
+                &frame,
+                &LocalProcess,
+                None,
+                "work",
+                &mut arg_names,
+            )
+        };
+
+        // python 3.10 and earlier don't have co_qualname, so the class comes from the arguments
+        assert_eq!(
+            qualname("self", &mut object).as_deref(),
+            Some("Worker.work")
+        );
+        assert_eq!(
+            qualname("cls", &mut class as *mut _ as *mut _).as_deref(),
+            Some("Worker.work")
+        );
+        assert_eq!(qualname("value", &mut object), None);
+
+        // the argument name is only read once for each code object
+        let mut argname = to_asciiobject("self");
+        let mut varnames = PyTupleObject {
+            ob_item: [&mut argname as *mut _ as *mut _],
+            ..Default::default()
+        };
+        let code = PyCodeObject {
+            co_argcount: 1,
+            co_varnames: &mut varnames as *mut _ as *mut _,
+            ..Default::default()
+        };
+        let mut arg_names = HashMap::from([(0, "value".to_owned())]);
+        let frame = _frame {
+            f_localsplus: [&mut object],
+            ..Default::default()
+        };
+        let qualname = get_qualname::<PyThreadState, _>(
+            &code,
+            &frame,
+            &frame,
+            &LocalProcess,
+            None,
+            "work",
+            &mut arg_names,
+        );
+        assert_eq!(qualname, None);
     }
 }
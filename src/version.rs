@@ -10,13 +10,15 @@ pub struct Version {
     pub patch: u64,
     pub release_flags: String,
     pub build_metadata: Option<String>,
+    /// Whether this is a free-threaded build of python, that can run without the GIL
+    pub free_threaded: bool,
 }
 
 impl Version {
     pub fn scan_bytes(data: &[u8]) -> Result<Version, Error> {
         lazy_static! {
             static ref RE: Regex = Regex::new(
-                r"((2|3)\.(3|4|5|6|7|8|9|10|11|12|13)\.(\d{1,2}))((a|b|c|rc)\d{1,2})?(\+(?:[0-9a-z-]+(?:[.][0-9a-z-]+)*)?)? (.{1,64})"
+                r"((2|3)\.(3|4|5|6|7|8|9|1\d)\.(\d{1,2}))((a|b|c|rc)\d{1,2})?(\+(?:[0-9a-z-]+(?:[.][0-9a-z-]+)*)?)? (.{1,64})"
             )
             .unwrap();
         }
@@ -35,6 +37,10 @@ impl Version {
                 None
             };
 
+            // free-threaded builds have a sys.version like
+            // '3.13.0 experimental free-threading build (main, Oct  8 2024, 08:51:28)'
+            let free_threaded = String::from_utf8_lossy(&cap[8]).contains("free-threading");
+
             let version = std::str::from_utf8(&cap[0])?;
             info!("Found matching version string '{}'", version);
             #[cfg(windows)]
@@ -54,6 +60,7 @@ impl Version {
                 patch,
                 release_flags: release.to_owned(),
                 build_metadata,
+                free_threaded,
             });
         }
         Err(format_err!("failed to find version string"))
@@ -67,6 +74,10 @@ impl std::fmt::Display for Version {
             "{}.{}.{}{}",
             self.major, self.minor, self.patch, self.release_flags
         )?;
+        // free-threaded builds are named like python3.13t
+        if self.free_threaded {
+            write!(f, "t")?
+        }
         if let Some(build_metadata) = &self.build_metadata {
             write!(f, "+{build_metadata}",)?
         }
@@ -88,6 +99,7 @@ mod tests {
                 patch: 10,
                 release_flags: "".to_owned(),
                 build_metadata: None,
+                free_threaded: false,
             }
         );
 
@@ -103,6 +115,7 @@ mod tests {
                 patch: 3,
                 release_flags: "".to_owned(),
                 build_metadata: None,
+                free_threaded: false,
             }
         );
 
@@ -117,6 +130,7 @@ mod tests {
                 patch: 0,
                 release_flags: "rc1".to_owned(),
                 build_metadata: None,
+                free_threaded: false,
             }
         );
 
@@ -131,6 +145,7 @@ mod tests {
                 patch: 0,
                 release_flags: "rc1".to_owned(),
                 build_metadata: None,
+                free_threaded: false,
             }
         );
 
@@ -154,6 +169,7 @@ mod tests {
                 patch: 15,
                 release_flags: "".to_owned(),
                 build_metadata: Some("".to_owned()),
+                free_threaded: false,
             }
         );
 
@@ -166,6 +182,7 @@ mod tests {
                 patch: 10,
                 release_flags: "".to_owned(),
                 build_metadata: Some("dcba".to_owned()),
+                free_threaded: false,
             }
         );
 
@@ -178,6 +195,7 @@ mod tests {
                 patch: 10,
                 release_flags: "".to_owned(),
                 build_metadata: Some("5-4.abcd".to_owned()),
+                free_threaded: false,
             }
         );
 
@@ -190,7 +208,47 @@ mod tests {
                 patch: 5,
                 release_flags: "".to_owned(),
                 build_metadata: Some("cinder".to_owned()),
+                free_threaded: false,
+            }
+        );
+
+        // versions newer than the ones we have bindings for are still found, so that they can
+        // be profiled using the _Py_DebugOffsets
+        let version = Version::scan_bytes(b"3.14.0rc1 (main, Jul 22 2025, 12:00:00)").unwrap();
+        assert_eq!(
+            version,
+            Version {
+                major: 3,
+                minor: 14,
+                patch: 0,
+                release_flags: "rc1".to_owned(),
+                build_metadata: None,
+                free_threaded: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_find_free_threaded_version() {
+        let version = Version::scan_bytes(
+            b"3.13.0 experimental free-threading build (main, Oct  8 2024, 08:51:28) [GCC 11.4.0]",
+        )
+        .unwrap();
+        assert_eq!(
+            version,
+            Version {
+                major: 3,
+                minor: 13,
+                patch: 0,
+                release_flags: "".to_owned(),
+                build_metadata: None,
+                free_threaded: true,
             }
         );
+        assert_eq!(version.to_string(), "3.13.0t");
+
+        let version = Version::scan_bytes(b"3.13.0 (main, Oct  8 2024, 08:51:28)").unwrap();
+        assert!(!version.free_threaded);
+        assert_eq!(version.to_string(), "3.13.0");
     }
 }